@@ -0,0 +1,293 @@
+//! Memory access logic shared by the drivers.
+//!
+//! This is only generic over the I²C bus. The device geometry is a runtime value so that
+//! the code is instantiated once per bus type rather than once per device type.
+
+use crate::eeprom24x::build_payload;
+use crate::slice::split_mut;
+use crate::{Error, SlaveAddr};
+use core::mem;
+use embedded_hal::i2c::{I2c, Operation};
+
+/// Maximum number of buffers read in a single transaction by `read_data_vectored()`.
+pub(crate) const VECTORED_READ_BUFFERS: usize = 8;
+
+/// Position of the block select bit in the device address of 24xx1025 devices.
+const BLOCK_SELECT_BIT: u8 = 0b100;
+
+/// How memory addresses are transmitted to a device
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Geometry {
+    /// Device address
+    pub(crate) address: SlaveAddr,
+    /// Number of bits used for memory addressing
+    pub(crate) address_bits: u8,
+    /// Number of bytes used to transmit the memory address
+    pub(crate) address_bytes: u8,
+    /// Whether the upper 64 KiB are selected with the A2 bit of the device address
+    pub(crate) block_select: bool,
+}
+
+impl Geometry {
+    /// Return device capacity in bytes
+    pub(crate) fn capacity(&self) -> usize {
+        1usize
+            .checked_shl(u32::from(self.address_bits))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Return the 7-bit I²C address used when accessing the given memory address.
+    pub(crate) fn device_address<E>(&self, memory_address: u32) -> Result<u8, Error<E>> {
+        if memory_address as usize >= self.capacity() {
+            return Err(Error::InvalidAddr {
+                address: memory_address,
+                capacity: self.capacity(),
+            });
+        }
+        let addr = self.address.addr();
+        if self.block_select {
+            // the block select bit takes the place of A2
+            if addr > 0x7F || addr & BLOCK_SELECT_BIT != 0 {
+                return Err(Error::InvalidSlaveAddr);
+            }
+            return Ok(if memory_address >> 16 != 0 {
+                addr | BLOCK_SELECT_BIT
+            } else {
+                addr
+            });
+        }
+        let shift = self.address_bytes * 8;
+        if !self.address.is_valid(self.address_bits, shift) {
+            return Err(Error::InvalidSlaveAddr);
+        }
+        Ok(self
+            .address
+            .devaddr(memory_address, self.address_bits, shift))
+    }
+
+    /// Return the 7-bit I²C address used when accessing the given memory address without
+    /// validating it. Addresses beyond the capacity wrap around to the start of the memory.
+    fn device_address_unchecked(&self, memory_address: u32) -> u8 {
+        let memory_address = memory_address & (self.capacity().wrapping_sub(1) as u32);
+        let addr = self.address.addr();
+        if self.block_select {
+            return if memory_address >> 16 != 0 {
+                addr | BLOCK_SELECT_BIT
+            } else {
+                addr
+            };
+        }
+        self.address
+            .devaddr(memory_address, self.address_bits, self.address_bytes * 8)
+    }
+
+    /// Return the number of bytes which can be read sequentially from the given memory
+    /// address. The address counter of devices with a block select bit does not roll over
+    /// into the next 64 KiB block.
+    fn block_remaining(&self, memory_address: u32) -> usize {
+        if self.block_select {
+            0x1_0000 - (memory_address & 0xFFFF) as usize
+        } else {
+            usize::MAX
+        }
+    }
+
+    /// Write the memory address into the buffer and return the bytes to transmit.
+    pub(crate) fn memory_address<'a>(&self, address: u32, buffer: &'a mut [u8; 2]) -> &'a [u8] {
+        *buffer = [(address >> 8) as u8, address as u8];
+        if self.address_bytes == 1 {
+            let [_, low] = buffer;
+            core::slice::from_mut(low)
+        } else {
+            buffer
+        }
+    }
+}
+
+/// Classify the bus error of a memory access and keep track of pending write cycles.
+pub(crate) fn finish_access<E>(
+    write_pending: &mut bool,
+    result: Result<(), E>,
+    write: bool,
+) -> Result<(), Error<E>>
+where
+    E: embedded_hal::i2c::Error,
+{
+    match result {
+        Ok(()) => {
+            *write_pending = write;
+            Ok(())
+        }
+        Err(e) => Err(Error::from_bus(e, *write_pending)),
+    }
+}
+
+/// Memory accesses through a driver's bus
+pub(crate) struct Access<'a, I2C> {
+    pub(crate) i2c: &'a mut I2C,
+    pub(crate) write_pending: &'a mut bool,
+    pub(crate) geometry: Geometry,
+    pub(crate) max_transfer_len: usize,
+    /// Whether the memory addresses and page boundaries are validated
+    pub(crate) checked: bool,
+}
+
+impl<I2C, E> Access<'_, I2C>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    fn finish(&mut self, result: Result<(), E>, write: bool) -> Result<(), Error<E>> {
+        finish_access(self.write_pending, result, write)
+    }
+
+    fn device_address(&self, address: u32) -> Result<u8, Error<E>> {
+        if self.checked {
+            self.geometry.device_address(address)
+        } else {
+            Ok(self.geometry.device_address_unchecked(address))
+        }
+    }
+
+    /// Write a single byte in an address.
+    pub(crate) fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<E>> {
+        let devaddr = self.device_address(address)?;
+        let mut memaddr = [0; 2];
+        let memaddr = self.geometry.memory_address(address, &mut memaddr);
+        let mut buffer = [0; 3];
+        let payload = build_payload(&mut buffer, memaddr, &[data])?;
+        let result = self.i2c.write(devaddr, payload);
+        self.finish(result, true)
+    }
+
+    /// Write data within a page of the given size.
+    pub(crate) fn write_page(
+        &mut self,
+        address: u32,
+        data: &[u8],
+        page_size: usize,
+        write_cycle: bool,
+    ) -> Result<(), Error<E>> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let allowed = page_size - address as usize % page_size;
+        if self.checked && data.len() > allowed {
+            // This would actually be supported by the EEPROM but
+            // the data in the page would be overwritten
+            return Err(Error::PageOverflow {
+                requested: data.len(),
+                allowed,
+            });
+        }
+
+        let devaddr = self.device_address(address)?;
+        let mut memaddr = [0; 2];
+        let memaddr = self.geometry.memory_address(address, &mut memaddr);
+        let result = self.i2c.transaction(
+            devaddr,
+            &mut [Operation::Write(memaddr), Operation::Write(data)],
+        );
+        self.finish(result, write_cycle)
+    }
+
+    /// Read data in transfers of at most the maximum transfer length.
+    ///
+    /// If `sequential` is set, the memory address is only sent when the device address
+    /// changes and the device's internal address counter is used otherwise.
+    pub(crate) fn read_data(
+        &mut self,
+        address: u32,
+        data: &mut [u8],
+        sequential: bool,
+    ) -> Result<(), Error<E>> {
+        let mut address = address;
+        let mut data = data;
+        let mut previous_devaddr = None;
+        loop {
+            let max_len = self
+                .max_transfer_len
+                .min(self.geometry.block_remaining(address));
+            let (chunk, rest) = split_mut(mem::take(&mut data), max_len);
+            let len = chunk.len();
+            let devaddr = self.device_address(address)?;
+            let result = if sequential && previous_devaddr == Some(devaddr) {
+                self.i2c.read(devaddr, chunk)
+            } else {
+                let mut memaddr = [0; 2];
+                let memaddr = self.geometry.memory_address(address, &mut memaddr);
+                self.i2c.write_read(devaddr, memaddr, chunk)
+            };
+            self.finish(result, false)?;
+            if rest.is_empty() {
+                return Ok(());
+            }
+            previous_devaddr = Some(devaddr);
+            address = address.wrapping_add(len as u32);
+            data = rest;
+        }
+    }
+
+    /// Read into several buffers back-to-back.
+    pub(crate) fn read_data_vectored(
+        &mut self,
+        address: u32,
+        buffers: &mut [&mut [u8]],
+    ) -> Result<(), Error<E>> {
+        let mut address = address;
+        if self.max_transfer_len != usize::MAX {
+            for buffer in buffers.iter_mut() {
+                self.read_data(address, buffer, false)?;
+                address = address.wrapping_add(buffer.len() as u32);
+            }
+            return Ok(());
+        }
+        for group in buffers.chunks_mut(VECTORED_READ_BUFFERS) {
+            let len: usize = group.iter().map(|buffer| buffer.len()).sum();
+            if len > self.geometry.block_remaining(address) {
+                // the device address changes within the group
+                for buffer in group.iter_mut() {
+                    self.read_data(address, buffer, false)?;
+                    address = address.wrapping_add(buffer.len() as u32);
+                }
+                continue;
+            }
+            let devaddr = self.device_address(address)?;
+            let mut memaddr = [0; 2];
+            let memaddr = self.geometry.memory_address(address, &mut memaddr);
+            let result = read_vectored(self.i2c, devaddr, memaddr, group);
+            self.finish(result, false)?;
+            address = address.wrapping_add(len as u32);
+        }
+        Ok(())
+    }
+}
+
+/// Send the memory address and read into up to `VECTORED_READ_BUFFERS` buffers back-to-back
+/// in a single transaction. Further buffers are not read.
+fn read_vectored<I2C: I2c>(
+    i2c: &mut I2C,
+    devaddr: u8,
+    memaddr: &[u8],
+    buffers: &mut [&mut [u8]],
+) -> Result<(), I2C::Error> {
+    // empty buffers are skipped so that no zero-length operations are issued
+    let mut buffers = buffers.iter_mut().filter(|buffer| !buffer.is_empty());
+    let mut count = 1;
+    let mut operations: [Operation<'_>; 1 + VECTORED_READ_BUFFERS] =
+        core::array::from_fn(|index| {
+            if index == 0 {
+                return Operation::Write(memaddr);
+            }
+            match buffers.next() {
+                Some(buffer) => {
+                    count += 1;
+                    Operation::Read(buffer)
+                }
+                None => Operation::Read(&mut []),
+            }
+        });
+    let (operations, _) = split_mut(&mut operations, count);
+    i2c.transaction(devaddr, operations)
+}
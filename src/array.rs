@@ -0,0 +1,78 @@
+use crate::{ArrayError, EepromArray, EepromCell, Storable};
+use core::marker::PhantomData;
+use embedded_storage::{ReadStorage, Storage};
+
+impl<T, const N: usize> EepromArray<T, N> {
+    /// Create a handle for `N` values stored back-to-back from a memory address.
+    pub const fn new(address: u32) -> Self {
+        EepromArray {
+            address,
+            _value: PhantomData,
+        }
+    }
+
+    /// Return the memory address of the first element.
+    pub const fn address(&self) -> u32 {
+        self.address
+    }
+
+    /// Return the number of elements.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Return whether the array has no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<T, const N: usize> Clone for EepromArray<T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for EepromArray<T, N> {}
+
+impl<T: Storable, const N: usize> EepromArray<T, N> {
+    /// Return the size of an element in bytes.
+    pub fn stride(&self) -> usize {
+        T::Bytes::default().as_ref().len()
+    }
+
+    /// Return the handle of an element or `None` if the index is out of bounds.
+    pub fn cell(&self, index: usize) -> Option<EepromCell<T>> {
+        if index >= N {
+            return None;
+        }
+        let offset = (index * self.stride()) as u32;
+        Some(EepromCell::new(self.address + offset))
+    }
+
+    /// Read an element from the storage.
+    pub fn get<S: ReadStorage>(
+        &self,
+        storage: &mut S,
+        index: usize,
+    ) -> Result<T, ArrayError<S::Error>> {
+        let cell = self.checked_cell(index)?;
+        cell.get(storage).map_err(ArrayError::Storage)
+    }
+
+    /// Write an element to the storage.
+    pub fn set<S: Storage>(
+        &self,
+        storage: &mut S,
+        index: usize,
+        value: &T,
+    ) -> Result<(), ArrayError<S::Error>> {
+        let cell = self.checked_cell(index)?;
+        cell.set(storage, value).map_err(ArrayError::Storage)
+    }
+
+    fn checked_cell<E>(&self, index: usize) -> Result<EepromCell<T>, ArrayError<E>> {
+        self.cell(index)
+            .ok_or(ArrayError::OutOfBounds { index, len: N })
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{AuthError, AuthenticatedBlock, Mac};
+use embedded_storage::Storage;
+
+impl<S, M> AuthenticatedBlock<S, M> {
+    /// Create a new instance authenticating the data with the MAC.
+    pub fn new(storage: S, mac: M) -> Self {
+        AuthenticatedBlock { storage, mac }
+    }
+
+    /// Destroy the authenticated block, return the storage and the MAC.
+    pub fn destroy(self) -> (S, M) {
+        (self.storage, self.mac)
+    }
+}
+
+impl<S, M> AuthenticatedBlock<S, M>
+where
+    S: Storage,
+    M: Mac,
+{
+    /// Return the maximum length of the data in bytes.
+    pub fn max_len(&self) -> usize {
+        self.storage
+            .capacity()
+            .saturating_sub(self.header_len() as usize)
+            .min(usize::from(u16::MAX))
+    }
+
+    /// Load the data into the buffer and return its length.
+    ///
+    /// Returns `AuthError::TamperDetected` if the data does not match its tag, which is
+    /// also the case if no data was saved. The buffer contents are unspecified then.
+    pub fn load(&mut self, buffer: &mut [u8]) -> Result<usize, AuthError<S::Error>> {
+        let mut len = [0; 2];
+        self.storage.read(0, &mut len).map_err(AuthError::Storage)?;
+        let mut stored = M::Tag::default();
+        self.storage
+            .read(2, stored.as_mut())
+            .map_err(AuthError::Storage)?;
+        let data_len = usize::from(u16::from_le_bytes(len));
+        if data_len > self.max_len() {
+            return Err(AuthError::TamperDetected);
+        }
+        let data = buffer
+            .get_mut(..data_len)
+            .ok_or(AuthError::BufferTooSmall { required: data_len })?;
+        self.storage
+            .read(self.header_len(), data)
+            .map_err(AuthError::Storage)?;
+        self.mac.update(&len);
+        self.mac.update(data);
+        if !tags_equal(self.mac.finalize().as_ref(), stored.as_ref()) {
+            return Err(AuthError::TamperDetected);
+        }
+        Ok(data_len)
+    }
+
+    /// Save the data followed by its length and tag.
+    ///
+    /// Returns `AuthError::TooLarge` if the data is longer than
+    /// [`max_len()`](Self::max_len).
+    pub fn save(&mut self, data: &[u8]) -> Result<(), AuthError<S::Error>> {
+        if data.len() > self.max_len() {
+            return Err(AuthError::TooLarge);
+        }
+        let len = (data.len() as u16).to_le_bytes();
+        self.mac.update(&len);
+        self.mac.update(data);
+        let tag = self.mac.finalize();
+        self.storage
+            .write(self.header_len(), data)
+            .map_err(AuthError::Storage)?;
+        self.storage.write(0, &len).map_err(AuthError::Storage)?;
+        self.storage
+            .write(2, tag.as_ref())
+            .map_err(AuthError::Storage)
+    }
+
+    /// Return the size of the header: data length and tag.
+    fn header_len(&self) -> u32 {
+        2 + M::Tag::default().as_ref().len() as u32
+    }
+}
+
+/// Compare two tags in constant time.
+fn tags_equal(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
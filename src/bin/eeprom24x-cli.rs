@@ -0,0 +1,283 @@
+//! Command-line tool to service EEPROMs connected to a Linux I²C bus.
+//!
+//! Run `eeprom24x-cli --help` for the available commands.
+
+use eeprom24x::{
+    dump, hexdump, secure_erase, Device, DynStorage, Eeprom24x, Eeprom24xDyn, SlaveAddr,
+    TransferError,
+};
+use embedded_storage::{ReadStorage, Storage};
+use linux_embedded_hal::{Delay, I2cdev};
+use std::fmt;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: eeprom24x-cli --device DEVICE [--bus BUS] [--address ADDRESS] COMMAND
+
+Options:
+  --device DEVICE    Device type, e.g. 24x256 or AT24C256
+  --bus BUS          I2C bus device [default: /dev/i2c-1]
+  --address ADDRESS  7-bit I2C address, e.g. 0x51 [default: 0x50]
+
+Commands:
+  dump [FILE]        Print a hex dump of the memory or write it to FILE
+  flash FILE         Write FILE to the start of the memory
+  verify FILE        Compare the start of the memory with FILE
+  erase              Fill the memory with 0xFF
+  serial             Print the factory-programmed unique serial number";
+
+/// Parsed command line
+#[derive(Debug)]
+struct Args {
+    device: Device,
+    bus: String,
+    address: SlaveAddr,
+    command: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    match parse_args(args.into_iter()).and_then(run) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut device = None;
+    let mut bus = String::from("/dev/i2c-1");
+    let mut address = SlaveAddr::default();
+    let mut command = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {arg}"));
+        match arg.as_str() {
+            "--device" => {
+                let name = value()?;
+                device = Some(
+                    name.parse::<Device>()
+                        .map_err(|_| format!("unknown device {name}"))?,
+                );
+            }
+            "--bus" => bus = value()?,
+            "--address" => address = parse_address(&value()?)?,
+            _ => command.push(arg),
+        }
+    }
+    let device = device.ok_or(format!("missing --device\n\n{USAGE}"))?;
+    Ok(Args {
+        device,
+        bus,
+        address,
+        command,
+    })
+}
+
+fn parse_address(value: &str) -> Result<SlaveAddr, String> {
+    let address = match value.strip_prefix("0x") {
+        Some(hex) => u8::from_str_radix(hex, 16),
+        None => value.parse(),
+    }
+    .map_err(|_| format!("invalid address {value}"))?;
+    Ok(match address {
+        0x50..=0x57 => SlaveAddr::from_pins(address & 0b111),
+        0x80.. => return Err(format!("address {value} is not a 7-bit address")),
+        _ => SlaveAddr::Custom(address),
+    })
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let i2c = I2cdev::new(&args.bus).map_err(|e| format!("cannot open {}: {e}", args.bus))?;
+    let command: Vec<&str> = args.command.iter().map(String::as_str).collect();
+    if command == ["serial"] {
+        return print_serial(i2c, args.address, args.device);
+    }
+    let eeprom = Eeprom24xDyn::new_from_device(i2c, args.address, args.device);
+    let mut storage = DynStorage::new(eeprom, Delay);
+    let capacity = storage.capacity() as u32;
+    match command.as_slice() {
+        ["dump"] => {
+            let mut out = Stdout(io::stdout().lock());
+            hexdump(&mut storage, 0..capacity, &mut out).map_err(|e| e.to_string())?;
+        }
+        ["dump", file] => {
+            let mut file = std::fs::File::create(file).map_err(|e| e.to_string())?;
+            dump(&mut storage, 0..capacity, |chunk| file.write_all(chunk))
+                .map_err(|e| e.to_string())?;
+        }
+        ["flash", file] => {
+            let data = read_image(file, capacity)?;
+            storage.write(0, &data).map_err(|e| e.to_string())?;
+            println!("{} bytes written", data.len());
+        }
+        ["verify", file] => {
+            let data = read_image(file, capacity)?;
+            let mut offset = 0;
+            let result = dump(&mut storage, 0..data.len() as u32, |chunk| {
+                let expected = data.iter().skip(offset);
+                match chunk.iter().zip(expected).position(|(a, b)| a != b) {
+                    Some(position) => Err(offset + position),
+                    None => {
+                        offset += chunk.len();
+                        Ok(())
+                    }
+                }
+            });
+            match result {
+                Ok(()) => println!("{} bytes verified", data.len()),
+                Err(TransferError::Callback(offset)) => {
+                    return Err(format!("mismatch at {offset:#06x}"))
+                }
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+        ["erase"] => {
+            secure_erase(&mut storage, 0..capacity, 1).map_err(|e| e.to_string())?;
+            println!("{capacity} bytes erased");
+        }
+        _ => return Err(USAGE.into()),
+    }
+    Ok(())
+}
+
+/// Adapter writing formatted text to the standard output as it is produced
+struct Stdout<'a>(io::StdoutLock<'a>);
+
+impl fmt::Write for Stdout<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write_all(s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+/// Read an image file which must fit into the memory.
+fn read_image(file: &str, capacity: u32) -> Result<Vec<u8>, String> {
+    let data = std::fs::read(file).map_err(|e| format!("cannot read {file}: {e}"))?;
+    if data.len() > capacity as usize {
+        return Err(format!(
+            "{file} is larger than the memory ({} > {capacity} bytes)",
+            data.len()
+        ));
+    }
+    Ok(data)
+}
+
+fn print_serial(i2c: I2cdev, address: SlaveAddr, device: Device) -> Result<(), String> {
+    macro_rules! read_serial {
+        ($create:ident) => {
+            Eeprom24x::$create(i2c, address).read_unique_serial()
+        };
+    }
+    let serial = match device {
+        Device::AT24CS01 => read_serial!(new_24csx01),
+        Device::AT24CS02 => read_serial!(new_24csx02),
+        Device::AT24CS04 => read_serial!(new_24csx04),
+        Device::AT24CS08 => read_serial!(new_24csx08),
+        Device::AT24CS16 => read_serial!(new_24csx16),
+        Device::AT24CS32 => read_serial!(new_24csx32),
+        Device::AT24CS64 => read_serial!(new_24csx64),
+        Device::AT24CSW010 => read_serial!(new_24cswx01),
+        Device::AT24CSW020 => read_serial!(new_24cswx02),
+        Device::AT24CSW040 => read_serial!(new_24cswx04),
+        Device::AT24CSW080 => read_serial!(new_24cswx08),
+        Device::AT24CSW160 => read_serial!(new_24cswx16),
+        Device::AT24MAC402 => read_serial!(new_24mac402),
+        Device::AT24MAC602 => read_serial!(new_24mac602),
+        _ => return Err(format!("{} has no unique serial number", device.part())),
+    }
+    .map_err(|e| e.to_string())?;
+    let hex: String = serial.iter().map(|byte| format!("{byte:02X}")).collect();
+    println!("{hex}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|arg| String::from(*arg)))
+    }
+
+    #[test]
+    fn parses_args() {
+        let args = parse(&[
+            "--device",
+            "24x256",
+            "--bus",
+            "/dev/i2c-2",
+            "dump",
+            "out.bin",
+        ])
+        .unwrap();
+        assert_eq!(Device::AT24C256, args.device);
+        assert_eq!("/dev/i2c-2", args.bus);
+        assert_eq!(SlaveAddr::default(), args.address);
+        assert_eq!(vec!["dump", "out.bin"], args.command);
+    }
+
+    #[test]
+    fn parses_part_name_and_address() {
+        let args = parse(&["erase", "--address", "0x53", "--device", "at24c02"]).unwrap();
+        assert_eq!(Device::AT24C02, args.device);
+        assert_eq!("/dev/i2c-1", args.bus);
+        assert_eq!(SlaveAddr::from_pins(0b011), args.address);
+        assert_eq!(vec!["erase"], args.command);
+    }
+
+    #[test]
+    fn rejects_missing_device() {
+        assert!(parse(&["dump"])
+            .unwrap_err()
+            .starts_with("missing --device"));
+    }
+
+    #[test]
+    fn rejects_unknown_device() {
+        assert_eq!(
+            "unknown device 24x999",
+            parse(&["--device", "24x999"]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn rejects_missing_value() {
+        assert_eq!(
+            "missing value for --address",
+            parse(&["--device", "24x256", "--address"]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parses_addresses() {
+        assert_eq!(SlaveAddr::from_pins(0), parse_address("0x50").unwrap());
+        assert_eq!(SlaveAddr::from_pins(0b111), parse_address("87").unwrap());
+        assert_eq!(SlaveAddr::Custom(0x18), parse_address("0x18").unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_addresses() {
+        assert_eq!("invalid address 0x", parse_address("0x").unwrap_err());
+        assert_eq!("invalid address 0xZZ", parse_address("0xZZ").unwrap_err());
+        assert_eq!("invalid address -1", parse_address("-1").unwrap_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_addresses() {
+        assert_eq!("invalid address 0x100", parse_address("0x100").unwrap_err());
+        assert_eq!(
+            "address 0x80 is not a 7-bit address",
+            parse_address("0x80").unwrap_err()
+        );
+        assert_eq!(
+            "address 255 is not a 7-bit address",
+            parse_address("255").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::slice::split_mut;
+use crate::EepromBitmap;
+use embedded_storage::{ReadStorage, Storage};
+
+/// Size of the chunks in which the bits are counted.
+const CHUNK_LEN: usize = 16;
+
+impl<S> EepromBitmap<S> {
+    /// Create a new instance using all the bytes of the storage.
+    pub fn new(storage: S) -> Self {
+        EepromBitmap { storage }
+    }
+
+    /// Destroy the bitmap, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S> EepromBitmap<S>
+where
+    S: ReadStorage,
+{
+    /// Return the number of bits.
+    pub fn len(&self) -> usize {
+        self.storage.capacity() * 8
+    }
+
+    /// Return whether the bitmap has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.storage.capacity() == 0
+    }
+
+    /// Return the value of a bit.
+    pub fn get(&mut self, index: u32) -> Result<bool, S::Error> {
+        let byte = self.read_byte(index)?;
+        Ok(byte & mask(index) != 0)
+    }
+
+    /// Return the number of set bits.
+    pub fn count_ones(&mut self) -> Result<usize, S::Error> {
+        let mut chunk = [0; CHUNK_LEN];
+        let mut count = 0;
+        let mut offset = 0;
+        while offset < self.storage.capacity() {
+            let (data, _) = split_mut(&mut chunk, self.storage.capacity() - offset);
+            self.storage.read(offset as u32, data)?;
+            count += data
+                .iter()
+                .map(|byte| byte.count_ones() as usize)
+                .sum::<usize>();
+            offset += data.len();
+        }
+        Ok(count)
+    }
+
+    fn read_byte(&mut self, index: u32) -> Result<u8, S::Error> {
+        let mut byte = [0];
+        self.storage.read(index / 8, &mut byte)?;
+        let [byte] = byte;
+        Ok(byte)
+    }
+}
+
+impl<S> EepromBitmap<S>
+where
+    S: Storage,
+{
+    /// Set a bit.
+    ///
+    /// Only the byte containing the bit is written and only if the bit changes.
+    pub fn set(&mut self, index: u32) -> Result<(), S::Error> {
+        let byte = self.read_byte(index)?;
+        self.update(index, byte, byte | mask(index))
+    }
+
+    /// Clear a bit.
+    ///
+    /// Only the byte containing the bit is written and only if the bit changes.
+    pub fn clear(&mut self, index: u32) -> Result<(), S::Error> {
+        let byte = self.read_byte(index)?;
+        self.update(index, byte, byte & !mask(index))
+    }
+
+    fn update(&mut self, index: u32, old: u8, new: u8) -> Result<(), S::Error> {
+        if old == new {
+            return Ok(());
+        }
+        self.storage.write(index / 8, &[new])
+    }
+}
+
+/// Return the mask of a bit within its byte. Bit 0 is the least significant bit of the
+/// first byte.
+fn mask(index: u32) -> u8 {
+    1 << (index % 8)
+}
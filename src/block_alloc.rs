@@ -0,0 +1,254 @@
+use crate::slice::{split, split_mut};
+use crate::{AllocError, Allocation, BlockAllocator};
+use embedded_storage::Storage;
+
+/// Size of the chunks in which the bitmap is scanned and data is copied.
+const CHUNK_LEN: usize = 16;
+
+impl<S> BlockAllocator<S> {
+    /// Destroy the allocator, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+
+    /// Return the size of a block in bytes.
+    pub fn block_size(&self) -> usize {
+        self.block_size as usize
+    }
+}
+
+impl<S> BlockAllocator<S>
+where
+    S: Storage,
+{
+    /// Create a new instance managing blocks of `block_size` bytes.
+    ///
+    /// The storage starts with a bitmap with one bit per block followed by the blocks.
+    pub fn new(storage: S, block_size: usize) -> Self {
+        let block_size = block_size.max(1);
+        // each block takes its size plus a bit in the bitmap
+        let blocks = storage.capacity() * 8 / (block_size * 8 + 1);
+        BlockAllocator {
+            storage,
+            block_size: block_size as u32,
+            blocks: blocks as u32,
+        }
+    }
+
+    /// Return the number of blocks.
+    pub fn blocks(&self) -> usize {
+        self.blocks as usize
+    }
+
+    /// Mark all the blocks as free.
+    pub fn format(&mut self) -> Result<(), AllocError<S::Error>> {
+        let zeros = [0; CHUNK_LEN];
+        let mut offset = 0;
+        while offset < self.bitmap_len() {
+            let (data, _) = split(&zeros, (self.bitmap_len() - offset) as usize);
+            self.storage
+                .write(offset, data)
+                .map_err(AllocError::Storage)?;
+            offset += data.len() as u32;
+        }
+        Ok(())
+    }
+
+    /// Allocate `count` contiguous blocks.
+    ///
+    /// Returns `AllocError::Full` if there is no free run of this length.
+    pub fn alloc(&mut self, count: u32) -> Result<Allocation, AllocError<S::Error>> {
+        let start = self.find_free(count)?.ok_or(AllocError::Full)?;
+        self.mark(start, count, true)?;
+        Ok(Allocation { start, count })
+    }
+
+    /// Release the blocks of an allocation.
+    ///
+    /// Returns `AllocError::NotAllocated` if any of the blocks is free.
+    pub fn free(&mut self, allocation: Allocation) -> Result<(), AllocError<S::Error>> {
+        self.check(allocation)?;
+        if !self.is_run(allocation.start, allocation.count, true)? {
+            return Err(AllocError::NotAllocated);
+        }
+        self.mark(allocation.start, allocation.count, false)
+    }
+
+    /// Change the number of blocks of an allocation.
+    ///
+    /// The allocation grows in place if the following blocks are free. Otherwise, a new
+    /// run is allocated, the data is copied and the old blocks are released.
+    pub fn realloc(
+        &mut self,
+        allocation: Allocation,
+        count: u32,
+    ) -> Result<Allocation, AllocError<S::Error>> {
+        self.check(allocation)?;
+        if !self.is_run(allocation.start, allocation.count, true)? {
+            return Err(AllocError::NotAllocated);
+        }
+        if count <= allocation.count {
+            self.mark(allocation.start + count, allocation.count - count, false)?;
+            return Ok(Allocation {
+                start: allocation.start,
+                count,
+            });
+        }
+        let tail = allocation.start + allocation.count;
+        let extra = count - allocation.count;
+        if tail + extra <= self.blocks && self.is_run(tail, extra, false)? {
+            self.mark(tail, extra, true)?;
+            return Ok(Allocation {
+                start: allocation.start,
+                count,
+            });
+        }
+        let new = self.alloc(count)?;
+        let mut chunk = [0; CHUNK_LEN];
+        let len = allocation.count * self.block_size;
+        let mut offset = 0;
+        while offset < len {
+            let (data, _) = split_mut(&mut chunk, (len - offset) as usize);
+            let n = data.len() as u32;
+            self.storage
+                .read(self.block_offset(allocation.start) + offset, data)
+                .map_err(AllocError::Storage)?;
+            self.storage
+                .write(self.block_offset(new.start) + offset, data)
+                .map_err(AllocError::Storage)?;
+            offset += n;
+        }
+        self.mark(allocation.start, allocation.count, false)?;
+        Ok(new)
+    }
+
+    /// Read data at an offset within an allocation.
+    pub fn read(
+        &mut self,
+        allocation: Allocation,
+        offset: u32,
+        bytes: &mut [u8],
+    ) -> Result<(), AllocError<S::Error>> {
+        self.check_access(allocation, offset, bytes.len())?;
+        self.storage
+            .read(self.block_offset(allocation.start) + offset, bytes)
+            .map_err(AllocError::Storage)
+    }
+
+    /// Write data at an offset within an allocation.
+    pub fn write(
+        &mut self,
+        allocation: Allocation,
+        offset: u32,
+        bytes: &[u8],
+    ) -> Result<(), AllocError<S::Error>> {
+        self.check_access(allocation, offset, bytes.len())?;
+        self.storage
+            .write(self.block_offset(allocation.start) + offset, bytes)
+            .map_err(AllocError::Storage)
+    }
+
+    fn bitmap_len(&self) -> u32 {
+        self.blocks.div_ceil(8)
+    }
+
+    fn block_offset(&self, block: u32) -> u32 {
+        self.bitmap_len() + block * self.block_size
+    }
+
+    fn check(&self, allocation: Allocation) -> Result<(), AllocError<S::Error>> {
+        if allocation.count == 0
+            || !matches!(allocation.start.checked_add(allocation.count), Some(end) if end <= self.blocks)
+        {
+            return Err(AllocError::OutOfBounds);
+        }
+        Ok(())
+    }
+
+    fn check_access(
+        &self,
+        allocation: Allocation,
+        offset: u32,
+        len: usize,
+    ) -> Result<(), AllocError<S::Error>> {
+        self.check(allocation)?;
+        let size = allocation.count as usize * self.block_size as usize;
+        if len > size.saturating_sub(offset as usize) {
+            return Err(AllocError::OutOfBounds);
+        }
+        Ok(())
+    }
+
+    /// Return the first block of the first free run of `count` blocks, if any.
+    fn find_free(&mut self, count: u32) -> Result<Option<u32>, AllocError<S::Error>> {
+        if count == 0 {
+            return Err(AllocError::OutOfBounds);
+        }
+        let mut chunk = [0; CHUNK_LEN];
+        let mut run_start = 0;
+        let mut block = 0;
+        while block < self.blocks {
+            let offset = block / 8;
+            let (data, _) = split_mut(&mut chunk, (self.bitmap_len() - offset) as usize);
+            self.storage
+                .read(offset, data)
+                .map_err(AllocError::Storage)?;
+            for byte in data.iter() {
+                for bit in 0..8 {
+                    if block >= self.blocks {
+                        return Ok(None);
+                    }
+                    if byte & (1 << bit) != 0 {
+                        run_start = block + 1;
+                    } else if block + 1 - run_start == count {
+                        return Ok(Some(run_start));
+                    }
+                    block += 1;
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Return whether all the blocks of a run are allocated (`used`) or free.
+    fn is_run(&mut self, start: u32, count: u32, used: bool) -> Result<bool, AllocError<S::Error>> {
+        for block in start..start + count {
+            let mut byte = [0];
+            self.storage
+                .read(block / 8, &mut byte)
+                .map_err(AllocError::Storage)?;
+            let [byte] = byte;
+            if (byte & (1 << (block % 8)) != 0) != used {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Mark the blocks of a run as allocated (`used`) or free, writing each bitmap byte
+    /// once.
+    fn mark(&mut self, start: u32, count: u32, used: bool) -> Result<(), AllocError<S::Error>> {
+        let end = start + count;
+        let mut block = start;
+        while block < end {
+            let offset = block / 8;
+            let mut byte = [0];
+            self.storage
+                .read(offset, &mut byte)
+                .map_err(AllocError::Storage)?;
+            let [mut byte] = byte;
+            while block < end && block / 8 == offset {
+                if used {
+                    byte |= 1 << (block % 8);
+                } else {
+                    byte &= !(1 << (block % 8));
+                }
+                block += 1;
+            }
+            self.storage
+                .write(offset, &[byte])
+                .map_err(AllocError::Storage)?;
+        }
+        Ok(())
+    }
+}
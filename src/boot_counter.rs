@@ -0,0 +1,93 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::{BootCounter, CounterError};
+use embedded_storage::Storage;
+
+/// Size of a slot: count and checksum.
+const SLOT_LEN: u32 = 6;
+
+impl<S> BootCounter<S> {
+    /// Create a new instance spreading the writes over the whole storage.
+    pub fn new(storage: S) -> Self {
+        BootCounter {
+            storage,
+            current: None,
+            scanned: false,
+        }
+    }
+
+    /// Destroy the counter, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S> BootCounter<S>
+where
+    S: Storage,
+{
+    /// Return the number of boots counted so far.
+    ///
+    /// This is zero until [`increment_boot_count()`](Self::increment_boot_count) is called
+    /// for the first time.
+    pub fn boot_count(&mut self) -> Result<u32, CounterError<S::Error>> {
+        self.scan()?;
+        Ok(self.current.map_or(0, |(_, count)| count))
+    }
+
+    /// Increment the boot count and return the new count.
+    ///
+    /// The count is written to the slot following the slot holding the current count, so
+    /// that each slot is only written once every `capacity / 6` increments. If the power is
+    /// lost while writing, the previous count is kept. The count wraps around to zero after
+    /// `u32::MAX`.
+    pub fn increment_boot_count(&mut self) -> Result<u32, CounterError<S::Error>> {
+        self.scan()?;
+        let slots = self.slots();
+        if slots == 0 {
+            return Err(CounterError::TooSmall);
+        }
+        let (slot, count) = match self.current {
+            Some((slot, count)) => ((slot + 1) % slots, count.wrapping_add(1)),
+            None => (0, 1),
+        };
+        let [n0, n1, n2, n3] = count.to_le_bytes();
+        let [c0, c1] = crc16(CRC16_INIT, &[n0, n1, n2, n3]).to_le_bytes();
+        self.storage
+            .write(slot * SLOT_LEN, &[n0, n1, n2, n3, c0, c1])
+            .map_err(CounterError::Storage)?;
+        self.current = Some((slot, count));
+        Ok(count)
+    }
+
+    fn slots(&self) -> u32 {
+        self.storage.capacity() as u32 / SLOT_LEN
+    }
+
+    /// Find the valid slot with the most recent count.
+    fn scan(&mut self) -> Result<(), CounterError<S::Error>> {
+        if self.scanned {
+            return Ok(());
+        }
+        for slot in 0..self.slots() {
+            let mut raw = [0; SLOT_LEN as usize];
+            self.storage
+                .read(slot * SLOT_LEN, &mut raw)
+                .map_err(CounterError::Storage)?;
+            let [n0, n1, n2, n3, c0, c1] = raw;
+            if crc16(CRC16_INIT, &[n0, n1, n2, n3]) != u16::from_le_bytes([c0, c1]) {
+                continue;
+            }
+            let count = u32::from_le_bytes([n0, n1, n2, n3]);
+            let newer = match self.current {
+                // counts wrap around
+                Some((_, other)) => count.wrapping_sub(other) as i32 > 0,
+                None => true,
+            };
+            if newer {
+                self.current = Some((slot, count));
+            }
+        }
+        self.scanned = true;
+        Ok(())
+    }
+}
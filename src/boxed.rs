@@ -0,0 +1,42 @@
+use crate::{Eeprom24xTrait, Error};
+use alloc::boxed::Box;
+
+/// Forwarding implementation so that boxed devices can be used in generic code
+impl<T> Eeprom24xTrait for Box<T>
+where
+    T: Eeprom24xTrait + ?Sized,
+{
+    type Error = T::Error;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<Self::Error>> {
+        (**self).write_byte(address, data)
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Error<Self::Error>> {
+        (**self).read_byte(address)
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<Self::Error>> {
+        (**self).read_data(address, data)
+    }
+
+    fn read_current_address(&mut self) -> Result<u8, Error<Self::Error>> {
+        (**self).read_current_address()
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        (**self).write_page(address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        (**self).page_size()
+    }
+
+    fn capacity(&self) -> usize {
+        (**self).capacity()
+    }
+
+    fn write_cycle_ms(&self) -> u32 {
+        (**self).write_cycle_ms()
+    }
+}
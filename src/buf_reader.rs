@@ -0,0 +1,58 @@
+use crate::slice::split_mut;
+use crate::{BufReader, ReadAhead};
+use embedded_storage::ReadStorage;
+
+impl<S, const N: usize> BufReader<S, N> {
+    /// Create a new reader starting at an offset of the storage.
+    pub fn new(storage: S, offset: u32) -> Self {
+        BufReader {
+            reader: ReadAhead::new(storage),
+            position: offset,
+        }
+    }
+
+    /// Return the offset from which the next data is read.
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// Continue reading from another offset.
+    ///
+    /// The buffered data is kept, so seeking within it does not access the storage.
+    pub fn seek(&mut self, offset: u32) {
+        self.position = offset;
+    }
+
+    /// Destroy the reader, return the storage.
+    pub fn destroy(self) -> S {
+        self.reader.destroy()
+    }
+}
+
+impl<S, const N: usize> BufReader<S, N>
+where
+    S: ReadStorage,
+{
+    /// Read the next byte, or return `None` at the end of the storage.
+    pub fn read_byte(&mut self) -> Result<Option<u8>, S::Error> {
+        let mut byte = [0];
+        Ok(match self.read(&mut byte)? {
+            0 => None,
+            _ => Some(u8::from_le_bytes(byte)),
+        })
+    }
+
+    /// Read the next bytes into the buffer and return how many were read.
+    ///
+    /// Fewer bytes are only read at the end of the storage.
+    pub fn read(&mut self, bytes: &mut [u8]) -> Result<usize, S::Error> {
+        let remaining = self
+            .reader
+            .capacity()
+            .saturating_sub(self.position as usize);
+        let (data, _) = split_mut(bytes, remaining);
+        self.reader.read(self.position, data)?;
+        self.position += data.len() as u32;
+        Ok(data.len())
+    }
+}
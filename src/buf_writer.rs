@@ -0,0 +1,66 @@
+use crate::slice::{copy, split, split_mut};
+use crate::BufWriter;
+use embedded_storage::Storage;
+
+impl<S, const P: usize> BufWriter<S, P> {
+    /// Compile-time check of the page size.
+    const PAGE_SIZE_OK: () = assert!(P.is_power_of_two(), "unsupported page size");
+
+    /// Create a new writer appending data from an offset of the storage.
+    pub fn new(storage: S, offset: u32) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::PAGE_SIZE_OK;
+        BufWriter {
+            storage,
+            buffer: [0; P],
+            start: offset,
+            len: 0,
+        }
+    }
+
+    /// Return the offset at which the next data is written.
+    pub fn position(&self) -> u32 {
+        self.start + self.len as u32
+    }
+
+    /// Destroy the writer, return the storage.
+    ///
+    /// Data which has not been flushed is lost.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S, const P: usize> BufWriter<S, P>
+where
+    S: Storage,
+{
+    /// Append data.
+    ///
+    /// The data is buffered and each page is written as soon as it is complete.
+    pub fn write(&mut self, mut data: &[u8]) -> Result<(), S::Error> {
+        while !data.is_empty() {
+            let offset = self.start as usize % P + self.len;
+            let (_, target) = split_mut(&mut self.buffer, offset);
+            let len = copy(target, data);
+            self.len += len;
+            data = split(data, len).1;
+            if offset + len == P {
+                self.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the buffered data of the incomplete page.
+    pub fn flush(&mut self) -> Result<(), S::Error> {
+        if self.len > 0 {
+            let (_, data) = split(&self.buffer, self.start as usize % P);
+            let (data, _) = split(data, self.len);
+            self.storage.write(self.start, data)?;
+            self.start += data.len() as u32;
+            self.len = 0;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use crate::slice::{copy, split, split_mut};
+use crate::{CachedEeprom, Eeprom24xTrait, Error};
+use core::mem;
+use embedded_hal::delay::DelayNs;
+
+/// Copy of a line of the device memory
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub(crate) struct CacheLine<const P: usize> {
+    /// Memory address of the first byte, if the line is in use
+    address: Option<u32>,
+    /// Line contents
+    data: [u8; P],
+    /// Whether the contents differ from the device memory
+    dirty: bool,
+}
+
+impl<const P: usize> CacheLine<P> {
+    const EMPTY: Self = CacheLine {
+        address: None,
+        data: [0; P],
+        dirty: false,
+    };
+}
+
+impl<T, D, const N: usize, const P: usize> CachedEeprom<T, D, N, P>
+where
+    T: Eeprom24xTrait,
+    D: DelayNs,
+{
+    /// Compile-time check of the cache geometry.
+    const GEOMETRY_OK: () = assert!(N > 0 && P.is_power_of_two(), "unsupported cache geometry");
+
+    /// Create a new cache wrapping a device.
+    ///
+    /// Returns `Error::InvalidGeometry` if the line size is larger than the device
+    /// page size.
+    pub fn new(eeprom: T, delay: D) -> Result<Self, Error<T::Error>> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::GEOMETRY_OK;
+        if P > eeprom.page_size() {
+            return Err(Error::InvalidGeometry);
+        }
+        Ok(CachedEeprom {
+            eeprom,
+            delay,
+            lines: [CacheLine::EMPTY; N],
+            next_victim: 0,
+        })
+    }
+
+    /// Write all the dirty lines to the device.
+    pub fn flush(&mut self) -> Result<(), Error<T::Error>> {
+        for line in self.lines.iter_mut() {
+            write_back(&mut self.eeprom, &mut self.delay, line)?;
+        }
+        Ok(())
+    }
+
+    /// Drop all the lines without writing them to the device.
+    ///
+    /// This can be used e.g. after the device has been modified through other means.
+    pub fn invalidate(&mut self) {
+        self.lines = [CacheLine::EMPTY; N];
+    }
+
+    /// Return whether there is data which has not been written to the device yet.
+    pub fn is_dirty(&self) -> bool {
+        self.lines.iter().any(|line| line.dirty)
+    }
+
+    /// Run a closure on the line containing an address, loading it if necessary.
+    ///
+    /// If the line is going to be overwritten completely, `load` can be set to `false` to
+    /// skip reading it from the device.
+    fn with_line<R>(
+        &mut self,
+        address: u32,
+        load: bool,
+        f: impl FnOnce(&mut CacheLine<P>) -> R,
+    ) -> Result<R, Error<T::Error>> {
+        let address = address & !(P as u32 - 1);
+        let index = match self
+            .lines
+            .iter()
+            .position(|line| line.address == Some(address))
+        {
+            Some(index) => index,
+            None => {
+                let index = self.next_victim;
+                self.next_victim = (index + 1) % N;
+                index
+            }
+        };
+        let Some(line) = self.lines.get_mut(index) else {
+            return Err(Error::InvalidGeometry);
+        };
+        if line.address != Some(address) {
+            write_back(&mut self.eeprom, &mut self.delay, line)?;
+            line.address = None;
+            if load {
+                self.eeprom.read_data(address, &mut line.data)?;
+            }
+            line.address = Some(address);
+        }
+        Ok(f(line))
+    }
+}
+
+/// Write a line to the device if it is dirty.
+fn write_back<T, D, const P: usize>(
+    eeprom: &mut T,
+    delay: &mut D,
+    line: &mut CacheLine<P>,
+) -> Result<(), Error<T::Error>>
+where
+    T: Eeprom24xTrait,
+    D: DelayNs,
+{
+    if let (true, Some(address)) = (line.dirty, line.address) {
+        eeprom.write_page(address, &line.data)?;
+        if eeprom.write_cycle_ms() > 0 {
+            delay.delay_ms(eeprom.write_cycle_ms());
+        }
+        line.dirty = false;
+    }
+    Ok(())
+}
+
+impl<T, D, const N: usize, const P: usize> CachedEeprom<T, D, N, P> {
+    /// Destroy the wrapper, return the device and the delay instance.
+    ///
+    /// Data which has not been flushed is lost.
+    pub fn destroy(self) -> (T, D) {
+        (self.eeprom, self.delay)
+    }
+}
+
+impl<T, D, const N: usize, const P: usize> embedded_storage::ReadStorage
+    for CachedEeprom<T, D, N, P>
+where
+    T: Eeprom24xTrait,
+    D: DelayNs,
+{
+    type Error = Error<T::Error>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let end = (offset as usize).checked_add(bytes.len());
+        if !matches!(end, Some(end) if end <= self.eeprom.capacity()) {
+            return Err(Error::InvalidAddr {
+                address: offset,
+                capacity: self.eeprom.capacity(),
+            });
+        }
+        let mut offset = offset;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let start = offset as usize % P;
+            let (chunk, rest) = split_mut(mem::take(&mut bytes), P - start);
+            self.with_line(offset, true, |line| {
+                copy(chunk, split(&line.data, start).1);
+            })?;
+            offset += chunk.len() as u32;
+            bytes = rest;
+        }
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.eeprom.capacity()
+    }
+}
+
+impl<T, D, const N: usize, const P: usize> embedded_storage::Storage for CachedEeprom<T, D, N, P>
+where
+    T: Eeprom24xTrait,
+    D: DelayNs,
+{
+    /// Write into the cache.
+    ///
+    /// The data is written to the device with [`flush()`](CachedEeprom::flush) or when
+    /// the lines are evicted.
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let allowed = self.eeprom.capacity().saturating_sub(offset as usize);
+        if bytes.len() > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: bytes.len(),
+                allowed,
+            });
+        }
+        let mut offset = offset;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let start = offset as usize % P;
+            let (chunk, rest) = split(bytes, P - start);
+            self.with_line(offset, chunk.len() < P, |line| {
+                copy(split_mut(&mut line.data, start).1, chunk);
+                line.dirty = true;
+            })?;
+            offset += chunk.len() as u32;
+            bytes = rest;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{EepromCell, Storable};
+use core::marker::PhantomData;
+use embedded_storage::{ReadStorage, Storage};
+
+impl<T> EepromCell<T> {
+    /// Create a handle for a value stored at a memory address.
+    pub const fn new(address: u32) -> Self {
+        EepromCell {
+            address,
+            _value: PhantomData,
+        }
+    }
+
+    /// Return the memory address of the value.
+    pub const fn address(&self) -> u32 {
+        self.address
+    }
+}
+
+impl<T> Clone for EepromCell<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for EepromCell<T> {}
+
+impl<T: Storable> EepromCell<T> {
+    /// Read the value from the storage.
+    pub fn get<S: ReadStorage>(&self, storage: &mut S) -> Result<T, S::Error> {
+        let mut bytes = T::Bytes::default();
+        storage.read(self.address, bytes.as_mut())?;
+        Ok(T::from_bytes(bytes))
+    }
+
+    /// Write the value to the storage.
+    pub fn set<S: Storage>(&self, storage: &mut S, value: &T) -> Result<(), S::Error> {
+        storage.write(self.address, value.to_bytes().as_ref())
+    }
+}
+
+macro_rules! impl_storable {
+    ( $( $type:ty ),* ) => {
+        $(
+            impl Storable for $type {
+                type Bytes = [u8; core::mem::size_of::<$type>()];
+
+                fn to_bytes(&self) -> Self::Bytes {
+                    self.to_le_bytes()
+                }
+
+                fn from_bytes(bytes: Self::Bytes) -> Self {
+                    Self::from_le_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_storable!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl Storable for bool {
+    type Bytes = [u8; 1];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        [u8::from(*self)]
+    }
+
+    fn from_bytes(bytes: Self::Bytes) -> Self {
+        bytes != [0]
+    }
+}
@@ -0,0 +1,177 @@
+use crate::slice::split;
+use crate::{Eeprom24xTrait, EepromChain, Error};
+use core::cmp::min;
+use embedded_hal::delay::DelayNs;
+
+impl<T, D, const N: usize> EepromChain<T, D, N>
+where
+    T: Eeprom24xTrait,
+{
+    /// Compile-time check of the number of devices.
+    const DEVICE_COUNT_OK: () = assert!(N > 0 && N <= 8, "unsupported number of devices");
+
+    /// Create a new chain out of devices of the same type.
+    ///
+    /// The device at index `i` contains the memory addresses starting at
+    /// `i * capacity`. Usually the devices are created with consecutive slave addresses,
+    /// e.g. with `SlaveAddr::from_pins(i)`.
+    pub fn new(devices: [T; N], delay: D) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::DEVICE_COUNT_OK;
+        EepromChain {
+            devices,
+            delay,
+            current: 0,
+        }
+    }
+
+    /// Destroy chain instance, return the devices and the delay instance.
+    pub fn destroy(self) -> ([T; N], D) {
+        (self.devices, self.delay)
+    }
+
+    /// Capacity of each of the devices in bytes
+    fn device_capacity(&self) -> usize {
+        self.devices.first().map_or(0, T::capacity)
+    }
+
+    /// Select the device containing an address and return it together with the address
+    /// within the device.
+    fn select(&mut self, address: u32) -> Result<(&mut T, u32), Error<T::Error>> {
+        let device_capacity = self.device_capacity() as u32;
+        let index = address
+            .checked_div(device_capacity)
+            .map(|index| index as usize);
+        match index.and_then(|index| Some((index, self.devices.get_mut(index)?))) {
+            Some((index, device)) => {
+                self.current = index;
+                Ok((device, address % device_capacity))
+            }
+            None => Err(Error::InvalidAddr {
+                address,
+                capacity: (device_capacity as usize).saturating_mul(N),
+            }),
+        }
+    }
+}
+
+impl<T, D, const N: usize> Eeprom24xTrait for EepromChain<T, D, N>
+where
+    T: Eeprom24xTrait,
+{
+    type Error = T::Error;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<Self::Error>> {
+        let (device, address) = self.select(address)?;
+        device.write_byte(address, data)
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Error<Self::Error>> {
+        let (device, address) = self.select(address)?;
+        device.read_byte(address)
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<Self::Error>> {
+        let end = (address as usize).checked_add(data.len());
+        if !matches!(end, Some(end) if end <= self.capacity()) {
+            return Err(Error::InvalidAddr {
+                address,
+                capacity: self.capacity(),
+            });
+        }
+        let device_capacity = self.device_capacity();
+        let mut address = address;
+        let mut data = data;
+        while !data.is_empty() {
+            let (device, device_address) = self.select(address)?;
+            let chunk_size = min(data.len(), device_capacity - device_address as usize);
+            let (chunk, rest) =
+                data.split_at_mut_checked(chunk_size)
+                    .ok_or(Error::InvalidAddr {
+                        address,
+                        capacity: device_capacity.saturating_mul(N),
+                    })?;
+            device.read_data(device_address, chunk)?;
+            address += chunk_size as u32;
+            data = rest;
+        }
+        Ok(())
+    }
+
+    /// Read the current address of the last accessed device.
+    ///
+    /// Note that the current address does not roll over into the next device.
+    fn read_current_address(&mut self) -> Result<u8, Error<Self::Error>> {
+        match self.devices.get_mut(self.current) {
+            Some(device) => device.read_current_address(),
+            // the current index always refers to one of the devices
+            None => Err(Error::InvalidAddr {
+                address: 0,
+                capacity: self.capacity(),
+            }),
+        }
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        let (device, address) = self.select(address)?;
+        device.write_page(address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        self.devices.first().map_or(1, T::page_size)
+    }
+
+    fn capacity(&self) -> usize {
+        self.device_capacity().saturating_mul(N)
+    }
+
+    fn write_cycle_ms(&self) -> u32 {
+        self.devices.first().map_or(0, T::write_cycle_ms)
+    }
+}
+
+impl<T, D, const N: usize> embedded_storage::ReadStorage for EepromChain<T, D, N>
+where
+    T: Eeprom24xTrait,
+{
+    type Error = Error<T::Error>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.read_data(offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        Eeprom24xTrait::capacity(self)
+    }
+}
+
+impl<T, D, const N: usize> embedded_storage::Storage for EepromChain<T, D, N>
+where
+    T: Eeprom24xTrait,
+    D: DelayNs,
+{
+    fn write(&mut self, mut offset: u32, mut bytes: &[u8]) -> Result<(), Self::Error> {
+        let allowed = Eeprom24xTrait::capacity(self).saturating_sub(offset as usize);
+        if bytes.len() > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: bytes.len(),
+                allowed,
+            });
+        }
+        let page_size = self.page_size();
+        while !bytes.is_empty() {
+            let this_page_offset = offset as usize % page_size;
+            let this_page_remaining = page_size - this_page_offset;
+            let (chunk, rest) = split(bytes, this_page_remaining);
+            self.write_page(offset, chunk)?;
+            offset += chunk.len() as u32;
+            bytes = rest;
+            // devices without write cycle (e.g. FRAMs) can be accessed again immediately
+            let write_cycle_ms = self.write_cycle_ms();
+            if write_cycle_ms > 0 {
+                self.delay.delay_ms(write_cycle_ms);
+            }
+        }
+        Ok(())
+    }
+}
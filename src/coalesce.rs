@@ -0,0 +1,143 @@
+use crate::slice::split;
+use crate::{Eeprom24xTrait, Error, WriteCoalescer};
+use core::cmp::{max, min};
+use embedded_hal::delay::DelayNs;
+
+impl<T, D, const P: usize> WriteCoalescer<T, D, P>
+where
+    T: Eeprom24xTrait,
+    D: DelayNs,
+{
+    /// Compile-time check of the block size.
+    const BLOCK_SIZE_OK: () = assert!(P.is_power_of_two(), "unsupported block size");
+
+    /// Create a new instance wrapping a device.
+    ///
+    /// Returns `Error::InvalidGeometry` if the block size is larger than the device
+    /// page size.
+    pub fn new(eeprom: T, delay: D) -> Result<Self, Error<T::Error>> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::BLOCK_SIZE_OK;
+        if P > eeprom.page_size() {
+            return Err(Error::InvalidGeometry);
+        }
+        Ok(WriteCoalescer {
+            eeprom,
+            delay,
+            buffer: [0; P],
+            pending: None,
+        })
+    }
+
+    /// Write the pending data to the device.
+    pub fn flush(&mut self) -> Result<(), Error<T::Error>> {
+        if let Some((address, len)) = self.pending {
+            let (_, data) = split(&self.buffer, address as usize % P);
+            let (data, _) = split(data, len);
+            self.eeprom.write_page(address, data)?;
+            self.delay.delay_ms(5);
+            self.pending = None;
+        }
+        Ok(())
+    }
+
+    /// Return whether there is data which has not been written to the device yet.
+    pub fn is_dirty(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Add data within a single block to the pending data, flushing it first if the
+    /// data does not continue it.
+    fn add(&mut self, address: u32, data: &[u8]) -> Result<(), Error<T::Error>> {
+        let end = address + data.len() as u32;
+        let merged = match self.pending {
+            Some((start, len)) => {
+                let pending_end = start + len as u32;
+                let same_block = start / P as u32 == address / P as u32;
+                (same_block && address <= pending_end && end >= start)
+                    .then(|| (min(start, address), max(pending_end, end)))
+            }
+            None => Some((address, end)),
+        };
+        let (start, end) = match merged {
+            Some(span) => span,
+            None => {
+                self.flush()?;
+                (address, end)
+            }
+        };
+        let offset = address as usize % P;
+        for (buffered, byte) in self.buffer.iter_mut().skip(offset).zip(data) {
+            *buffered = *byte;
+        }
+        self.pending = Some((start, (end - start) as usize));
+        Ok(())
+    }
+}
+
+impl<T, D, const P: usize> WriteCoalescer<T, D, P> {
+    /// Destroy the wrapper, return the device and the delay instance.
+    ///
+    /// Data which has not been flushed is lost.
+    pub fn destroy(self) -> (T, D) {
+        (self.eeprom, self.delay)
+    }
+}
+
+impl<T, D, const P: usize> embedded_storage::ReadStorage for WriteCoalescer<T, D, P>
+where
+    T: Eeprom24xTrait,
+    D: DelayNs,
+{
+    type Error = Error<T::Error>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.eeprom.read_data(offset, bytes)?;
+        if let Some((address, len)) = self.pending {
+            let start = address as usize % P;
+            let pending = self.buffer.iter().skip(start).take(len);
+            for (current, byte) in (address..).zip(pending) {
+                if let Some(read) = current
+                    .checked_sub(offset)
+                    .and_then(|index| bytes.get_mut(index as usize))
+                {
+                    *read = *byte;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.eeprom.capacity()
+    }
+}
+
+impl<T, D, const P: usize> embedded_storage::Storage for WriteCoalescer<T, D, P>
+where
+    T: Eeprom24xTrait,
+    D: DelayNs,
+{
+    /// Add the data to the pending data.
+    ///
+    /// The data is written to the device with [`flush()`](WriteCoalescer::flush) or when
+    /// a write does not continue the pending data.
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let allowed = self.eeprom.capacity().saturating_sub(offset as usize);
+        if bytes.len() > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: bytes.len(),
+                allowed,
+            });
+        }
+        let mut offset = offset;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let (chunk, rest) = split(bytes, P - offset as usize % P);
+            self.add(offset, chunk)?;
+            offset += chunk.len() as u32;
+            bytes = rest;
+        }
+        Ok(())
+    }
+}
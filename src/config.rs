@@ -0,0 +1,110 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::{ConfigBlock, ConfigError};
+use embedded_storage::Storage;
+
+/// Size of the header: magic, schema version, data length and checksum.
+const HEADER_LEN: usize = 10;
+
+impl<S> ConfigBlock<S> {
+    /// Create a new instance for configurations with the given magic and schema version.
+    ///
+    /// The magic identifies the configuration so that unrelated data is never loaded.
+    pub fn new(storage: S, magic: u32, version: u16) -> Self {
+        ConfigBlock {
+            storage,
+            magic,
+            version,
+        }
+    }
+
+    /// Destroy the configuration block, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S> ConfigBlock<S>
+where
+    S: Storage,
+{
+    /// Return the maximum length of the configuration data in bytes.
+    pub fn max_len(&self) -> usize {
+        self.storage
+            .capacity()
+            .saturating_sub(HEADER_LEN)
+            .min(usize::from(u16::MAX))
+    }
+
+    /// Load the configuration data into the buffer and return its length.
+    ///
+    /// If the stored configuration has an older schema version, it is passed to `migrate`
+    /// together with its version and length. The callback converts the data in place and
+    /// returns its new length, or `None` if the version cannot be migrated. The migrated
+    /// configuration is saved so that the migration is only done once.
+    ///
+    /// Returns `ConfigError::NotFound` if the storage does not contain a configuration
+    /// with this magic and `ConfigError::Corrupted` if it does not match its checksum.
+    pub fn load<F>(&mut self, buffer: &mut [u8], migrate: F) -> Result<usize, ConfigError<S::Error>>
+    where
+        F: FnOnce(u16, &mut [u8], usize) -> Option<usize>,
+    {
+        let mut header = [0; HEADER_LEN];
+        self.storage
+            .read(0, &mut header)
+            .map_err(ConfigError::Storage)?;
+        let [m0, m1, m2, m3, v0, v1, l0, l1, c0, c1] = header;
+        if u32::from_le_bytes([m0, m1, m2, m3]) != self.magic {
+            return Err(ConfigError::NotFound);
+        }
+        let version = u16::from_le_bytes([v0, v1]);
+        let len = usize::from(u16::from_le_bytes([l0, l1]));
+        if len > self.max_len() {
+            return Err(ConfigError::Corrupted);
+        }
+        let data = buffer
+            .get_mut(..len)
+            .ok_or(ConfigError::BufferTooSmall { required: len })?;
+        self.storage
+            .read(HEADER_LEN as u32, data)
+            .map_err(ConfigError::Storage)?;
+        let crc = crc16(crc16(CRC16_INIT, &[m0, m1, m2, m3, v0, v1, l0, l1]), data);
+        if crc != u16::from_le_bytes([c0, c1]) {
+            return Err(ConfigError::Corrupted);
+        }
+
+        if version > self.version {
+            return Err(ConfigError::UnsupportedVersion { version });
+        }
+        if version == self.version {
+            return Ok(len);
+        }
+        let len =
+            migrate(version, buffer, len).ok_or(ConfigError::UnsupportedVersion { version })?;
+        let data = buffer
+            .get(..len)
+            .ok_or(ConfigError::BufferTooSmall { required: len })?;
+        self.save(data)?;
+        Ok(len)
+    }
+
+    /// Save the configuration data with the current schema version.
+    ///
+    /// Returns `ConfigError::TooLarge` if the data is longer than
+    /// [`max_len()`](Self::max_len).
+    pub fn save(&mut self, data: &[u8]) -> Result<(), ConfigError<S::Error>> {
+        if data.len() > self.max_len() {
+            return Err(ConfigError::TooLarge);
+        }
+        let [m0, m1, m2, m3] = self.magic.to_le_bytes();
+        let [v0, v1] = self.version.to_le_bytes();
+        let [l0, l1] = (data.len() as u16).to_le_bytes();
+        let header = [m0, m1, m2, m3, v0, v1, l0, l1];
+        let [c0, c1] = crc16(crc16(CRC16_INIT, &header), data).to_le_bytes();
+        self.storage
+            .write(HEADER_LEN as u32, data)
+            .map_err(ConfigError::Storage)?;
+        self.storage
+            .write(0, &[m0, m1, m2, m3, v0, v1, l0, l1, c0, c1])
+            .map_err(ConfigError::Storage)
+    }
+}
@@ -0,0 +1,33 @@
+//! Checksums protecting the data stored by the higher-level subsystems.
+
+/// Initial value of the CRC-16/CCITT-FALSE checksum.
+pub(crate) const CRC16_INIT: u16 = 0xFFFF;
+
+/// Update a CRC-16/CCITT-FALSE checksum with some data.
+pub(crate) fn crc16(crc: u16, data: &[u8]) -> u16 {
+    data.iter().fold(crc, |crc, byte| {
+        (0..8).fold(crc ^ (u16::from(*byte) << 8), |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_check_value() {
+        assert_eq!(0x29B1, crc16(CRC16_INIT, b"123456789"));
+    }
+
+    #[test]
+    fn can_be_computed_incrementally() {
+        let crc = crc16(CRC16_INIT, b"1234");
+        assert_eq!(0x29B1, crc16(crc, b"56789"));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::slice::{copy, split, split_mut};
+use crate::{CursorError, EepromCursor};
+use embedded_io::{ErrorKind, ErrorType, Read, Seek, SeekFrom, Write};
+use embedded_storage::Storage;
+
+/// Size of the write buffer. Buffered writes are aligned to it, so that they never span
+/// more pages than necessary for devices with pages of up to 64 bytes.
+const BUFFER_LEN: usize = 64;
+
+impl<S> EepromCursor<S> {
+    /// Create a new cursor at the start of the storage.
+    pub fn new(storage: S) -> Self {
+        EepromCursor {
+            storage,
+            position: 0,
+            buffer: [0; BUFFER_LEN],
+            buffer_start: 0,
+            buffered: 0,
+        }
+    }
+
+    /// Destroy the cursor, return the storage.
+    ///
+    /// Buffered data which was not written with [`flush()`](Write::flush) is discarded.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+
+    /// Return the current position.
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+}
+
+impl<S> EepromCursor<S>
+where
+    S: Storage,
+{
+    /// Write the buffered data to the storage.
+    fn write_buffer(&mut self) -> Result<(), CursorError<S::Error>> {
+        if self.buffered > 0 {
+            self.storage
+                .write(self.buffer_start, split(&self.buffer, self.buffered).0)
+                .map_err(CursorError::Storage)?;
+            self.buffered = 0;
+        }
+        Ok(())
+    }
+}
+
+impl<E: core::fmt::Debug> embedded_io::Error for CursorError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            CursorError::Storage(_) => ErrorKind::Other,
+            CursorError::EndOfStorage => ErrorKind::WriteZero,
+            CursorError::InvalidSeek => ErrorKind::InvalidInput,
+        }
+    }
+}
+
+impl<S> ErrorType for EepromCursor<S>
+where
+    S: Storage,
+    S::Error: core::fmt::Debug,
+{
+    type Error = CursorError<S::Error>;
+}
+
+impl<S> Read for EepromCursor<S>
+where
+    S: Storage,
+    S::Error: core::fmt::Debug,
+{
+    /// Read data at the current position, writing the buffered data first.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.write_buffer()?;
+        let remaining = self
+            .storage
+            .capacity()
+            .saturating_sub(self.position as usize);
+        let (data, _) = split_mut(buf, remaining);
+        self.storage
+            .read(self.position, data)
+            .map_err(CursorError::Storage)?;
+        self.position += data.len() as u32;
+        Ok(data.len())
+    }
+}
+
+impl<S> Write for EepromCursor<S>
+where
+    S: Storage,
+    S::Error: core::fmt::Debug,
+{
+    /// Buffer data to write at the current position.
+    ///
+    /// The buffer is written to the storage when the data reaches a 64-byte boundary.
+    /// Returns `CursorError::EndOfStorage` at the end of the storage.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.position as usize >= self.storage.capacity() {
+            return Err(CursorError::EndOfStorage);
+        }
+        if self.buffered > 0 && self.position != self.buffer_start + self.buffered as u32 {
+            self.write_buffer()?;
+        }
+        if self.buffered == 0 {
+            self.buffer_start = self.position;
+        }
+        let start = self.buffer_start as usize % BUFFER_LEN;
+        let boundary =
+            (self.buffer_start as usize - start + BUFFER_LEN).min(self.storage.capacity());
+        let (_, target) = split_mut(&mut self.buffer, self.buffered);
+        let (target, _) = split_mut(target, boundary - self.position as usize);
+        let len = copy(target, buf);
+        self.buffered += len;
+        self.position += len as u32;
+        if self.position as usize == boundary {
+            self.write_buffer()?;
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.write_buffer()
+    }
+}
+
+impl<S> Seek for EepromCursor<S>
+where
+    S: Storage,
+    S::Error: core::fmt::Debug,
+{
+    /// Move the cursor. Positions beyond the end of the storage are allowed, but reading
+    /// there returns no data and writing there fails.
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => (self.storage.capacity() as u64).checked_add_signed(offset),
+            SeekFrom::Current(offset) => u64::from(self.position).checked_add_signed(offset),
+        };
+        self.position = position
+            .and_then(|position| u32::try_from(position).ok())
+            .ok_or(CursorError::InvalidSeek)?;
+        Ok(u64::from(self.position))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Debug> From<CursorError<E>> for std::io::Error {
+    fn from(error: CursorError<E>) -> Self {
+        let kind = match error {
+            CursorError::Storage(_) => std::io::ErrorKind::Other,
+            CursorError::EndOfStorage => std::io::ErrorKind::WriteZero,
+            CursorError::InvalidSeek => std::io::ErrorKind::InvalidInput,
+        };
+        std::io::Error::new(kind, std::string::ToString::to_string(&error))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S> std::io::Read for EepromCursor<S>
+where
+    S: Storage,
+    S::Error: core::fmt::Debug,
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(Read::read(self, buf)?)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S> std::io::Write for EepromCursor<S>
+where
+    S: Storage,
+    S::Error: core::fmt::Debug,
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(Write::write(self, buf)?)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(Write::flush(self)?)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S> std::io::Seek for EepromCursor<S>
+where
+    S: Storage,
+    S::Error: core::fmt::Debug,
+{
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            std::io::SeekFrom::Start(offset) => SeekFrom::Start(offset),
+            std::io::SeekFrom::End(offset) => SeekFrom::End(offset),
+            std::io::SeekFrom::Current(offset) => SeekFrom::Current(offset),
+        };
+        Ok(Seek::seek(self, pos)?)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{Device, DeviceInfo, ParseDeviceError};
+use core::str::FromStr;
+
+macro_rules! impl_devices {
+    ( $( [ $dev:ident, $name:expr, $address_bits:expr, $address_bytes:expr, $page_size:expr, $serial:expr ] ),* ) => {
+        impl Device {
+            /// All devices which can be selected at runtime
+            ///
+            /// These are the EEPROMs with pages of up to 256 bytes. FRAMs (MB85RC and FM24
+            /// series), 24xx1025, 47x16 EERAM and M24LR devices are only supported through
+            /// their [`Eeprom24x`](crate::Eeprom24x) constructors, e.g. `new_mb85rc256()`.
+            pub const ALL: &'static [Device] = &[ $( Device::$dev ),* ];
+
+            /// Get the generic device name, e.g. `"24x256"`
+            pub fn name(self) -> &'static str {
+                match self {
+                    $( Device::$dev => $name ),*
+                }
+            }
+
+            /// Get the part name, e.g. `"AT24C256"`
+            pub fn part(self) -> &'static str {
+                match self {
+                    $( Device::$dev => stringify!($dev) ),*
+                }
+            }
+
+            /// Get the device geometry and features
+            pub fn info(self) -> DeviceInfo {
+                match self {
+                    $( Device::$dev => DeviceInfo {
+                        address_bits: $address_bits,
+                        address_bytes: $address_bytes,
+                        page_size: $page_size,
+                        unique_serial: $serial,
+                    } ),*
+                }
+            }
+        }
+    };
+}
+
+impl_devices!(
+    [MC24C00, "24x00", 4, 1, 1, false],
+    [MC24C01A, "24x01A", 7, 1, 4, false],
+    [MC24C02A, "24x02A", 8, 1, 4, false],
+    [AT24C01, "24x01", 7, 1, 8, false],
+    [AT24C02, "24x02", 8, 1, 8, false],
+    [AT24CS01, "24CSx01", 7, 1, 8, true],
+    [AT24CS02, "24CSx02", 8, 1, 8, true],
+    [AT24CSW010, "24CSWx01", 7, 1, 8, true],
+    [AT24CSW020, "24CSWx02", 8, 1, 8, true],
+    [MC24AA02E48, "24x02E48", 8, 1, 8, true],
+    [MC24AA02E64, "24x02E64", 8, 1, 8, true],
+    [AT24MAC402, "24MAC402", 8, 1, 16, true],
+    [AT24MAC602, "24MAC602", 8, 1, 16, true],
+    [AT24C04, "24x04", 9, 1, 16, false],
+    [AT24C08, "24x08", 10, 1, 16, false],
+    [AT24C16, "24x16", 11, 1, 16, false],
+    [AT24CS04, "24CSx04", 9, 1, 16, true],
+    [AT24CS08, "24CSx08", 10, 1, 16, true],
+    [AT24CS16, "24CSx16", 11, 1, 16, true],
+    [AT24CSW040, "24CSWx04", 9, 1, 16, true],
+    [AT24CSW080, "24CSWx08", 10, 1, 16, true],
+    [AT24CSW160, "24CSWx16", 11, 1, 16, true],
+    [MC24AA025E48, "24x025E48", 8, 1, 16, true],
+    [MC24AA025E64, "24x025E64", 8, 1, 16, true],
+    [M24C01, "M24x01", 7, 1, 16, false],
+    [M24C02, "M24x02", 8, 1, 16, false],
+    [CAT24C01, "CAT24x01", 7, 1, 16, false],
+    [CAT24C02, "CAT24x02", 8, 1, 16, false],
+    [MC34AA02, "34x02", 8, 1, 16, false],
+    [AT24C32, "24x32", 12, 2, 32, false],
+    [AT24C64, "24x64", 13, 2, 32, false],
+    [AT24CS32, "24CSx32", 12, 2, 32, true],
+    [AT24CS64, "24CSx64", 13, 2, 32, true],
+    [MC24CW160, "24CWx16", 11, 2, 32, false],
+    [MC24CW320, "24CWx32", 12, 2, 32, false],
+    [MC24CW640, "24CWx64", 13, 2, 32, false],
+    [MC24CW1280, "24CWx128", 14, 2, 32, false],
+    [AT24C128, "24x128", 14, 2, 64, false],
+    [AT24C256, "24x256", 15, 2, 64, false],
+    [AT24C512, "24x512", 16, 2, 128, false],
+    [AT24CM01, "24xM01", 17, 2, 256, false],
+    [AT24CM02, "24xM02", 18, 2, 256, false]
+);
+
+impl DeviceInfo {
+    /// Device capacity in bytes
+    pub fn capacity(&self) -> usize {
+        1 << self.address_bits
+    }
+}
+
+impl FromStr for Device {
+    type Err = ParseDeviceError;
+
+    /// Parse a device from its part name (e.g. `"AT24C256"`) or its generic
+    /// name (e.g. `"24x256"`), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Device::ALL
+            .iter()
+            .find(|dev| dev.part().eq_ignore_ascii_case(s) || dev.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or(ParseDeviceError(()))
+    }
+}
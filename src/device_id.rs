@@ -0,0 +1,34 @@
+use crate::{DeviceId, Error};
+use embedded_hal::i2c::I2c;
+
+/// Reserved I²C address used for reading the Device ID (`0xF8`/`0xF9` in 8-bit notation).
+const DEVICE_ID_ADDRESS: u8 = 0x7C;
+
+impl DeviceId {
+    fn from_bytes(data: [u8; 3]) -> Self {
+        DeviceId {
+            manufacturer: (u16::from(data[0]) << 4) | u16::from(data[1] >> 4),
+            part: (u16::from(data[1] & 0xF) << 5) | u16::from(data[2] >> 3),
+            revision: data[2] & 0x7,
+        }
+    }
+}
+
+/// Read the Device ID of the device with the given address.
+///
+/// The device address is transmitted to the reserved Device ID address and three bytes
+/// are read back after a repeated start condition.
+pub(crate) fn read_device_id<I2C, E>(
+    i2c: &mut I2C,
+    address: u8,
+    write_pending: bool,
+) -> Result<DeviceId, Error<E>>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    let mut data = [0; 3];
+    i2c.write_read(DEVICE_ID_ADDRESS, &[address << 1], &mut data)
+        .map_err(|e| Error::from_bus(e, write_pending))?;
+    Ok(DeviceId::from_bytes(data))
+}
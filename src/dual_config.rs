@@ -0,0 +1,246 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::split_mut;
+#[cfg(feature = "compression")]
+use crate::Compressor;
+use crate::{ConfigError, DualConfig};
+use embedded_storage::Storage;
+
+/// Size of the slot header: sequence number, data length and checksum.
+const HEADER_LEN: u32 = 8;
+/// Size of the chunks in which the slot data is read to compute its checksum.
+const CHUNK_LEN: usize = 16;
+/// Size of the frame header of compressed data: method and original length.
+#[cfg(feature = "compression")]
+const FRAME_HEADER_LEN: usize = 3;
+/// Frame method of data stored as is.
+#[cfg(feature = "compression")]
+const STORED: u8 = 0;
+/// Frame method of compressed data.
+#[cfg(feature = "compression")]
+const COMPRESSED: u8 = 1;
+
+impl<S> DualConfig<S> {
+    /// Create a new instance splitting the storage into two slots.
+    pub fn new(storage: S) -> Self {
+        DualConfig {
+            storage,
+            active: None,
+            scanned: false,
+        }
+    }
+
+    /// Destroy the configuration, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S> DualConfig<S>
+where
+    S: Storage,
+{
+    /// Return the maximum length of the configuration data in bytes.
+    pub fn max_len(&self) -> usize {
+        (self.slot_len() as usize)
+            .saturating_sub(HEADER_LEN as usize)
+            .min(usize::from(u16::MAX))
+    }
+
+    /// Load the most recently saved valid copy of the configuration into the buffer and
+    /// return its length.
+    ///
+    /// Returns `ConfigError::NotFound` if neither slot contains a valid copy.
+    pub fn load(&mut self, buffer: &mut [u8]) -> Result<usize, ConfigError<S::Error>> {
+        self.scan()?;
+        let (slot, _) = self.active.ok_or(ConfigError::NotFound)?;
+        let (_, len) = self.read_header(slot)?;
+        let data = buffer
+            .get_mut(..len)
+            .ok_or(ConfigError::BufferTooSmall { required: len })?;
+        self.storage
+            .read(slot * self.slot_len() + HEADER_LEN, data)
+            .map_err(ConfigError::Storage)?;
+        Ok(len)
+    }
+
+    /// Save the configuration data.
+    ///
+    /// The data is written to the slot not holding the current copy and the header with
+    /// the next sequence number is written last, which commits the new copy. If the power
+    /// is lost before, the previous copy is loaded at the next boot.
+    ///
+    /// Returns `ConfigError::TooLarge` if the data is longer than
+    /// [`max_len()`](Self::max_len).
+    pub fn save(&mut self, data: &[u8]) -> Result<(), ConfigError<S::Error>> {
+        if data.len() > self.max_len() {
+            return Err(ConfigError::TooLarge);
+        }
+        self.scan()?;
+        let (slot, seq) = match self.active {
+            Some((slot, seq)) => (1 - slot, seq.wrapping_add(1)),
+            None => (0, 0),
+        };
+        let [s0, s1, s2, s3] = seq.to_le_bytes();
+        let [l0, l1] = (data.len() as u16).to_le_bytes();
+        let [c0, c1] = crc16(crc16(CRC16_INIT, &[s0, s1, s2, s3, l0, l1]), data).to_le_bytes();
+        let offset = slot * self.slot_len();
+        self.storage
+            .write(offset + HEADER_LEN, data)
+            .map_err(ConfigError::Storage)?;
+        self.storage
+            .write(offset, &[s0, s1, s2, s3, l0, l1, c0, c1])
+            .map_err(ConfigError::Storage)?;
+        self.active = Some((slot, seq));
+        Ok(())
+    }
+
+    fn slot_len(&self) -> u32 {
+        (self.storage.capacity() / 2) as u32
+    }
+
+    /// Find the valid slot with the most recent sequence number.
+    fn scan(&mut self) -> Result<(), ConfigError<S::Error>> {
+        if self.scanned {
+            return Ok(());
+        }
+        for slot in 0..2 {
+            if let Some(seq) = self.check(slot)? {
+                let newer = match self.active {
+                    // sequence numbers wrap around
+                    Some((_, other)) => seq.wrapping_sub(other) as i32 > 0,
+                    None => true,
+                };
+                if newer {
+                    self.active = Some((slot, seq));
+                }
+            }
+        }
+        self.scanned = true;
+        Ok(())
+    }
+
+    fn read_header(&mut self, slot: u32) -> Result<([u8; 8], usize), ConfigError<S::Error>> {
+        let mut header = [0; HEADER_LEN as usize];
+        self.storage
+            .read(slot * self.slot_len(), &mut header)
+            .map_err(ConfigError::Storage)?;
+        let [_, _, _, _, l0, l1, _, _] = header;
+        Ok((header, usize::from(u16::from_le_bytes([l0, l1]))))
+    }
+
+    /// Return the sequence number of a slot if it holds a valid copy.
+    fn check(&mut self, slot: u32) -> Result<Option<u32>, ConfigError<S::Error>> {
+        let (header, len) = self.read_header(slot)?;
+        if len > self.max_len() {
+            return Ok(None);
+        }
+        let [s0, s1, s2, s3, l0, l1, c0, c1] = header;
+        let mut crc = crc16(CRC16_INIT, &[s0, s1, s2, s3, l0, l1]);
+        let mut chunk = [0; CHUNK_LEN];
+        let mut offset = slot * self.slot_len() + HEADER_LEN;
+        let mut left = len;
+        while left > 0 {
+            let (data, _) = split_mut(&mut chunk, left);
+            let n = data.len();
+            self.storage
+                .read(offset, data)
+                .map_err(ConfigError::Storage)?;
+            crc = crc16(crc, data);
+            offset += n as u32;
+            left -= n;
+        }
+        if crc != u16::from_le_bytes([c0, c1]) {
+            return Ok(None);
+        }
+        Ok(Some(u32::from_le_bytes([s0, s1, s2, s3])))
+    }
+}
+
+#[cfg(feature = "compression")]
+impl<S> DualConfig<S>
+where
+    S: Storage,
+{
+    /// Compress the configuration data and save it.
+    ///
+    /// The data is compressed into the scratch buffer behind a small frame header. If it
+    /// does not become smaller, it is stored as is. Returns `ConfigError::BufferTooSmall`
+    /// if the scratch buffer cannot hold the framed data.
+    pub fn save_compressed<C>(
+        &mut self,
+        data: &[u8],
+        compressor: &mut C,
+        scratch: &mut [u8],
+    ) -> Result<(), ConfigError<S::Error>>
+    where
+        C: Compressor,
+    {
+        let len = u16::try_from(data.len()).map_err(|_| ConfigError::TooLarge)?;
+        let (header, payload) =
+            scratch
+                .split_at_mut_checked(FRAME_HEADER_LEN)
+                .ok_or(ConfigError::BufferTooSmall {
+                    required: FRAME_HEADER_LEN + data.len(),
+                })?;
+        let compressed = compressor
+            .compress(data, payload)
+            .filter(|compressed| *compressed < data.len());
+        let (method, payload_len) = match compressed {
+            Some(compressed) => (COMPRESSED, compressed),
+            None => {
+                payload
+                    .get_mut(..data.len())
+                    .ok_or(ConfigError::BufferTooSmall {
+                        required: FRAME_HEADER_LEN + data.len(),
+                    })?
+                    .copy_from_slice(data);
+                (STORED, data.len())
+            }
+        };
+        let [l0, l1] = len.to_le_bytes();
+        header.copy_from_slice(&[method, l0, l1]);
+        let required = FRAME_HEADER_LEN + payload_len;
+        let framed = scratch
+            .get(..required)
+            .ok_or(ConfigError::BufferTooSmall { required })?;
+        self.save(framed)
+    }
+
+    /// Load configuration data saved with [`save_compressed()`](Self::save_compressed),
+    /// decompress it into the buffer and return its length.
+    ///
+    /// The scratch buffer must hold the framed data as stored. Returns
+    /// `ConfigError::Corrupted` if the data cannot be decompressed.
+    pub fn load_compressed<C>(
+        &mut self,
+        buffer: &mut [u8],
+        compressor: &mut C,
+        scratch: &mut [u8],
+    ) -> Result<usize, ConfigError<S::Error>>
+    where
+        C: Compressor,
+    {
+        let framed_len = self.load(scratch)?;
+        let framed = scratch
+            .get(..framed_len)
+            .ok_or(ConfigError::BufferTooSmall {
+                required: framed_len,
+            })?;
+        let (header, payload) = framed
+            .split_at_checked(FRAME_HEADER_LEN)
+            .ok_or(ConfigError::Corrupted)?;
+        let [method, l0, l1] = header else {
+            return Err(ConfigError::Corrupted);
+        };
+        let len = usize::from(u16::from_le_bytes([*l0, *l1]));
+        let data = buffer
+            .get_mut(..len)
+            .ok_or(ConfigError::BufferTooSmall { required: len })?;
+        match *method {
+            STORED if payload.len() == len => data.copy_from_slice(payload),
+            COMPRESSED if compressor.decompress(payload, data) == Some(len) => (),
+            _ => return Err(ConfigError::Corrupted),
+        }
+        Ok(len)
+    }
+}
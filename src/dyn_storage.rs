@@ -0,0 +1,61 @@
+use crate::{slice::split, DynStorage, Eeprom24xDyn, Eeprom24xTrait, Error};
+use embedded_hal::{delay::DelayNs, i2c::I2c};
+use embedded_storage::ReadStorage;
+
+impl<I2C, D> DynStorage<I2C, D> {
+    /// Create a new instance wrapping the given device
+    pub fn new(eeprom: Eeprom24xDyn<I2C>, delay: D) -> Self {
+        DynStorage { eeprom, delay }
+    }
+
+    /// Destroy driver instance, return I²C bus and timer instance.
+    pub fn destroy(self) -> (I2C, D) {
+        (self.eeprom.destroy(), self.delay)
+    }
+}
+
+impl<I2C, E, D> embedded_storage::ReadStorage for DynStorage<I2C, D>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    type Error = Error<E>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.eeprom.read_data(offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        self.eeprom.capacity()
+    }
+}
+
+impl<I2C, E, D> embedded_storage::Storage for DynStorage<I2C, D>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    D: DelayNs,
+{
+    fn write(&mut self, mut offset: u32, mut bytes: &[u8]) -> Result<(), Self::Error> {
+        let allowed = self.capacity().saturating_sub(offset as usize);
+        if bytes.len() > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: bytes.len(),
+                allowed,
+            });
+        }
+        let page_size = self.eeprom.page_size();
+        while !bytes.is_empty() {
+            let this_page_remaining = page_size - offset as usize % page_size;
+            let (chunk, rest) = split(bytes, this_page_remaining);
+            self.eeprom.write_page(offset, chunk)?;
+            offset += chunk.len() as u32;
+            bytes = rest;
+            let write_cycle_ms = self.eeprom.write_cycle_ms();
+            if write_cycle_ms > 0 {
+                self.delay.delay_ms(write_cycle_ms);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use crate::slice::{copy, split, split_mut};
+use crate::{EccError, EccEvent, EccStorage};
+use embedded_storage::{ReadStorage, Storage};
+
+/// Number of data bytes protected by a check byte.
+const BLOCK_DATA_LEN: usize = 8;
+/// Size of a block in the storage: data and check byte.
+const BLOCK_LEN: usize = BLOCK_DATA_LEN + 1;
+/// Number of blocks written at once.
+const WRITE_BLOCKS: usize = 8;
+
+impl<S, F> EccStorage<S, F>
+where
+    F: FnMut(EccEvent),
+{
+    /// Create a new instance calling `report` for each corrected or uncorrectable error.
+    pub fn new(storage: S, report: F) -> Self {
+        EccStorage { storage, report }
+    }
+
+    /// Destroy the wrapper, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S, F> EccStorage<S, F>
+where
+    S: ReadStorage,
+    F: FnMut(EccEvent),
+{
+    /// Read and decode a block, correcting single-bit errors.
+    fn read_block(&mut self, block: u32) -> Result<[u8; BLOCK_DATA_LEN], EccError<S::Error>> {
+        let mut raw = [0; BLOCK_LEN];
+        self.storage
+            .read(block * BLOCK_LEN as u32, &mut raw)
+            .map_err(EccError::Storage)?;
+        let [d0, d1, d2, d3, d4, d5, d6, d7, check] = raw;
+        let data = u64::from_le_bytes([d0, d1, d2, d3, d4, d5, d6, d7]);
+        let offset = block * BLOCK_DATA_LEN as u32;
+        match decode(data, check) {
+            Some((data, corrected)) => {
+                if corrected {
+                    (self.report)(EccEvent::Corrected { offset });
+                }
+                Ok(data.to_le_bytes())
+            }
+            None => {
+                (self.report)(EccEvent::Uncorrectable { offset });
+                Err(EccError::Uncorrectable { offset })
+            }
+        }
+    }
+
+    fn check_bounds(&self, offset: u32, len: usize) -> Result<(), EccError<S::Error>> {
+        if len > self.capacity().saturating_sub(offset as usize) {
+            return Err(EccError::OutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+impl<S, F> ReadStorage for EccStorage<S, F>
+where
+    S: ReadStorage,
+    F: FnMut(EccEvent),
+{
+    type Error = EccError<S::Error>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.check_bounds(offset, bytes.len())?;
+        let mut offset = offset as usize;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let data = self.read_block((offset / BLOCK_DATA_LEN) as u32)?;
+            let len = copy(bytes, split(&data, offset % BLOCK_DATA_LEN).1);
+            offset += len;
+            bytes = split_mut(core::mem::take(&mut bytes), len).1;
+        }
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.storage.capacity() / BLOCK_LEN * BLOCK_DATA_LEN
+    }
+}
+
+impl<S, F> Storage for EccStorage<S, F>
+where
+    S: Storage,
+    F: FnMut(EccEvent),
+{
+    /// Write data and its check bytes.
+    ///
+    /// Blocks which are only partially written are read and corrected first.
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check_bounds(offset, bytes.len())?;
+        let mut offset = offset as usize;
+        let mut bytes = bytes;
+        let mut buffer = [0; BLOCK_LEN * WRITE_BLOCKS];
+        while !bytes.is_empty() {
+            let first_block = offset / BLOCK_DATA_LEN;
+            let mut count = 0;
+            for raw in buffer.chunks_exact_mut(BLOCK_LEN) {
+                if bytes.is_empty() {
+                    break;
+                }
+                let block = first_block + count;
+                let start = offset % BLOCK_DATA_LEN;
+                let len = bytes.len().min(BLOCK_DATA_LEN - start);
+                let mut data = if len < BLOCK_DATA_LEN {
+                    self.read_block(block as u32)?
+                } else {
+                    [0; BLOCK_DATA_LEN]
+                };
+                copy(split_mut(&mut data, start).1, bytes);
+                let check = encode(u64::from_le_bytes(data));
+                let (raw_data, raw_check) = split_mut(raw, BLOCK_DATA_LEN);
+                copy(raw_data, &data);
+                copy(raw_check, &[check]);
+                offset += len;
+                bytes = split(bytes, len).1;
+                count += 1;
+            }
+            let (raw, _) = split(&buffer, count * BLOCK_LEN);
+            self.storage
+                .write((first_block * BLOCK_LEN) as u32, raw)
+                .map_err(EccError::Storage)?;
+        }
+        Ok(())
+    }
+}
+
+/// Return the positions in the Hamming code word of the data bits, skipping the positions
+/// of the parity bits (powers of two).
+fn data_positions() -> impl Iterator<Item = u8> {
+    (3..=71u8).filter(|position| !position.is_power_of_two())
+}
+
+/// Return the XOR of the positions of the set data bits.
+fn syndrome(data: u64) -> u8 {
+    data_positions()
+        .enumerate()
+        .filter(|(bit, _)| data >> bit & 1 != 0)
+        .fold(0, |syndrome, (_, position)| syndrome ^ position)
+}
+
+/// Return the check byte of an extended Hamming (72, 64) code: seven Hamming parity bits
+/// and the overall parity in the most significant bit.
+fn encode(data: u64) -> u8 {
+    let parity_bits = syndrome(data);
+    let overall = (data.count_ones() + parity_bits.count_ones()) & 1;
+    parity_bits | (overall as u8) << 7
+}
+
+/// Decode a code word, returning the data and whether an error was corrected, or `None`
+/// if a double-bit error was detected.
+fn decode(data: u64, check: u8) -> Option<(u64, bool)> {
+    let error_position = syndrome(data) ^ (check & 0x7F);
+    let overall = (data.count_ones() + check.count_ones()) & 1;
+    match (error_position, overall) {
+        (0, 0) => Some((data, false)),
+        (_, 0) => None,
+        // a parity bit or the overall parity bit was flipped
+        (position, _) if position == 0 || position.is_power_of_two() => Some((data, true)),
+        (position, _) => {
+            let bit = data_positions().position(|p| p == position)?;
+            Some((data ^ 1 << bit, true))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corrects_every_single_bit_error() {
+        let data = 0x0123_4567_89AB_CDEF;
+        let check = encode(data);
+        assert_eq!(Some((data, false)), decode(data, check));
+        for bit in 0..64 {
+            assert_eq!(Some((data, true)), decode(data ^ 1 << bit, check));
+        }
+        for bit in 0..8 {
+            assert_eq!(Some((data, true)), decode(data, check ^ 1 << bit));
+        }
+    }
+
+    #[test]
+    fn detects_double_bit_errors() {
+        let data = 0xFEDC_BA98_7654_3210;
+        let check = encode(data);
+        for bit in 1..64 {
+            assert_eq!(None, decode(data ^ 1 ^ 1 << bit, check));
+        }
+        assert_eq!(None, decode(data ^ 1, check ^ 1));
+    }
+}
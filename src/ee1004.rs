@@ -0,0 +1,190 @@
+use crate::{access::finish_access, eeprom24x::build_payload, Ee1004, Error, SlaveAddr};
+use embedded_hal::i2c::I2c;
+
+/// Device addresses of the set page address commands (SPA0, SPA1).
+const SPA_ADDR: [u8; 2] = [0b011_0110, 0b011_0111];
+/// Size in bytes of the memory pages selected with the SPA commands.
+const MEMORY_PAGE_SIZE: u32 = 256;
+/// Total capacity in bytes.
+const CAPACITY: u32 = 2 * MEMORY_PAGE_SIZE;
+/// Size in bytes of the pages for page writes.
+const WRITE_PAGE_SIZE: usize = 16;
+
+/// Common methods
+impl<I2C> Ee1004<I2C> {
+    /// Create a new instance of a EE1004 device (e.g. 34x04)
+    pub fn new(i2c: I2C, address: SlaveAddr) -> Self {
+        Ee1004 {
+            i2c,
+            address,
+            write_pending: false,
+        }
+    }
+
+    /// Destroy driver instance, return I²C bus instance.
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+
+    /// Return device page size for page writes
+    pub fn page_size(&self) -> usize {
+        WRITE_PAGE_SIZE
+    }
+
+    /// Return device capacity in bytes
+    pub fn capacity(&self) -> usize {
+        CAPACITY as usize
+    }
+}
+
+/// Device access methods
+impl<I2C, E> Ee1004<I2C>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Classify the bus error of a memory access and keep track of pending write cycles.
+    fn finish_access(&mut self, result: Result<(), E>, write: bool) -> Result<(), Error<E>> {
+        finish_access(&mut self.write_pending, result, write)
+    }
+
+    /// Write a single byte in an address.
+    ///
+    /// After writing a byte, the EEPROM enters an internally-timed write cycle
+    /// to the nonvolatile memory.
+    /// During this time all inputs are disabled and the EEPROM will not
+    /// respond until the write is complete.
+    pub fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<E>> {
+        self.write_page(address, &[data])
+    }
+
+    /// Read a single byte from an address.
+    pub fn read_byte(&mut self, address: u32) -> Result<u8, Error<E>> {
+        let mut data = [0; 1];
+        self.read_data(address, &mut data).and(Ok(data[0]))
+    }
+
+    /// Read starting in an address as many bytes as necessary to fill the data array provided.
+    ///
+    /// Reads crossing the boundary between the memory pages are split.
+    pub fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<E>> {
+        if address >= CAPACITY || data.len() as u32 > CAPACITY - address {
+            return Err(Error::InvalidAddr {
+                address,
+                capacity: CAPACITY as usize,
+            });
+        }
+        let mut address = address;
+        let mut data = data;
+        while !data.is_empty() {
+            let len = ((MEMORY_PAGE_SIZE - address % MEMORY_PAGE_SIZE) as usize).min(data.len());
+            let (chunk, rest) = data.split_at_mut_checked(len).ok_or(Error::InvalidAddr {
+                address,
+                capacity: CAPACITY as usize,
+            })?;
+            self.select_page(address)?;
+            let result = self
+                .i2c
+                .write_read(self.address.addr(), &[address as u8], chunk);
+            self.finish_access(result, false)?;
+            address += len as u32;
+            data = rest;
+        }
+        Ok(())
+    }
+
+    /// Read the contents of the last address accessed during the last read
+    /// or write operation, _incremented by one_.
+    ///
+    /// Note: This may not be available on your platform.
+    pub fn read_current_address(&mut self) -> Result<u8, Error<E>> {
+        let mut data = [0];
+        let result = self.i2c.read(self.address.addr(), &mut data);
+        self.finish_access(result, false).and(Ok(data[0]))
+    }
+
+    /// Write up to a page starting in an address.
+    ///
+    /// The maximum amount of data that can be written depends on the page
+    /// size of the device. If the data would cross a page boundary, the error
+    /// `Error::PageOverflow` will be returned.
+    ///
+    /// After writing a byte, the EEPROM enters an internally-timed write cycle
+    /// to the nonvolatile memory.
+    /// During this time all inputs are disabled and the EEPROM will not
+    /// respond until the write is complete.
+    pub fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        if address >= CAPACITY {
+            return Err(Error::InvalidAddr {
+                address,
+                capacity: CAPACITY as usize,
+            });
+        }
+        let allowed = WRITE_PAGE_SIZE - address as usize % WRITE_PAGE_SIZE;
+        if data.len() > allowed {
+            return Err(Error::PageOverflow {
+                requested: data.len(),
+                allowed,
+            });
+        }
+
+        self.select_page(address)?;
+        let mut buffer = [0; 1 + WRITE_PAGE_SIZE];
+        let payload = build_payload(&mut buffer, &[address as u8], data)?;
+        let result = self.i2c.write(self.address.addr(), payload);
+        self.finish_access(result, true)
+    }
+
+    /// Select the memory page containing an address.
+    fn select_page(&mut self, address: u32) -> Result<(), Error<E>> {
+        let page = (address / MEMORY_PAGE_SIZE) as usize;
+        let spa_addr = SPA_ADDR.get(page).ok_or(Error::InvalidAddr {
+            address,
+            capacity: CAPACITY as usize,
+        })?;
+        // the command requires a dummy data byte. It is broadcast to all devices on the bus,
+        // so an acknowledge does not tell whether the write cycle is finished.
+        self.i2c
+            .write(*spa_addr, &[0])
+            .map_err(|e| Error::from_bus(e, self.write_pending))
+    }
+}
+
+impl<I2C, E> crate::Eeprom24xTrait for Ee1004<I2C>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    type Error = E;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<Self::Error>> {
+        self.write_byte(address, data)
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Error<Self::Error>> {
+        self.read_byte(address)
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<Self::Error>> {
+        self.read_data(address, data)
+    }
+
+    fn read_current_address(&mut self) -> Result<u8, Error<Self::Error>> {
+        self.read_current_address()
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        self.write_page(address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        WRITE_PAGE_SIZE
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+}
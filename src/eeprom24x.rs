@@ -1,27 +1,60 @@
-use crate::{addr_size, page_size, private, unique_serial, Eeprom24x, Error, SlaveAddr};
+use crate::access::{finish_access, Access, Geometry};
+use crate::device_id::read_device_id;
+use crate::probe::probe;
+use crate::recover::bus_recover;
+use crate::{
+    addr_size, page_size, private, unique_serial, DeviceId, Eeprom24x, Eeprom24xDyn, Error,
+    GeometryMismatch, SlaveAddr,
+};
 use core::marker::PhantomData;
-use embedded_hal::i2c::I2c;
+use embedded_hal::{delay::DelayNs, i2c::I2c};
 pub trait MultiSizeAddr: private::Sealed {
     const ADDRESS_BYTES: usize;
 
-    fn fill_address(address: u32, payload: &mut [u8]);
+    /// Whether the upper 64 KiB are selected with the A2 bit of the device address
+    const BLOCK_SELECT: bool = false;
 }
 
+/// Memory address sizes supported by the runtime-geometry driver
+pub trait DynAddr: MultiSizeAddr {}
+
 impl MultiSizeAddr for addr_size::OneByte {
     const ADDRESS_BYTES: usize = 1;
-
-    fn fill_address(address: u32, payload: &mut [u8]) {
-        payload[0] = address as u8;
-    }
 }
 
 impl MultiSizeAddr for addr_size::TwoBytes {
     const ADDRESS_BYTES: usize = 2;
+}
+
+impl MultiSizeAddr for addr_size::TwoBytesBlockSelect {
+    const ADDRESS_BYTES: usize = 2;
+    const BLOCK_SELECT: bool = true;
+}
+
+impl DynAddr for addr_size::OneByte {}
+impl DynAddr for addr_size::TwoBytes {}
 
-    fn fill_address(address: u32, payload: &mut [u8]) {
-        payload[0] = (address >> 8) as u8;
-        payload[1] = address as u8;
+/// Copy the memory address followed by the data into the buffer and return the bytes
+/// to transmit.
+///
+/// Returns `Error::PageOverflow` if the data does not fit into the buffer.
+pub(crate) fn build_payload<'a, E>(
+    buffer: &'a mut [u8],
+    memaddr: &[u8],
+    data: &[u8],
+) -> Result<&'a [u8], Error<E>> {
+    let len = memaddr.len().saturating_add(data.len());
+    let allowed = buffer.len().saturating_sub(memaddr.len());
+    let (payload, _) = buffer
+        .split_at_mut_checked(len)
+        .ok_or(Error::PageOverflow {
+            requested: data.len(),
+            allowed,
+        })?;
+    for (byte, value) in payload.iter_mut().zip(memaddr.iter().chain(data)) {
+        *byte = *value;
     }
+    Ok(payload)
 }
 
 /// Common methods
@@ -30,29 +63,100 @@ impl<I2C, PS, AS, SN> Eeprom24x<I2C, PS, AS, SN> {
     pub fn destroy(self) -> I2C {
         self.i2c
     }
+
+    /// Return device capacity in bytes
+    pub fn capacity(&self) -> usize {
+        1usize
+            .checked_shl(u32::from(self.address_bits))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Set the device address used for subsequent accesses.
+    ///
+    /// This allows using one driver instance to access several devices of the same type
+    /// on the same bus sequentially, e.g. cascaded chips. Note that the device does not
+    /// respond to other addresses during an internally-timed write cycle but other devices
+    /// can be accessed in the meantime.
+    pub fn set_address(&mut self, address: SlaveAddr) {
+        self.address = address;
+    }
+
+    /// Return the device address currently in use.
+    pub fn address(&self) -> SlaveAddr {
+        self.address
+    }
+
+    /// Set the maximum number of bytes read in a single transfer.
+    ///
+    /// Longer reads are split into several transfers. This is necessary for I²C
+    /// peripherals which cannot transfer more than a certain number of bytes at once,
+    /// e.g. SMBus adapters limited to 32 bytes. By default, reads are not split.
+    /// A length of 0 is treated as 1.
+    pub fn set_max_transfer_len(&mut self, len: usize) {
+        self.max_transfer_len = len.max(1);
+    }
+
+    /// Classify the bus error of a memory access and keep track of pending write cycles.
+    pub(crate) fn finish_access<E>(
+        &mut self,
+        result: Result<(), E>,
+        write: bool,
+    ) -> Result<(), Error<E>>
+    where
+        E: embedded_hal::i2c::Error,
+    {
+        finish_access(&mut self.write_pending, result, write)
+    }
 }
 
 impl<I2C, PS, AS, SN> Eeprom24x<I2C, PS, AS, SN>
 where
     AS: MultiSizeAddr,
 {
-    fn get_device_address<E>(&self, memory_address: u32) -> Result<u8, Error<E>> {
-        if memory_address >= (1 << self.address_bits) {
-            return Err(Error::InvalidAddr);
+    /// Return the 7-bit I²C address used when accessing the given memory address.
+    ///
+    /// Some devices (e.g. 24x16) use some of the device address bits for memory addressing,
+    /// so that different memory addresses are accessed through different I²C addresses.
+    /// Returns `None` if the memory address is out of range or if the slave address cannot
+    /// be used with this device.
+    pub fn device_address(&self, memory_address: u32) -> Option<u8> {
+        self.get_device_address::<()>(memory_address).ok()
+    }
+
+    pub(crate) fn get_device_address<E>(&self, memory_address: u32) -> Result<u8, Error<E>> {
+        self.geometry().device_address(memory_address)
+    }
+
+    /// Return the runtime description of the device addressing.
+    pub(crate) fn geometry(&self) -> Geometry {
+        Geometry {
+            address: self.address,
+            address_bits: self.address_bits,
+            address_bytes: AS::ADDRESS_BYTES as u8,
+            block_select: AS::BLOCK_SELECT,
+        }
+    }
+
+    /// Access the memory through the non-generic access logic.
+    pub(crate) fn access(&mut self) -> Access<'_, I2C> {
+        Access {
+            geometry: self.geometry(),
+            i2c: &mut self.i2c,
+            write_pending: &mut self.write_pending,
+            max_transfer_len: self.max_transfer_len,
+            checked: true,
         }
-        let addr = self.address.devaddr(
-            memory_address,
-            self.address_bits,
-            AS::ADDRESS_BYTES as u8 * 8,
-        );
-        Ok(addr)
     }
 }
 
 /// Common methods
+///
+/// If the device does not acknowledge its address, `Error::Busy` is returned while it may
+/// still be in the write cycle of a previous write and `Error::NoDevice` otherwise.
 impl<I2C, E, PS, AS, SN> Eeprom24x<I2C, PS, AS, SN>
 where
     I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
     AS: MultiSizeAddr,
 {
     /// Write a single byte in an address.
@@ -62,35 +166,188 @@ where
     /// During this time all inputs are disabled and the EEPROM will not
     /// respond until the write is complete.
     pub fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<E>> {
-        let devaddr = self.get_device_address(address)?;
-        let mut payload = [0; 3];
-        AS::fill_address(address, &mut payload);
-        payload[AS::ADDRESS_BYTES] = data;
-        self.i2c
-            .write(devaddr, &payload[..=AS::ADDRESS_BYTES])
-            .map_err(Error::I2C)
+        self.access().write_byte(address, data)
     }
 
     /// Read a single byte from an address.
     pub fn read_byte(&mut self, address: u32) -> Result<u8, Error<E>> {
-        let devaddr = self.get_device_address(address)?;
-        let mut memaddr = [0; 2];
-        AS::fill_address(address, &mut memaddr);
         let mut data = [0; 1];
-        self.i2c
-            .write_read(devaddr, &memaddr[..AS::ADDRESS_BYTES], &mut data)
-            .map_err(Error::I2C)
-            .and(Ok(data[0]))
+        self.read_data(address, &mut data).and(Ok(data[0]))
     }
 
     /// Read starting in an address as many bytes as necessary to fill the data array provided.
+    ///
+    /// The read is split into several transfers if it is longer than the maximum transfer
+    /// length. See [`set_max_transfer_len()`](Self::set_max_transfer_len).
     pub fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<E>> {
-        let devaddr = self.get_device_address(address)?;
-        let mut memaddr = [0; 2];
-        AS::fill_address(address, &mut memaddr);
-        self.i2c
-            .write_read(devaddr, &memaddr[..AS::ADDRESS_BYTES], data)
-            .map_err(Error::I2C)
+        self.access().read_data(address, data, false)
+    }
+
+    /// Read starting in an address as many bytes as necessary to fill the data array provided
+    /// without validating the address.
+    ///
+    /// This is intended for hot loops where the caller has already validated the addresses.
+    /// No `Error::InvalidAddr` or `Error::InvalidSlaveAddr` is returned. Instead, addresses
+    /// beyond the capacity wrap around to the start of the memory, like the device itself
+    /// does when a read continues past the last byte.
+    pub fn read_data_unchecked(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<E>> {
+        let mut access = self.access();
+        access.checked = false;
+        access.read_data(address, data, false)
+    }
+
+    /// Read starting in an address as many bytes as necessary to fill the data array provided,
+    /// sending the memory address only once.
+    ///
+    /// Like `read_data()`, the read is split into transfers of at most the maximum transfer
+    /// length. However, only the first transfer sends the memory address. The following ones
+    /// are plain reads continuing at the internal address counter of the device (sequential
+    /// current address reads), which makes reading large amounts of data faster, e.g. when
+    /// dumping the whole memory. The memory address is sent again only where the device
+    /// address changes.
+    ///
+    /// The internal address counter must not be modified by other bus masters during the dump.
+    pub fn dump(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<E>> {
+        self.access().read_data(address, data, true)
+    }
+
+    /// Read starting in an address as many bytes as necessary to fill the buffers provided
+    /// back-to-back.
+    ///
+    /// This allows reading data which must land in separate places without intermediate
+    /// copies. Up to 8 buffers are read in a single transaction. If a maximum transfer
+    /// length has been set, each buffer is read with `read_data()` instead.
+    pub fn read_data_vectored(
+        &mut self,
+        address: u32,
+        buffers: &mut [&mut [u8]],
+    ) -> Result<(), Error<E>> {
+        self.access().read_data_vectored(address, buffers)
+    }
+
+    /// Read `len` bytes starting in an address into a new `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn read_vec(&mut self, address: u32, len: usize) -> Result<alloc::vec::Vec<u8>, Error<E>> {
+        let mut data = alloc::vec![0; len];
+        self.read_data(address, &mut data)?;
+        Ok(data)
+    }
+
+    /// Read the whole memory into a new `Vec`.
+    ///
+    /// Like [`dump()`](Self::dump), the memory address is only sent where necessary.
+    #[cfg(feature = "alloc")]
+    pub fn read_all_vec(&mut self) -> Result<alloc::vec::Vec<u8>, Error<E>> {
+        let mut data = alloc::vec![0; self.capacity()];
+        self.dump(0, &mut data)?;
+        Ok(data)
+    }
+
+    /// Read up to `N` bytes starting in an address into a new `heapless::Vec`.
+    ///
+    /// Fewer bytes are read if the end of the memory is reached before.
+    #[cfg(feature = "heapless")]
+    pub fn read_heapless<const N: usize>(
+        &mut self,
+        address: u32,
+    ) -> Result<heapless::Vec<u8, N>, Error<E>> {
+        let len = N.min(self.capacity().saturating_sub(address as usize));
+        let mut data = heapless::Vec::new();
+        // cannot fail as the length does not exceed N
+        let _ = data.resize_default(len);
+        self.read_data(address, &mut data)?;
+        Ok(data)
+    }
+}
+
+impl<I2C, E, PS, AS, SN> Eeprom24x<I2C, PS, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+{
+    /// Check whether the device is present on the bus.
+    ///
+    /// This issues a harmless zero-length write to the device address and returns whether
+    /// the device acknowledged it. Bus errors other than a missing acknowledge are returned
+    /// so that a missing or unpowered device can be distinguished from transient errors.
+    ///
+    /// Note that the device does not acknowledge during an internally-timed write cycle.
+    /// Some platforms do not support zero-length writes.
+    pub fn is_present(&mut self) -> Result<bool, Error<E>> {
+        let devaddr = self.get_device_address(0)?;
+        probe(&mut self.i2c, devaddr)
+    }
+
+    /// Recover the device after a read was interrupted mid-transfer.
+    ///
+    /// If the controller is reset during a read, the device may keep holding SDA low while
+    /// waiting for further clock cycles, which blocks the bus. This issues the software
+    /// reset sequence described in the datasheets (START, 9 clock cycles with SDA high,
+    /// START, STOP) expressed as dummy transactions. Missing acknowledges and arbitration
+    /// losses are expected during the sequence and are not reported. Other bus errors are
+    /// returned.
+    ///
+    /// Note that some platforms do not support zero-length transfers and that some I²C
+    /// peripherals refuse to start a transfer while SDA is held low. In that case the bus
+    /// must be recovered by toggling SCL as GPIO.
+    pub fn bus_recover(&mut self) -> Result<(), Error<E>> {
+        let devaddr = self.get_device_address(0)?;
+        bus_recover(&mut self.i2c, devaddr)
+    }
+}
+
+impl<I2C, E, PS, AS, SN> Eeprom24x<I2C, PS, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    PS: WriteCycle,
+    AS: MultiSizeAddr,
+{
+    /// Check that the configured device type matches the hardware.
+    ///
+    /// A marker is written to the memory address 0 and read back from the same address
+    /// and from the middle of the configured memory. If the marker cannot be read back,
+    /// `Error::GeometryMismatch(GeometryMismatch::AddressSize)` is returned. If the marker
+    /// shows up in the middle of the memory, the device is smaller than configured and
+    /// `Error::GeometryMismatch(GeometryMismatch::Capacity)` is returned.
+    /// The original contents are restored afterwards. The delay is used to wait for the
+    /// write cycles to finish.
+    ///
+    /// Note that if the memory address size does not match, the device may interpret
+    /// some of the memory address bytes as data so that the contents of the first bytes of
+    /// the memory can be modified.
+    pub fn validate_geometry<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<E>> {
+        let middle = 1 << (self.address_bits - 1);
+        let original = self.read_byte(0)?;
+        let original_middle = self.read_byte(middle)?;
+        self.write_byte(0, !original)?;
+        Self::wait_write_cycle(delay);
+        let result = self.check_marker(middle, !original, original_middle);
+        self.write_byte(0, original)?;
+        Self::wait_write_cycle(delay);
+        result
+    }
+
+    fn wait_write_cycle<D: DelayNs>(delay: &mut D) {
+        if PS::WRITE_CYCLE_MS > 0 {
+            delay.delay_ms(PS::WRITE_CYCLE_MS);
+        }
+    }
+
+    fn check_marker(
+        &mut self,
+        middle: u32,
+        marker: u8,
+        original_middle: u8,
+    ) -> Result<(), Error<E>> {
+        if self.read_byte(0)? != marker {
+            return Err(Error::GeometryMismatch(GeometryMismatch::AddressSize));
+        }
+        if self.read_byte(middle)? != original_middle {
+            return Err(Error::GeometryMismatch(GeometryMismatch::Capacity));
+        }
+        Ok(())
     }
 }
 
@@ -98,6 +355,7 @@ where
 impl<I2C, E, PS, AS, SN> Eeprom24x<I2C, PS, AS, SN>
 where
     I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
 {
     /// Read the contents of the last address accessed during the last read
     /// or write operation, _incremented by one_.
@@ -105,14 +363,22 @@ where
     /// Note: This may not be available on your platform.
     pub fn read_current_address(&mut self) -> Result<u8, Error<E>> {
         let mut data = [0];
-        self.i2c
-            .read(self.address.addr(), &mut data)
-            .map_err(Error::I2C)
-            .and(Ok(data[0]))
+        let result = self.i2c.read(self.address.addr(), &mut data);
+        self.finish_access(result, false).and(Ok(data[0]))
+    }
+
+    /// Read the I²C-bus Device ID.
+    ///
+    /// This can be used to confirm the populated part at runtime. Devices which do not
+    /// support the Device ID do not acknowledge the reserved address and `Error::NoDevice`
+    /// is returned.
+    pub fn read_device_id(&mut self) -> Result<DeviceId, Error<E>> {
+        read_device_id(&mut self.i2c, self.address.addr(), self.write_pending)
     }
 }
 
 /// Specialization for devices without page access (e.g. 24C00)
+#[cfg(feature = "one-byte-addr")]
 impl<I2C, E> Eeprom24x<I2C, page_size::No, addr_size::OneByte, unique_serial::No>
 where
     I2C: I2c<Error = E>,
@@ -123,6 +389,9 @@ where
             i2c,
             address,
             address_bits: 4,
+            secure_region_address: None,
+            write_pending: false,
+            max_transfer_len: usize::MAX,
             _ps: PhantomData,
             _as: PhantomData,
             _sn: PhantomData,
@@ -130,6 +399,51 @@ where
     }
 }
 
+impl<I2C> Eeprom24x<I2C, page_size::No, addr_size::OneByte, unique_serial::No> {
+    /// Convert into a runtime-geometry driver instance, keeping the I²C bus.
+    pub fn into_dyn(self) -> Eeprom24xDyn<I2C> {
+        let mut eeprom = Eeprom24xDyn::new(self.i2c, self.address, self.address_bits, 1, 1);
+        eeprom.max_transfer_len = self.max_transfer_len;
+        eeprom
+    }
+}
+
+impl<I2C, E, AS, SN> PageWrite<E> for Eeprom24x<I2C, page_size::No, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+{
+    /// Devices without page access are written byte by byte.
+    fn page_write(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        match data {
+            [] => Ok(()),
+            [byte] => self.write_byte(address, *byte),
+            _ => Err(Error::PageOverflow {
+                requested: data.len(),
+                allowed: 1,
+            }),
+        }
+    }
+
+    fn page_size(&self) -> usize {
+        1
+    }
+}
+
+/// Runtime-geometry specialization for devices without page access (e.g. 24C00)
+#[cfg(feature = "one-byte-addr")]
+impl<I2C, E> Eeprom24xDyn<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create a new instance of a 24x00 device (e.g. 24C00)
+    pub fn new_24x00(i2c: I2C, address: SlaveAddr) -> Self {
+        Self::new(i2c, address, 4, 1, 1)
+    }
+}
+
+#[cfg(any(feature = "one-byte-addr", feature = "two-byte-addr"))]
 macro_rules! impl_create {
     ( $dev:expr, $part:expr, $address_bits:expr, $create:ident ) => {
         impl_create! {
@@ -146,147 +460,202 @@ macro_rules! impl_create {
     };
 }
 
-// This macro could be simplified once https://github.com/rust-lang/rust/issues/42863 is fixed.
-macro_rules! impl_for_page_size {
-    ( $AS:ident, $addr_bytes:expr, $PS:ident, $page_size:expr,
-        $( [ $dev:expr, $part:expr, $address_bits:expr, $SN:ident, $create:ident ] ),* ) => {
-        impl_for_page_size!{
-            @gen [$AS, $addr_bytes, $PS, $page_size,
-            concat!("Specialization for devices with a page size of ", stringify!($page_size), " bytes."),
-            concat!("Create generic instance for devices with a page size of ", stringify!($page_size), " bytes."),
-            $( [ $dev, $part, $address_bits, $SN, $create ] ),* ]
+#[cfg(any(feature = "one-byte-addr", feature = "two-byte-addr"))]
+macro_rules! impl_create_dyn {
+    ( $dev:expr, $part:expr, $address_bits:expr, $addr_bytes:expr, $page_size:expr, $create:ident ) => {
+        impl_create_dyn! {
+            @gen [$create, $address_bits, $addr_bytes, $page_size,
+                concat!("Create a new runtime-geometry instance of a ", $dev, " device (e.g. ", $part, ")")]
         }
     };
 
-    (@gen [$AS:ident, $addr_bytes:expr, $PS:ident, $page_size:expr, $doc_impl:expr, $doc_new:expr,
-        $( [ $dev:expr, $part:expr, $address_bits:expr, $SN:ident, $create:ident ] ),* ] ) => {
-
-            $(
-            impl<I2C, E> Eeprom24x<I2C, page_size::$PS, addr_size::$AS, unique_serial::$SN>
-            where
-                I2C: I2c<Error = E>
-            {
-                impl_create!($dev, $part, $address_bits, $create);
-            }
-            )*
-
-            #[doc = $doc_impl]
-            impl<I2C, E, SN> Eeprom24x<I2C, page_size::$PS, addr_size::$AS, SN>
-            where
-                I2C: I2c<Error = E>
-            {
-            #[doc = $doc_new]
-            fn new(i2c: I2C, address: SlaveAddr, address_bits: u8) -> Self {
-                Eeprom24x {
-                    i2c,
-                    address,
-                    address_bits,
-                    _ps: PhantomData,
-                    _as: PhantomData,
-                    _sn: PhantomData,
-                }
-            }
+    (@gen [$create:ident, $address_bits:expr, $addr_bytes:expr, $page_size:expr, $doc:expr] ) => {
+        #[doc = $doc]
+        pub fn $create(i2c: I2C, address: SlaveAddr) -> Self {
+            Self::new(i2c, address, $address_bits, $addr_bytes, $page_size)
         }
+    };
+}
 
-        impl<I2C, E, AS, SN> Eeprom24x<I2C, page_size::$PS, AS, SN>
-        where
-            I2C: I2c<Error = E>,
-            AS: MultiSizeAddr,
-        {
-            /// Write up to a page starting in an address.
-            ///
-            /// The maximum amount of data that can be written depends on the page
-            /// size of the device and its overall capacity. If too much data is passed,
-            /// the error `Error::TooMuchData` will be returned.
-            ///
-            /// After writing a byte, the EEPROM enters an internally-timed write cycle
-            /// to the nonvolatile memory.
-            /// During this time all inputs are disabled and the EEPROM will not
-            /// respond until the write is complete.
-            pub fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
-                if data.len() == 0 {
-                    return Ok(());
-                }
-
-                // check this before to ensure that data.len() fits into u32
-                // ($page_size always fits as its maximum value is 256).
-                if data.len() > $page_size {
-                    // This would actually be supported by the EEPROM but
-                    // the data in the page would be overwritten
-                    return Err(Error::TooMuchData);
-                }
-
-                let page_boundary = address | ($page_size as u32 - 1);
-                if address + data.len() as u32 > page_boundary + 1 {
-                    // This would actually be supported by the EEPROM but
-                    // the data in the page would be overwritten
-                    return Err(Error::TooMuchData);
-                }
-
-                let devaddr = self.get_device_address(address)?;
-                let mut payload: [u8; $addr_bytes + $page_size] = [0; $addr_bytes + $page_size];
-                AS::fill_address(address, &mut payload);
-                // only available since Rust 1.31: #[allow(clippy::range_plus_one)]
-                payload[$addr_bytes..$addr_bytes + data.len()].copy_from_slice(&data);
-                // only available since Rust 1.31: #[allow(clippy::range_plus_one)]
-                self.i2c
-                    .write(devaddr, &payload[..$addr_bytes + data.len()])
-                    .map_err(Error::I2C)
-            }
+/// Specialization for devices with page access
+impl<I2C, E, AS, SN, const N: usize> Eeprom24x<I2C, page_size::Bytes<N>, AS, SN>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Compile-time check that the page is a power of two and fits into the page write buffer.
+    const PAGE_SIZE_OK: () = assert!(
+        N.is_power_of_two() && N <= MAX_PAGE_SIZE,
+        "unsupported page size"
+    );
+
+    /// Create generic instance for devices with page access.
+    fn new(i2c: I2C, address: SlaveAddr, address_bits: u8) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::PAGE_SIZE_OK;
+        Eeprom24x {
+            i2c,
+            address,
+            address_bits,
+            secure_region_address: None,
+            write_pending: false,
+            max_transfer_len: usize::MAX,
+            _ps: PhantomData,
+            _as: PhantomData,
+            _sn: PhantomData,
         }
+    }
+}
 
-        impl<I2C, E, AS, SN> PageWrite<E> for Eeprom24x<I2C, page_size::$PS, AS, SN>
-        where
-            I2C: I2c<Error = E>,
-            AS: MultiSizeAddr,
-        {
-            fn page_write(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
-                self.write_page(address, data)
-            }
+impl<I2C, AS, SN, const N: usize> Eeprom24x<I2C, page_size::Bytes<N>, AS, SN>
+where
+    AS: DynAddr,
+{
+    /// Convert into a runtime-geometry driver instance, keeping the I²C bus.
+    ///
+    /// This allows type-erasing the device after construction.
+    pub fn into_dyn(self) -> Eeprom24xDyn<I2C> {
+        let mut eeprom = Eeprom24xDyn::new(
+            self.i2c,
+            self.address,
+            self.address_bits,
+            AS::ADDRESS_BYTES as u8,
+            N,
+        );
+        eeprom.max_transfer_len = self.max_transfer_len;
+        eeprom
+    }
+}
 
-            fn page_size(&self) -> usize {
-                $page_size
-            }
+impl<I2C, E, AS, const N: usize> Eeprom24x<I2C, page_size::Bytes<N>, AS, unique_serial::No>
+where
+    I2C: I2c<Error = E>,
+    AS: MultiSizeAddr,
+{
+    /// Create a new instance of a device with a custom geometry.
+    ///
+    /// This is useful for compatible devices not directly supported by this driver.
+    /// The page size and the memory address size are given by the marker types and
+    /// `address_bits` is the number of bits used for memory addressing, i.e. the
+    /// device capacity is `2^address_bits` bytes.
+    ///
+    /// Returns `Error::InvalidGeometry` if the number of address bits cannot be
+    /// transmitted with the memory address size, including the 3 bits that can be
+    /// moved into the device address.
+    pub fn new_custom(i2c: I2C, address: SlaveAddr, address_bits: u8) -> Result<Self, Error<E>> {
+        if !is_valid_geometry(address_bits, AS::ADDRESS_BYTES as u8, N) {
+            return Err(Error::InvalidGeometry);
         }
+        Ok(Self::new(i2c, address, address_bits))
+    }
+}
 
-        impl<I2C, E, AS, SN> crate::Eeprom24xTrait for Eeprom24x<I2C, page_size::$PS, AS, SN>
-        where
-            I2C: I2c<Error = E>,
-            AS: MultiSizeAddr
-            {
-                type Error = E;
-
-                fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<Self::Error>>
-                {
-                    self.write_byte(address, data)
-                }
-
-                fn read_byte(&mut self, address: u32) -> Result<u8, Error<Self::Error>>
-                {
-                    self.read_byte(address)
-                }
-
-                fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<Self::Error>>
-                {
-                    self.read_data(address, data)
-                }
-
-                fn read_current_address(&mut self) -> Result<u8, Error<Self::Error>>
-                {
-                    self.read_current_address()
-                }
-
-                fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<Self::Error>>
-                {
-                    self.write_page(address, &data)
-                }
-
-                fn page_size(&self) -> usize
-                {
-                    $page_size
-                }
-            }
-    };
+/// Check whether the device geometry can be handled by the driver.
+pub(crate) fn is_valid_geometry(address_bits: u8, address_bytes: u8, page_size: usize) -> bool {
+    (address_bytes == 1 || address_bytes == 2)
+        && address_bits > 0
+        && address_bits <= address_bytes * 8 + 3
+        && page_size.is_power_of_two()
+        && page_size <= MAX_PAGE_SIZE
+}
+
+/// Largest page size supported by the driver.
+///
+/// Page writes send the data without copying it, so no buffer of this size is needed.
+const MAX_PAGE_SIZE: usize = 256;
+
+impl<I2C, E, AS, SN, const N: usize> Eeprom24x<I2C, page_size::Bytes<N>, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+{
+    /// Write up to a page starting in an address.
+    ///
+    /// The maximum amount of data that can be written depends on the page
+    /// size of the device. If the data would cross a page boundary, the error
+    /// `Error::PageOverflow` will be returned.
+    ///
+    /// The memory address and the data are sent as two consecutive write operations
+    /// of a single I²C transaction so that the data is not copied.
+    ///
+    /// After writing a byte, the EEPROM enters an internally-timed write cycle
+    /// to the nonvolatile memory.
+    /// During this time all inputs are disabled and the EEPROM will not
+    /// respond until the write is complete.
+    pub fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        self.access().write_page(address, data, N, true)
+    }
+
+    /// Write up to a page starting in an address without validating the address or the
+    /// page boundary.
+    ///
+    /// This is intended for hot loops where the caller has already validated the addresses.
+    /// No `Error::InvalidAddr`, `Error::InvalidSlaveAddr` or `Error::PageOverflow` is
+    /// returned. Instead, addresses beyond the capacity wrap around to the start of the
+    /// memory and data crossing a page boundary wraps around to the start of the same page,
+    /// overwriting the data there, as the device does.
+    pub fn write_page_unchecked(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        let mut access = self.access();
+        access.checked = false;
+        access.write_page(address, data, N, true)
+    }
+}
+
+impl<I2C, E, AS, SN, const N: usize> PageWrite<E> for Eeprom24x<I2C, page_size::Bytes<N>, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+{
+    fn page_write(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        self.write_page(address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        N
+    }
+}
+
+impl<I2C, E, AS, SN, const N: usize> crate::Eeprom24xTrait
+    for Eeprom24x<I2C, page_size::Bytes<N>, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+{
+    type Error = E;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<Self::Error>> {
+        self.write_byte(address, data)
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Error<Self::Error>> {
+        self.read_byte(address)
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<Self::Error>> {
+        self.read_data(address, data)
+    }
+
+    fn read_current_address(&mut self) -> Result<u8, Error<Self::Error>> {
+        self.read_current_address()
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        self.write_page(address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        N
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn write_cycle_ms(&self) -> u32 {
+        page_size::Bytes::<N>::WRITE_CYCLE_MS
+    }
 }
 
 /// Helper trait which gives the Storage implementation access to the `write_page` method and
@@ -298,19 +667,80 @@ pub trait PageWrite<E> {
     fn page_size(&self) -> usize;
 }
 
-impl_for_page_size!(
+/// Duration of the internally-timed write cycle of the devices using a page size marker
+pub trait WriteCycle {
+    /// Time to wait after a write in milliseconds. Zero for devices without write cycle.
+    const WRITE_CYCLE_MS: u32;
+}
+
+impl WriteCycle for page_size::No {
+    const WRITE_CYCLE_MS: u32 = 5;
+}
+
+impl<const N: usize> WriteCycle for page_size::Bytes<N> {
+    const WRITE_CYCLE_MS: u32 = 5;
+}
+
+impl WriteCycle for page_size::Unlimited {
+    const WRITE_CYCLE_MS: u32 = 0;
+}
+
+#[cfg(any(feature = "one-byte-addr", feature = "two-byte-addr"))]
+macro_rules! impl_for_devices {
+    ( $AS:ident, $addr_bytes:expr, $PS:ident, $page_size:expr,
+        $( $(#[$attr:meta])* [ $dev:expr, $part:expr, $address_bits:expr, $SN:ident, $create:ident ] ),* ) => {
+        $(
+        $(#[$attr])*
+        impl<I2C, E> Eeprom24x<I2C, page_size::$PS, addr_size::$AS, unique_serial::$SN>
+        where
+            I2C: I2c<Error = E>
+        {
+            impl_create!($dev, $part, $address_bits, $create);
+        }
+
+        $(#[$attr])*
+        impl<I2C, E> Eeprom24xDyn<I2C>
+        where
+            I2C: I2c<Error = E>
+        {
+            impl_create_dyn!($dev, $part, $address_bits, $addr_bytes, $page_size, $create);
+        }
+        )*
+    };
+}
+
+#[cfg(feature = "one-byte-addr")]
+impl_for_devices!(
+    OneByte,
+    1,
+    B4,
+    4,
+    ["24x01A", "24C01A", 7, No, new_24x01a],
+    ["24x02A", "24C02A", 8, No, new_24x02a]
+);
+#[cfg(feature = "one-byte-addr")]
+impl_for_devices!(
     OneByte,
     1,
     B8,
     8,
     ["24x01", "AT24C01", 7, No, new_24x01],
     ["24x02", "AT24C02", 8, No, new_24x02],
+    #[cfg(feature = "cs-devices")]
     ["24CSx01", "24CS01", 7, Yes, new_24csx01],
+    #[cfg(feature = "cs-devices")]
     ["24CSx02", "24CS02", 8, Yes, new_24csx02],
-    ["24x02E48", "24AA02E48", 8, No, new_24x02e48],
-    ["24x02E64", "24AA02E64", 8, No, new_24x02e64]
+    #[cfg(feature = "cs-devices")]
+    ["24CSWx01", "AT24CSW010", 7, Csw, new_24cswx01],
+    #[cfg(feature = "cs-devices")]
+    ["24CSWx02", "AT24CSW020", 8, Csw, new_24cswx02],
+    #[cfg(feature = "cs-devices")]
+    ["24x02E48", "24AA02E48", 8, E48, new_24x02e48],
+    #[cfg(feature = "cs-devices")]
+    ["24x02E64", "24AA02E64", 8, E64, new_24x02e64]
 );
-impl_for_page_size!(
+#[cfg(feature = "one-byte-addr")]
+impl_for_devices!(
     OneByte,
     1,
     B16,
@@ -318,25 +748,72 @@ impl_for_page_size!(
     ["24x04", "AT24C04", 9, No, new_24x04],
     ["24x08", "AT24C08", 10, No, new_24x08],
     ["24x16", "AT24C16", 11, No, new_24x16],
+    #[cfg(feature = "cs-devices")]
     ["24CSx04", "AT24CS04", 9, Yes, new_24csx04],
+    #[cfg(feature = "cs-devices")]
     ["24CSx08", "AT24CS08", 10, Yes, new_24csx08],
+    #[cfg(feature = "cs-devices")]
     ["24CSx16", "AT24CS16", 11, Yes, new_24csx16],
-    ["24x025E48", "24AA025E48", 8, No, new_24x025e48],
-    ["24x025E64", "24AA025E64", 8, No, new_24x025e64],
+    #[cfg(feature = "cs-devices")]
+    ["24CSWx04", "AT24CSW040", 9, Csw, new_24cswx04],
+    #[cfg(feature = "cs-devices")]
+    ["24CSWx08", "AT24CSW080", 10, Csw, new_24cswx08],
+    #[cfg(feature = "cs-devices")]
+    ["24CSWx16", "AT24CSW160", 11, Csw, new_24cswx16],
+    #[cfg(feature = "cs-devices")]
+    ["24x025E48", "24AA025E48", 8, E48, new_24x025e48],
+    #[cfg(feature = "cs-devices")]
+    ["24x025E64", "24AA025E64", 8, E64, new_24x025e64],
     ["M24C01", "M24C01", 7, No, new_m24x01],
-    ["M24C02", "M24C02", 8, No, new_m24x02]
+    ["M24C02", "M24C02", 8, No, new_m24x02],
+    ["CAT24x01", "CAT24C01", 7, No, new_cat24x01],
+    ["CAT24x02", "CAT24C02", 8, No, new_cat24x02],
+    ["34x02", "34AA02", 8, Spd, new_34x02],
+    #[cfg(feature = "cs-devices")]
+    ["24MAC402", "AT24MAC402", 8, Mac402, new_24mac402],
+    #[cfg(feature = "cs-devices")]
+    ["24MAC602", "AT24MAC602", 8, Mac602, new_24mac602]
 );
-impl_for_page_size!(
+#[cfg(feature = "two-byte-addr")]
+impl<I2C, E> Eeprom24x<I2C, page_size::B128, addr_size::TwoBytesBlockSelect, unique_serial::No>
+where
+    I2C: I2c<Error = E>,
+{
+    impl_create!("24x1025", "24LC1025", 17, new_24x1025);
+}
+#[cfg(feature = "two-byte-addr")]
+impl<I2C, E> Eeprom24x<I2C, page_size::B4, addr_size::TwoBytes, unique_serial::M24lr>
+where
+    I2C: I2c<Error = E>,
+{
+    impl_create!("M24LR04E", "M24LR04E-R", 9, new_m24lr04e);
+    impl_create!("M24LR16E", "M24LR16E-R", 11, new_m24lr16e);
+    impl_create!("M24LR64E", "M24LR64E-R", 13, new_m24lr64e);
+}
+
+#[cfg(feature = "two-byte-addr")]
+impl_for_devices!(
     TwoBytes,
     2,
     B32,
     32,
     ["24x32", "AT24C32", 12, No, new_24x32],
     ["24x64", "AT24C64", 13, No, new_24x64],
+    #[cfg(feature = "cs-devices")]
     ["24CSx32", "AT24CS32", 12, Yes, new_24csx32],
-    ["24CSx64", "AT24CS64", 13, Yes, new_24csx64]
+    #[cfg(feature = "cs-devices")]
+    ["24CSx64", "AT24CS64", 13, Yes, new_24csx64],
+    #[cfg(feature = "cs-devices")]
+    ["24CWx16", "24CW160", 11, Cw, new_24cwx16],
+    #[cfg(feature = "cs-devices")]
+    ["24CWx32", "24CW320", 12, Cw, new_24cwx32],
+    #[cfg(feature = "cs-devices")]
+    ["24CWx64", "24CW640", 13, Cw, new_24cwx64],
+    #[cfg(feature = "cs-devices")]
+    ["24CWx128", "24CW1280", 14, Cw, new_24cwx128]
 );
-impl_for_page_size!(
+#[cfg(feature = "two-byte-addr")]
+impl_for_devices!(
     TwoBytes,
     2,
     B64,
@@ -344,14 +821,16 @@ impl_for_page_size!(
     ["24x128", "AT24C128", 14, No, new_24x128],
     ["24x256", "AT24C256", 15, No, new_24x256]
 );
-impl_for_page_size!(
+#[cfg(feature = "two-byte-addr")]
+impl_for_devices!(
     TwoBytes,
     2,
     B128,
     128,
     ["24x512", "AT24C512", 16, No, new_24x512]
 );
-impl_for_page_size!(
+#[cfg(feature = "two-byte-addr")]
+impl_for_devices!(
     TwoBytes,
     2,
     B256,
@@ -0,0 +1,462 @@
+use crate::access::{finish_access, Access, Geometry};
+use crate::device_id::read_device_id;
+use crate::eeprom24x::is_valid_geometry;
+use crate::probe::probe;
+use crate::recover::bus_recover;
+use crate::{Device, DeviceId, Eeprom24xDyn, Error, SlaveAddr};
+use embedded_hal::{delay::DelayNs, i2c::I2c};
+
+/// Common methods
+impl<I2C> Eeprom24xDyn<I2C> {
+    /// Create a new instance from its geometry.
+    pub(crate) fn new(
+        i2c: I2C,
+        address: SlaveAddr,
+        address_bits: u8,
+        address_bytes: u8,
+        page_size: usize,
+    ) -> Self {
+        Eeprom24xDyn {
+            i2c,
+            address,
+            address_bits,
+            address_bytes,
+            page_size,
+            write_pending: false,
+            max_transfer_len: usize::MAX,
+        }
+    }
+
+    /// Classify the bus error of a memory access and keep track of pending write cycles.
+    fn finish_access<E>(&mut self, result: Result<(), E>, write: bool) -> Result<(), Error<E>>
+    where
+        E: embedded_hal::i2c::Error,
+    {
+        finish_access(&mut self.write_pending, result, write)
+    }
+
+    /// Create a new instance of the given device.
+    ///
+    /// This is useful when the concrete device is only known at runtime, e.g.
+    /// when it comes from a configuration file.
+    pub fn new_from_device(i2c: I2C, address: SlaveAddr, device: Device) -> Self {
+        let info = device.info();
+        Self::new(
+            i2c,
+            address,
+            info.address_bits,
+            info.address_bytes,
+            info.page_size,
+        )
+    }
+
+    /// Create a new instance of a device with a custom geometry.
+    ///
+    /// This is useful for compatible devices not directly supported by this driver.
+    /// `address_bits` is the number of bits used for memory addressing, i.e. the
+    /// device capacity is `2^address_bits` bytes, `address_bytes` is the number of
+    /// bytes used to transmit the memory address and `page_size` is the page size
+    /// in bytes (1 for devices without page write support).
+    ///
+    /// Returns `Error::InvalidGeometry` if the page size is not a power of two of at
+    /// most 256 bytes, if `address_bytes` is not 1 or 2, or if the number of address
+    /// bits cannot be transmitted with the memory address size, including the 3 bits
+    /// that can be moved into the device address. Devices with a block select bit in
+    /// place of A2 (24xx1025) cannot be described with this geometry.
+    pub fn new_custom<E>(
+        i2c: I2C,
+        address: SlaveAddr,
+        page_size: usize,
+        address_bytes: u8,
+        address_bits: u8,
+    ) -> Result<Self, Error<E>>
+    where
+        I2C: I2c<Error = E>,
+    {
+        if !is_valid_geometry(address_bits, address_bytes, page_size) {
+            return Err(Error::InvalidGeometry);
+        }
+        Ok(Self::new(
+            i2c,
+            address,
+            address_bits,
+            address_bytes,
+            page_size,
+        ))
+    }
+
+    /// Destroy driver instance, return I²C bus instance.
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+
+    /// Return device page size
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Return device capacity in bytes
+    pub fn capacity(&self) -> usize {
+        1usize
+            .checked_shl(u32::from(self.address_bits))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Set the device address used for subsequent accesses.
+    ///
+    /// This allows using one driver instance to access several devices of the same type
+    /// on the same bus sequentially, e.g. cascaded chips. Note that the device does not
+    /// respond to other addresses during an internally-timed write cycle but other devices
+    /// can be accessed in the meantime.
+    pub fn set_address(&mut self, address: SlaveAddr) {
+        self.address = address;
+    }
+
+    /// Return the device address currently in use.
+    pub fn address(&self) -> SlaveAddr {
+        self.address
+    }
+
+    /// Set the maximum number of bytes read in a single transfer.
+    ///
+    /// Longer reads are split into several transfers. This is necessary for I²C
+    /// peripherals which cannot transfer more than a certain number of bytes at once,
+    /// e.g. SMBus adapters limited to 32 bytes. By default, reads are not split.
+    /// A length of 0 is treated as 1.
+    pub fn set_max_transfer_len(&mut self, len: usize) {
+        self.max_transfer_len = len.max(1);
+    }
+
+    /// Return the 7-bit I²C address used when accessing the given memory address.
+    ///
+    /// Some devices (e.g. 24x16) use some of the device address bits for memory addressing,
+    /// so that different memory addresses are accessed through different I²C addresses.
+    /// Returns `None` if the memory address is out of range or if the slave address cannot
+    /// be used with this device.
+    pub fn device_address(&self, memory_address: u32) -> Option<u8> {
+        self.get_device_address::<()>(memory_address).ok()
+    }
+
+    fn get_device_address<E>(&self, memory_address: u32) -> Result<u8, Error<E>> {
+        self.geometry().device_address(memory_address)
+    }
+
+    /// Return the runtime description of the device addressing.
+    fn geometry(&self) -> Geometry {
+        Geometry {
+            address: self.address,
+            address_bits: self.address_bits,
+            address_bytes: self.address_bytes,
+            // block select devices are only supported through `Eeprom24x`
+            block_select: false,
+        }
+    }
+
+    /// Access the memory through the non-generic access logic.
+    fn access(&mut self) -> Access<'_, I2C> {
+        Access {
+            geometry: self.geometry(),
+            i2c: &mut self.i2c,
+            write_pending: &mut self.write_pending,
+            max_transfer_len: self.max_transfer_len,
+            checked: true,
+        }
+    }
+}
+
+/// Device access methods
+///
+/// If the device does not acknowledge its address, `Error::Busy` is returned while it may
+/// still be in the write cycle of a previous write and `Error::NoDevice` otherwise.
+impl<I2C, E> Eeprom24xDyn<I2C>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Write a single byte in an address.
+    ///
+    /// After writing a byte, the EEPROM enters an internally-timed write cycle
+    /// to the nonvolatile memory.
+    /// During this time all inputs are disabled and the EEPROM will not
+    /// respond until the write is complete.
+    pub fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<E>> {
+        self.access().write_byte(address, data)
+    }
+
+    /// Read a single byte from an address.
+    pub fn read_byte(&mut self, address: u32) -> Result<u8, Error<E>> {
+        let mut data = [0; 1];
+        self.read_data(address, &mut data).and(Ok(data[0]))
+    }
+
+    /// Read starting in an address as many bytes as necessary to fill the data array provided.
+    ///
+    /// The read is split into several transfers if it is longer than the maximum transfer
+    /// length. See [`set_max_transfer_len()`](Self::set_max_transfer_len).
+    pub fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<E>> {
+        self.access().read_data(address, data, false)
+    }
+
+    /// Read starting in an address as many bytes as necessary to fill the data array provided
+    /// without validating the address.
+    ///
+    /// This is intended for hot loops where the caller has already validated the addresses.
+    /// No `Error::InvalidAddr` or `Error::InvalidSlaveAddr` is returned. Instead, addresses
+    /// beyond the capacity wrap around to the start of the memory, like the device itself
+    /// does when a read continues past the last byte.
+    pub fn read_data_unchecked(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<E>> {
+        let mut access = self.access();
+        access.checked = false;
+        access.read_data(address, data, false)
+    }
+
+    /// Read starting in an address as many bytes as necessary to fill the data array provided,
+    /// sending the memory address only once.
+    ///
+    /// Like `read_data()`, the read is split into transfers of at most the maximum transfer
+    /// length. However, only the first transfer sends the memory address. The following ones
+    /// are plain reads continuing at the internal address counter of the device (sequential
+    /// current address reads), which makes reading large amounts of data faster, e.g. when
+    /// dumping the whole memory. The memory address is sent again only where the device
+    /// address changes.
+    ///
+    /// The internal address counter must not be modified by other bus masters during the dump.
+    pub fn dump(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<E>> {
+        self.access().read_data(address, data, true)
+    }
+
+    /// Read starting in an address as many bytes as necessary to fill the buffers provided
+    /// back-to-back.
+    ///
+    /// This allows reading data which must land in separate places without intermediate
+    /// copies. Up to 8 buffers are read in a single transaction. If a maximum transfer
+    /// length has been set, each buffer is read with `read_data()` instead.
+    pub fn read_data_vectored(
+        &mut self,
+        address: u32,
+        buffers: &mut [&mut [u8]],
+    ) -> Result<(), Error<E>> {
+        self.access().read_data_vectored(address, buffers)
+    }
+
+    /// Read `len` bytes starting in an address into a new `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn read_vec(&mut self, address: u32, len: usize) -> Result<alloc::vec::Vec<u8>, Error<E>> {
+        let mut data = alloc::vec![0; len];
+        self.read_data(address, &mut data)?;
+        Ok(data)
+    }
+
+    /// Read the whole memory into a new `Vec`.
+    ///
+    /// Like [`dump()`](Self::dump), the memory address is only sent where necessary.
+    #[cfg(feature = "alloc")]
+    pub fn read_all_vec(&mut self) -> Result<alloc::vec::Vec<u8>, Error<E>> {
+        let mut data = alloc::vec![0; self.capacity()];
+        self.dump(0, &mut data)?;
+        Ok(data)
+    }
+
+    /// Read up to `N` bytes starting in an address into a new `heapless::Vec`.
+    ///
+    /// Fewer bytes are read if the end of the memory is reached before.
+    #[cfg(feature = "heapless")]
+    pub fn read_heapless<const N: usize>(
+        &mut self,
+        address: u32,
+    ) -> Result<heapless::Vec<u8, N>, Error<E>> {
+        let len = N.min(self.capacity().saturating_sub(address as usize));
+        let mut data = heapless::Vec::new();
+        // cannot fail as the length does not exceed N
+        let _ = data.resize_default(len);
+        self.read_data(address, &mut data)?;
+        Ok(data)
+    }
+
+    /// Read the contents of the last address accessed during the last read
+    /// or write operation, _incremented by one_.
+    ///
+    /// Note: This may not be available on your platform.
+    pub fn read_current_address(&mut self) -> Result<u8, Error<E>> {
+        let mut data = [0];
+        let result = self.i2c.read(self.address.addr(), &mut data);
+        self.finish_access(result, false).and(Ok(data[0]))
+    }
+
+    /// Read the I²C-bus Device ID.
+    ///
+    /// This can be used to confirm the populated part at runtime. Devices which do not
+    /// support the Device ID do not acknowledge the reserved address and `Error::NoDevice`
+    /// is returned.
+    pub fn read_device_id(&mut self) -> Result<DeviceId, Error<E>> {
+        read_device_id(&mut self.i2c, self.address.addr(), self.write_pending)
+    }
+
+    /// Write up to a page starting in an address.
+    ///
+    /// The maximum amount of data that can be written depends on the page
+    /// size of the device. If the data would cross a page boundary, the error
+    /// `Error::PageOverflow` will be returned.
+    ///
+    /// The memory address and the data are sent as two consecutive write operations
+    /// of a single I²C transaction so that the data is not copied.
+    ///
+    /// After writing a byte, the EEPROM enters an internally-timed write cycle
+    /// to the nonvolatile memory.
+    /// During this time all inputs are disabled and the EEPROM will not
+    /// respond until the write is complete.
+    pub fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        let page_size = self.page_size;
+        self.access().write_page(address, data, page_size, true)
+    }
+
+    /// Write up to a page starting in an address without validating the address or the
+    /// page boundary.
+    ///
+    /// This is intended for hot loops where the caller has already validated the addresses.
+    /// No `Error::InvalidAddr`, `Error::InvalidSlaveAddr` or `Error::PageOverflow` is
+    /// returned. Instead, addresses beyond the capacity wrap around to the start of the
+    /// memory and data crossing a page boundary wraps around to the start of the same page,
+    /// overwriting the data there, as the device does.
+    pub fn write_page_unchecked(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        let page_size = self.page_size;
+        let mut access = self.access();
+        access.checked = false;
+        access.write_page(address, data, page_size, true)
+    }
+}
+
+impl<I2C, E> Eeprom24xDyn<I2C>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Check whether the device is present on the bus.
+    ///
+    /// This issues a harmless zero-length write to the device address and returns whether
+    /// the device acknowledged it. Bus errors other than a missing acknowledge are returned.
+    ///
+    /// Note that the device does not acknowledge during an internally-timed write cycle.
+    pub fn is_present(&mut self) -> Result<bool, Error<E>> {
+        let devaddr = self.get_device_address(0)?;
+        probe(&mut self.i2c, devaddr)
+    }
+
+    /// Recover the device after a read was interrupted mid-transfer.
+    ///
+    /// If the controller is reset during a read, the device may keep holding SDA low while
+    /// waiting for further clock cycles, which blocks the bus. This issues the software
+    /// reset sequence described in the datasheets (START, 9 clock cycles with SDA high,
+    /// START, STOP) expressed as dummy transactions. Missing acknowledges and arbitration
+    /// losses are expected during the sequence and are not reported. Other bus errors are
+    /// returned.
+    ///
+    /// Note that some platforms do not support zero-length transfers and that some I²C
+    /// peripherals refuse to start a transfer while SDA is held low. In that case the bus
+    /// must be recovered by toggling SCL as GPIO.
+    pub fn bus_recover(&mut self) -> Result<(), Error<E>> {
+        let devaddr = self.get_device_address(0)?;
+        bus_recover(&mut self.i2c, devaddr)
+    }
+}
+
+/// Devices which can be told apart by `detect()`, sorted by capacity.
+const DETECTABLE_DEVICES: [Device; 5] = [
+    Device::AT24C32,
+    Device::AT24C64,
+    Device::AT24C128,
+    Device::AT24C256,
+    Device::AT24C512,
+];
+
+impl<I2C, E> Eeprom24xDyn<I2C>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Detect the capacity of a device with 2-byte memory addresses and create an instance
+    /// with the corresponding geometry.
+    ///
+    /// This can tell apart the devices 24x32, 24x64, 24x128, 24x256 and 24x512, which
+    /// usually share the same footprint. Smaller devices ignore the higher memory address
+    /// bits so that their memory is aliased. To find out where, a marker is written to the
+    /// memory address 0 and the original contents are restored afterwards. The delay is used
+    /// to wait for the write cycles to finish.
+    ///
+    /// Note that the memory address 0 will contain the marker if the power is lost during
+    /// the detection and that the detection cannot work if the device is write-protected.
+    pub fn detect<D: DelayNs>(
+        i2c: I2C,
+        address: SlaveAddr,
+        delay: &mut D,
+    ) -> Result<Self, Error<E>> {
+        let [.., largest] = DETECTABLE_DEVICES;
+        let mut eeprom = Self::new_from_device(i2c, address, largest);
+        let original = eeprom.read_byte(0)?;
+        let mut aliases = [0; DETECTABLE_DEVICES.len() - 1];
+        for (alias, device) in aliases.iter_mut().zip(DETECTABLE_DEVICES.iter()) {
+            *alias = eeprom.read_byte(device.info().capacity() as u32)?;
+        }
+
+        eeprom.write_byte(0, !original)?;
+        delay.delay_ms(5);
+        let mut detected = Ok(largest);
+        for (alias, device) in aliases.iter().zip(DETECTABLE_DEVICES.iter()) {
+            match eeprom.read_byte(device.info().capacity() as u32) {
+                Ok(value) if value != *alias => {
+                    detected = Ok(*device);
+                    break;
+                }
+                Ok(_) => (),
+                Err(e) => {
+                    detected = Err(e);
+                    break;
+                }
+            }
+        }
+        // restore the original contents even if the detection failed
+        eeprom.write_byte(0, original)?;
+        delay.delay_ms(5);
+
+        let info = detected?.info();
+        eeprom.address_bits = info.address_bits;
+        eeprom.page_size = info.page_size;
+        Ok(eeprom)
+    }
+}
+
+impl<I2C, E> crate::Eeprom24xTrait for Eeprom24xDyn<I2C>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    type Error = E;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<Self::Error>> {
+        self.write_byte(address, data)
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Error<Self::Error>> {
+        self.read_byte(address)
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<Self::Error>> {
+        self.read_data(address, data)
+    }
+
+    fn read_current_address(&mut self) -> Result<u8, Error<Self::Error>> {
+        self.read_current_address()
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        self.write_page(address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+}
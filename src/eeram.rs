@@ -0,0 +1,95 @@
+use crate::{
+    addr_size::TwoBytes, page_size::Unlimited, unique_serial, Eeprom24x, Error, SlaveAddr,
+};
+use embedded_hal::i2c::I2c;
+
+/// Device address of the control register without the address pin bits.
+const CONTROL_ADDR: u8 = 0b001_1000;
+/// Address of the status register.
+const STATUS_REGISTER: u8 = 0x00;
+/// Address of the command register.
+const COMMAND_REGISTER: u8 = 0x55;
+/// Command to store the SRAM contents into the EEPROM.
+const STORE: u8 = 0x33;
+/// Command to recall the EEPROM contents into the SRAM.
+const RECALL: u8 = 0xDD;
+/// Array modified bit of the status register.
+const AM_BIT: u8 = 1 << 7;
+/// Auto-store enable bit of the status register.
+const ASE_BIT: u8 = 1 << 1;
+
+impl<I2C, E> Eeprom24x<I2C, Unlimited, TwoBytes, unique_serial::Eeram>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create a new instance of a 47x16 EERAM device (e.g. 47L16)
+    pub fn new_47x16(i2c: I2C, address: SlaveAddr) -> Self {
+        Self::new(i2c, address, 11)
+    }
+}
+
+/// Methods for interacting with the control register of EERAM devices. e.g. 47x16.
+///
+/// The SRAM array is accessed at SRAM speed through the usual methods. Its contents
+/// are copied into the EEPROM on a STORE operation and back on a RECALL operation.
+/// The control register is accessed at the device addresses `0x18..=0x1F`.
+impl<I2C, E> Eeprom24x<I2C, Unlimited, TwoBytes, unique_serial::Eeram>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Copy the SRAM contents into the EEPROM.
+    ///
+    /// The device does not respond during the store operation (`tSTORE`, 8 ms).
+    pub fn store(&mut self) -> Result<(), Error<E>> {
+        self.write_control_register(COMMAND_REGISTER, STORE)
+    }
+
+    /// Copy the EEPROM contents into the SRAM.
+    ///
+    /// The device does not respond during the recall operation (`tRECALL`, 2 ms).
+    pub fn recall(&mut self) -> Result<(), Error<E>> {
+        self.write_control_register(COMMAND_REGISTER, RECALL)
+    }
+
+    /// Read the status register.
+    pub fn read_status(&mut self) -> Result<u8, Error<E>> {
+        let mut status = [0];
+        let result = self.i2c.read(self.control_addr(), &mut status);
+        self.finish_access(result, false).and(Ok(status[0]))
+    }
+
+    /// Check whether the SRAM has been written since the last STORE operation.
+    pub fn is_array_modified(&mut self) -> Result<bool, Error<E>> {
+        Ok(self.read_status()? & AM_BIT != 0)
+    }
+
+    /// Check whether the contents are stored automatically on power loss.
+    pub fn is_auto_store_enabled(&mut self) -> Result<bool, Error<E>> {
+        Ok(self.read_status()? & ASE_BIT != 0)
+    }
+
+    /// Enable or disable storing the contents automatically on power loss.
+    ///
+    /// The rest of the status register is kept. After this, the device enters an
+    /// internally-timed write cycle of the nonvolatile configuration.
+    pub fn set_auto_store(&mut self, enable: bool) -> Result<(), Error<E>> {
+        let status = self.read_status()? & !AM_BIT;
+        let status = if enable {
+            status | ASE_BIT
+        } else {
+            status & !ASE_BIT
+        };
+        self.write_control_register(STATUS_REGISTER, status)
+    }
+
+    fn write_control_register(&mut self, register: u8, value: u8) -> Result<(), Error<E>> {
+        let result = self.i2c.write(self.control_addr(), &[register, value]);
+        self.finish_access(result, true)
+    }
+
+    fn control_addr(&self) -> u8 {
+        // only the A2 and A1 pins are used
+        CONTROL_ADDR | (self.address.addr() & 0b110)
+    }
+}
@@ -0,0 +1,151 @@
+use crate::slice::{copy, split, split_mut};
+use crate::{EncryptedStorage, EncryptionError, Keystream};
+use embedded_storage::{ReadStorage, Storage};
+
+/// Number of data bytes in a region.
+const REGION_DATA_LEN: usize = 32;
+/// Size of a region in the storage: data and write counter.
+const REGION_LEN: usize = REGION_DATA_LEN + 4;
+
+impl<S, C> EncryptedStorage<S, C> {
+    /// Create a new instance encrypting the data with the cipher.
+    pub fn new(storage: S, cipher: C) -> Self {
+        EncryptedStorage { storage, cipher }
+    }
+
+    /// Destroy the wrapper, return the storage and the cipher.
+    pub fn destroy(self) -> (S, C) {
+        (self.storage, self.cipher)
+    }
+}
+
+impl<S, C> EncryptedStorage<S, C>
+where
+    S: ReadStorage,
+    C: Keystream,
+{
+    /// Read a region, returning the decrypted data and its write counter.
+    fn read_region(
+        &mut self,
+        region: u32,
+    ) -> Result<([u8; REGION_DATA_LEN], u32), EncryptionError<S::Error>> {
+        let mut raw = [0; REGION_LEN];
+        self.storage
+            .read(region * REGION_LEN as u32, &mut raw)
+            .map_err(EncryptionError::Storage)?;
+        let (data_bytes, counter) = split(&raw, REGION_DATA_LEN);
+        let mut data = [0; REGION_DATA_LEN];
+        copy(&mut data, data_bytes);
+        let mut counter_bytes = [0; 4];
+        copy(&mut counter_bytes, counter);
+        let counter = u32::from_le_bytes(counter_bytes);
+        self.cipher
+            .apply_keystream(&nonce(region, counter), &mut data);
+        Ok((data, counter))
+    }
+
+    fn check_bounds(&self, offset: u32, len: usize) -> Result<(), EncryptionError<S::Error>> {
+        if len > self.capacity().saturating_sub(offset as usize) {
+            return Err(EncryptionError::OutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+impl<S, C> EncryptedStorage<S, C>
+where
+    S: Storage,
+    C: Keystream,
+{
+    /// Fill the storage with encrypted zeros.
+    ///
+    /// This is needed once before use, as the data of an erased storage is decrypted to
+    /// random bytes.
+    pub fn format(&mut self) -> Result<(), EncryptionError<S::Error>> {
+        for region in 0..self.regions() {
+            self.write_region(region, [0; REGION_DATA_LEN], 0)?;
+        }
+        Ok(())
+    }
+
+    fn regions(&self) -> u32 {
+        (self.storage.capacity() / REGION_LEN) as u32
+    }
+
+    /// Encrypt and write the data of a region with its write counter.
+    fn write_region(
+        &mut self,
+        region: u32,
+        mut data: [u8; REGION_DATA_LEN],
+        counter: u32,
+    ) -> Result<(), EncryptionError<S::Error>> {
+        self.cipher
+            .apply_keystream(&nonce(region, counter), &mut data);
+        let mut raw = [0; REGION_LEN];
+        let (raw_data, raw_counter) = split_mut(&mut raw, REGION_DATA_LEN);
+        copy(raw_data, &data);
+        copy(raw_counter, &counter.to_le_bytes());
+        self.storage
+            .write(region * REGION_LEN as u32, &raw)
+            .map_err(EncryptionError::Storage)
+    }
+}
+
+impl<S, C> ReadStorage for EncryptedStorage<S, C>
+where
+    S: ReadStorage,
+    C: Keystream,
+{
+    type Error = EncryptionError<S::Error>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.check_bounds(offset, bytes.len())?;
+        let mut offset = offset as usize;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let (data, _) = self.read_region((offset / REGION_DATA_LEN) as u32)?;
+            let (_, data) = split(&data, offset % REGION_DATA_LEN);
+            let len = copy(bytes, data);
+            offset += len;
+            bytes = split_mut(core::mem::take(&mut bytes), len).1;
+        }
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.storage.capacity() / REGION_LEN * REGION_DATA_LEN
+    }
+}
+
+impl<S, C> Storage for EncryptedStorage<S, C>
+where
+    S: Storage,
+    C: Keystream,
+{
+    /// Write data, re-encrypting each affected region with a new nonce.
+    ///
+    /// The write counter of each region is incremented so that a keystream is never used
+    /// for two different contents.
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check_bounds(offset, bytes.len())?;
+        let mut offset = offset as usize;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let region = (offset / REGION_DATA_LEN) as u32;
+            let (mut data, counter) = self.read_region(region)?;
+            let (_, target) = split_mut(&mut data, offset % REGION_DATA_LEN);
+            let len = copy(target, bytes);
+            self.write_region(region, data, counter.wrapping_add(1))?;
+            offset += len;
+            bytes = split(bytes, len).1;
+        }
+        Ok(())
+    }
+}
+
+/// Return the nonce of a region: its index and its write counter.
+fn nonce(region: u32, counter: u32) -> [u8; 8] {
+    let [r0, r1, r2, r3] = region.to_le_bytes();
+    let [c0, c1, c2, c3] = counter.to_le_bytes();
+    [r0, r1, r2, r3, c0, c1, c2, c3]
+}
@@ -0,0 +1,54 @@
+use crate::slice::{split, split_mut};
+use crate::EraseError;
+use core::ops::Range;
+use embedded_storage::Storage;
+
+/// Size of the chunks in which the region is written and verified.
+const CHUNK_LEN: usize = 16;
+
+/// Overwrite a region of a storage several times and verify each pass.
+///
+/// Each pass writes a pattern to the whole region and reads it back. The patterns
+/// alternate between `0x55` and `0xAA` so that every bit is flipped at each pass, and the
+/// last pass writes `0xFF` so that the region looks erased. Returns
+/// `EraseError::VerifyFailed` with the offset of the first byte which could not be
+/// overwritten, e.g. because the device is write-protected.
+pub fn secure_erase<S>(
+    storage: &mut S,
+    range: Range<u32>,
+    passes: usize,
+) -> Result<(), EraseError<S::Error>>
+where
+    S: Storage,
+{
+    if range.end as usize > storage.capacity() {
+        return Err(EraseError::OutOfBounds);
+    }
+    for pass in 0..passes {
+        let pattern = match pass {
+            pass if pass + 1 == passes => 0xFF,
+            pass if pass % 2 == 0 => 0x55,
+            _ => 0xAA,
+        };
+        let chunk = [pattern; CHUNK_LEN];
+        let mut offset = range.start;
+        while offset < range.end {
+            let (data, _) = split(&chunk, (range.end - offset) as usize);
+            storage.write(offset, data).map_err(EraseError::Storage)?;
+            offset += data.len() as u32;
+        }
+        let mut read = [0; CHUNK_LEN];
+        let mut offset = range.start;
+        while offset < range.end {
+            let (data, _) = split_mut(&mut read, (range.end - offset) as usize);
+            storage.read(offset, data).map_err(EraseError::Storage)?;
+            if let Some(position) = data.iter().position(|byte| *byte != pattern) {
+                return Err(EraseError::VerifyFailed {
+                    offset: offset + position as u32,
+                });
+            }
+            offset += data.len() as u32;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,408 @@
+use crate::{Error, GeometryMismatch};
+use core::fmt;
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+impl<E: embedded_hal::i2c::Error> Error<E> {
+    /// Classify a bus error of a memory access.
+    ///
+    /// A device which does not acknowledge its address is reported as busy if it was
+    /// written to before and as absent otherwise.
+    pub(crate) fn from_bus(error: E, write_pending: bool) -> Self {
+        match error.kind() {
+            ErrorKind::NoAcknowledge(
+                NoAcknowledgeSource::Address | NoAcknowledgeSource::Unknown,
+            ) => {
+                if write_pending {
+                    Error::Busy(error)
+                } else {
+                    Error::NoDevice(error)
+                }
+            }
+            _ => Error::I2C(error),
+        }
+    }
+}
+
+impl<E> Error<E> {
+    /// Convert the bus error contained in the error, if any.
+    #[cfg(feature = "fault-injection")]
+    pub(crate) fn map_bus<F>(self, f: impl FnOnce(E) -> F) -> Error<F> {
+        match self {
+            Error::I2C(e) => Error::I2C(f(e)),
+            Error::Busy(e) => Error::Busy(f(e)),
+            Error::NoDevice(e) => Error::NoDevice(f(e)),
+            Error::PageOverflow { requested, allowed } => {
+                Error::PageOverflow { requested, allowed }
+            }
+            Error::CapacityExceeded { requested, allowed } => {
+                Error::CapacityExceeded { requested, allowed }
+            }
+            Error::InvalidAddr { address, capacity } => Error::InvalidAddr { address, capacity },
+            Error::InvalidGeometry => Error::InvalidGeometry,
+            Error::InvalidSlaveAddr => Error::InvalidSlaveAddr,
+            Error::GeometryMismatch(mismatch) => Error::GeometryMismatch(mismatch),
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::I2C(e) => write!(f, "I2C bus error: {:?}", e),
+            Error::Busy(e) => write!(f, "device busy in write cycle: {:?}", e),
+            Error::NoDevice(e) => write!(f, "device not present: {:?}", e),
+            Error::PageOverflow { requested, allowed } => write!(
+                f,
+                "write of {} bytes crosses the page boundary, {} bytes available",
+                requested, allowed
+            ),
+            Error::CapacityExceeded { requested, allowed } => write!(
+                f,
+                "write of {} bytes exceeds the memory capacity, {} bytes available",
+                requested, allowed
+            ),
+            Error::InvalidAddr { address, capacity } => write!(
+                f,
+                "memory address {:#x} is out of range for a capacity of {} bytes",
+                address, capacity
+            ),
+            Error::InvalidGeometry => write!(f, "invalid device geometry"),
+            Error::InvalidSlaveAddr => write!(f, "invalid slave address for the device"),
+            Error::GeometryMismatch(mismatch) => {
+                write!(f, "device geometry mismatch: {}", mismatch)
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for Error<E> {}
+
+impl fmt::Display for GeometryMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeometryMismatch::AddressSize => write!(f, "memory address size does not match"),
+            GeometryMismatch::Capacity => write!(f, "device is smaller than configured"),
+        }
+    }
+}
+
+#[cfg(feature = "fault-injection")]
+impl<E: fmt::Debug> fmt::Display for crate::FaultError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::FaultError::Nack => write!(f, "injected missing acknowledge"),
+            crate::FaultError::Timeout => write!(f, "injected bus timeout"),
+            crate::FaultError::Device(e) => write!(f, "{:?}", e),
+        }
+    }
+}
+
+#[cfg(feature = "fault-injection")]
+impl<E: fmt::Debug> core::error::Error for crate::FaultError<E> {}
+
+#[cfg(feature = "fault-injection")]
+impl<E: embedded_hal::i2c::Error> embedded_hal::i2c::Error for crate::FaultError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            crate::FaultError::Nack => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address),
+            crate::FaultError::Timeout => ErrorKind::Other,
+            crate::FaultError::Device(e) => e.kind(),
+        }
+    }
+}
+
+#[cfg(feature = "kv-store")]
+impl<E: fmt::Debug> fmt::Display for crate::KvError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::KvError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::KvError::NotFormatted => write!(f, "no key-value store found"),
+            crate::KvError::ValueTooLong => write!(f, "value too long"),
+            crate::KvError::BufferTooSmall { required } => {
+                write!(f, "buffer too small, {} bytes required", required)
+            }
+            crate::KvError::Full => write!(f, "key-value store full"),
+        }
+    }
+}
+
+#[cfg(feature = "kv-store")]
+impl<E: fmt::Debug> core::error::Error for crate::KvError<E> {}
+
+impl<E: fmt::Debug> fmt::Display for crate::ArrayError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::ArrayError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::ArrayError::OutOfBounds { index, len } => {
+                write!(f, "index {} out of bounds for {} elements", index, len)
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for crate::ArrayError<E> {}
+
+#[cfg(any(feature = "ring-log", feature = "event-log"))]
+impl<E: fmt::Debug> fmt::Display for crate::LogError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::LogError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::LogError::NotFormatted => write!(f, "no log found"),
+            crate::LogError::RecordTooLong => write!(f, "record too long"),
+            crate::LogError::BufferTooSmall { required } => {
+                write!(f, "buffer too small, {} bytes required", required)
+            }
+            crate::LogError::Corrupted => write!(f, "record corrupted"),
+            crate::LogError::Full => write!(f, "log full"),
+        }
+    }
+}
+
+#[cfg(any(feature = "ring-log", feature = "event-log"))]
+impl<E: fmt::Debug> core::error::Error for crate::LogError<E> {}
+
+#[cfg(feature = "config")]
+impl<E: fmt::Debug> fmt::Display for crate::ConfigError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::ConfigError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::ConfigError::NotFound => write!(f, "no configuration found"),
+            crate::ConfigError::Corrupted => write!(f, "configuration corrupted"),
+            crate::ConfigError::UnsupportedVersion { version } => {
+                write!(f, "unsupported configuration version {}", version)
+            }
+            crate::ConfigError::BufferTooSmall { required } => {
+                write!(f, "buffer too small, {} bytes required", required)
+            }
+            crate::ConfigError::TooLarge => write!(f, "configuration too large"),
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl<E: fmt::Debug> core::error::Error for crate::ConfigError<E> {}
+
+#[cfg(feature = "journal")]
+impl<E: fmt::Debug> fmt::Display for crate::JournalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::JournalError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::JournalError::OutOfBounds => write!(f, "write out of bounds"),
+            crate::JournalError::JournalFull => write!(f, "journal full"),
+        }
+    }
+}
+
+#[cfg(feature = "journal")]
+impl<E: fmt::Debug> core::error::Error for crate::JournalError<E> {}
+
+#[cfg(feature = "ecc")]
+impl<E: fmt::Debug> fmt::Display for crate::EccError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::EccError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::EccError::Uncorrectable { offset } => {
+                write!(f, "uncorrectable error in block at offset {}", offset)
+            }
+            crate::EccError::OutOfBounds => write!(f, "access out of bounds"),
+        }
+    }
+}
+
+#[cfg(feature = "ecc")]
+impl<E: fmt::Debug> core::error::Error for crate::EccError<E> {}
+
+#[cfg(feature = "mirror")]
+impl<E: fmt::Debug> fmt::Display for crate::MirrorError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::MirrorError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::MirrorError::Corrupted { offset } => {
+                write!(f, "both copies of block at offset {} corrupted", offset)
+            }
+            crate::MirrorError::OutOfBounds => write!(f, "access out of bounds"),
+        }
+    }
+}
+
+#[cfg(feature = "mirror")]
+impl<E: fmt::Debug> core::error::Error for crate::MirrorError<E> {}
+
+#[cfg(feature = "slot-fs")]
+impl<E: fmt::Debug> fmt::Display for crate::FsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::FsError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::FsError::NotFormatted => write!(f, "no filesystem found"),
+            crate::FsError::NotFound => write!(f, "slot not found"),
+            crate::FsError::AlreadyExists => write!(f, "slot already exists"),
+            crate::FsError::InvalidName => write!(f, "invalid slot name"),
+            crate::FsError::DirectoryFull => write!(f, "directory full"),
+            crate::FsError::NoSpace => write!(f, "no space left"),
+            crate::FsError::TooLarge => write!(f, "data larger than slot"),
+            crate::FsError::BufferTooSmall { required } => {
+                write!(f, "buffer too small, {} bytes required", required)
+            }
+            crate::FsError::Corrupted => write!(f, "slot data corrupted"),
+        }
+    }
+}
+
+#[cfg(feature = "slot-fs")]
+impl<E: fmt::Debug> core::error::Error for crate::FsError<E> {}
+
+#[cfg(feature = "block-alloc")]
+impl<E: fmt::Debug> fmt::Display for crate::AllocError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::AllocError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::AllocError::Full => write!(f, "no free blocks"),
+            crate::AllocError::NotAllocated => write!(f, "block not allocated"),
+            crate::AllocError::OutOfBounds => write!(f, "access out of bounds"),
+        }
+    }
+}
+
+#[cfg(feature = "block-alloc")]
+impl<E: fmt::Debug> core::error::Error for crate::AllocError<E> {}
+
+#[cfg(feature = "time-series")]
+impl<E: fmt::Debug> fmt::Display for crate::TimeSeriesError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::TimeSeriesError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::TimeSeriesError::TooSmall => write!(f, "storage too small for a record"),
+            crate::TimeSeriesError::NotIncreasing => write!(f, "timestamp not increasing"),
+            crate::TimeSeriesError::OutOfBounds => write!(f, "record index out of bounds"),
+            crate::TimeSeriesError::Corrupted => write!(f, "record checksum mismatch"),
+        }
+    }
+}
+
+#[cfg(feature = "time-series")]
+impl<E: fmt::Debug> core::error::Error for crate::TimeSeriesError<E> {}
+
+#[cfg(feature = "seed")]
+impl<E: fmt::Debug> fmt::Display for crate::SeedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::SeedError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::SeedError::NotInitialized => write!(f, "no seed stored"),
+        }
+    }
+}
+
+#[cfg(feature = "seed")]
+impl<E: fmt::Debug> core::error::Error for crate::SeedError<E> {}
+
+#[cfg(feature = "boot-counter")]
+impl<E: fmt::Debug> fmt::Display for crate::CounterError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::CounterError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::CounterError::TooSmall => write!(f, "storage too small for a count"),
+        }
+    }
+}
+
+#[cfg(feature = "boot-counter")]
+impl<E: fmt::Debug> core::error::Error for crate::CounterError<E> {}
+
+#[cfg(feature = "encryption")]
+impl<E: fmt::Debug> fmt::Display for crate::EncryptionError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::EncryptionError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::EncryptionError::OutOfBounds => write!(f, "access out of bounds"),
+        }
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl<E: fmt::Debug> core::error::Error for crate::EncryptionError<E> {}
+
+#[cfg(feature = "auth")]
+impl<E: fmt::Debug> fmt::Display for crate::AuthError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::AuthError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::AuthError::TamperDetected => write!(f, "data does not match its tag"),
+            crate::AuthError::BufferTooSmall { required } => {
+                write!(f, "buffer too small, {} bytes required", required)
+            }
+            crate::AuthError::TooLarge => write!(f, "data too large"),
+        }
+    }
+}
+
+#[cfg(feature = "auth")]
+impl<E: fmt::Debug> core::error::Error for crate::AuthError<E> {}
+
+impl<E: fmt::Debug> fmt::Display for crate::EraseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::EraseError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::EraseError::OutOfBounds => write!(f, "region out of bounds"),
+            crate::EraseError::VerifyFailed { offset } => {
+                write!(f, "verification failed at offset {}", offset)
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for crate::EraseError<E> {}
+
+#[cfg(feature = "embedded-io")]
+impl<E: fmt::Debug> fmt::Display for crate::CursorError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::CursorError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::CursorError::EndOfStorage => write!(f, "end of storage reached"),
+            crate::CursorError::InvalidSeek => write!(f, "invalid seek position"),
+        }
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<E: fmt::Debug> core::error::Error for crate::CursorError<E> {}
+
+impl<E: fmt::Debug> fmt::Display for crate::ExportError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::ExportError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::ExportError::OutOfBounds => write!(f, "region out of bounds"),
+            crate::ExportError::Format => write!(f, "output error"),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for crate::ExportError<E> {}
+
+impl<E: fmt::Debug, C: fmt::Debug> fmt::Display for crate::TransferError<E, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::TransferError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::TransferError::OutOfBounds => write!(f, "region out of bounds"),
+            crate::TransferError::Callback(e) => write!(f, "callback error: {:?}", e),
+        }
+    }
+}
+
+impl<E: fmt::Debug, C: fmt::Debug> core::error::Error for crate::TransferError<E, C> {}
+
+#[cfg(feature = "snapshot")]
+impl<E: fmt::Debug, C: fmt::Debug> fmt::Display for crate::SnapshotError<E, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            crate::SnapshotError::Storage(e) => write!(f, "storage error: {:?}", e),
+            crate::SnapshotError::Callback(e) => write!(f, "callback error: {:?}", e),
+            crate::SnapshotError::InvalidHeader => write!(f, "invalid snapshot header"),
+            crate::SnapshotError::GeometryMismatch => write!(f, "snapshot geometry mismatch"),
+            crate::SnapshotError::Truncated => write!(f, "snapshot truncated"),
+            crate::SnapshotError::Corrupted => write!(f, "snapshot corrupted"),
+        }
+    }
+}
+
+#[cfg(feature = "snapshot")]
+impl<E: fmt::Debug, C: fmt::Debug> core::error::Error for crate::SnapshotError<E, C> {}
@@ -0,0 +1,260 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::split_mut;
+use crate::{EventLog, LogError, Record, Records};
+use embedded_storage::{ReadStorage, Storage};
+
+/// Marker at the start of the header.
+const MAGIC: [u8; 2] = *b"EL";
+/// Size of the header: magic, epoch and checksum.
+const HEADER_LEN: u32 = 8;
+/// Size of a record without the data: length and checksum.
+const RECORD_OVERHEAD: u32 = 3;
+/// Size of the chunks in which the record data is read to compute its checksum.
+const CHUNK_LEN: usize = 16;
+
+impl<S> EventLog<S> {
+    /// Maximum length of a record in bytes.
+    pub const MAX_RECORD_LEN: usize = u8::MAX as usize;
+
+    /// Destroy the log, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+
+    /// Return whether the log contains no records.
+    pub fn is_empty(&self) -> bool {
+        self.end == HEADER_LEN
+    }
+}
+
+impl<S> EventLog<S>
+where
+    S: Storage,
+{
+    /// Create an empty log in the storage.
+    ///
+    /// The storage starts with a header followed by the records. The records of a previous
+    /// log are discarded by changing the epoch stored in the header, which is part of the
+    /// checksum of each record. Returns `LogError::Full` if the storage is too small.
+    pub fn format(storage: S) -> Result<Self, LogError<S::Error>> {
+        let mut log = EventLog {
+            storage,
+            epoch: 0,
+            end: HEADER_LEN,
+        };
+        if log.storage.capacity() < (HEADER_LEN + RECORD_OVERHEAD) as usize {
+            return Err(LogError::Full);
+        }
+        if let Some(previous) = log.read_header()? {
+            log.epoch = previous.wrapping_add(1);
+        }
+        let [m0, m1] = MAGIC;
+        let [e0, e1, e2, e3] = log.epoch.to_le_bytes();
+        let [c0, c1] = crc16(CRC16_INIT, &[m0, m1, e0, e1, e2, e3]).to_le_bytes();
+        log.storage
+            .write(0, &[m0, m1, e0, e1, e2, e3, c0, c1])
+            .map_err(LogError::Storage)?;
+        Ok(log)
+    }
+
+    /// Open a log previously created with [`format()`](Self::format).
+    ///
+    /// The records are scanned to find the end of the log. The scan stops at the first
+    /// record which does not match its checksum, so that a record torn by a power loss is
+    /// discarded and overwritten by the next append. Returns `LogError::NotFormatted` if
+    /// the storage does not contain a log.
+    pub fn mount(storage: S) -> Result<Self, LogError<S::Error>> {
+        let mut log = EventLog {
+            storage,
+            epoch: 0,
+            end: HEADER_LEN,
+        };
+        if log.storage.capacity() < (HEADER_LEN + RECORD_OVERHEAD) as usize {
+            return Err(LogError::NotFormatted);
+        }
+        log.epoch = log.read_header()?.ok_or(LogError::NotFormatted)?;
+        let mut records = Records {
+            storage: &mut log.storage,
+            epoch: log.epoch,
+            position: HEADER_LEN,
+            end: HEADER_LEN,
+        };
+        while let Some(record) = records.check()? {
+            records.position = record.end();
+        }
+        log.end = records.position;
+        Ok(log)
+    }
+
+    /// Append a record.
+    ///
+    /// Returns `LogError::RecordTooLong` if the record is longer than
+    /// [`MAX_RECORD_LEN`](Self::MAX_RECORD_LEN) and `LogError::Full` if it does not fit
+    /// into the remaining space.
+    pub fn append(&mut self, data: &[u8]) -> Result<(), LogError<S::Error>> {
+        let len = u8::try_from(data.len()).map_err(|_| LogError::RecordTooLong)?;
+        let size = RECORD_OVERHEAD + u32::from(len);
+        if size as usize > self.remaining() {
+            return Err(LogError::Full);
+        }
+        let crc = record_crc(self.epoch, len, data);
+        self.storage
+            .write(self.end, &[len])
+            .map_err(LogError::Storage)?;
+        self.storage
+            .write(self.end + 1, data)
+            .map_err(LogError::Storage)?;
+        self.storage
+            .write(self.end + 1 + u32::from(len), &crc.to_le_bytes())
+            .map_err(LogError::Storage)?;
+        self.end += size;
+        Ok(())
+    }
+
+    /// Return the number of bytes available for records, including their overhead.
+    pub fn remaining(&self) -> usize {
+        self.storage.capacity().saturating_sub(self.end as usize)
+    }
+
+    /// Return an iterator over the records from the oldest to the newest one.
+    pub fn records(&mut self) -> Records<'_, S> {
+        Records {
+            storage: &mut self.storage,
+            epoch: self.epoch,
+            position: HEADER_LEN,
+            end: self.end,
+        }
+    }
+
+    /// Read the epoch from the header if it is valid.
+    fn read_header(&mut self) -> Result<Option<u32>, LogError<S::Error>> {
+        let mut header = [0; HEADER_LEN as usize];
+        self.storage
+            .read(0, &mut header)
+            .map_err(LogError::Storage)?;
+        let [m0, m1, e0, e1, e2, e3, c0, c1] = header;
+        let crc = crc16(CRC16_INIT, &[m0, m1, e0, e1, e2, e3]);
+        if [m0, m1] != MAGIC || crc != u16::from_le_bytes([c0, c1]) {
+            return Ok(None);
+        }
+        Ok(Some(u32::from_le_bytes([e0, e1, e2, e3])))
+    }
+}
+
+impl Record {
+    /// Return the offset of the record data in the storage.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Return the length of the record data.
+    pub fn len(&self) -> usize {
+        usize::from(self.len)
+    }
+
+    /// Return whether the record contains no data.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the position following the record.
+    fn end(&self) -> u32 {
+        self.offset + u32::from(self.len) + 2
+    }
+}
+
+impl<S> Records<'_, S>
+where
+    S: ReadStorage,
+{
+    /// Read the data of a record into the buffer and return its length.
+    pub fn read(
+        &mut self,
+        record: &Record,
+        buffer: &mut [u8],
+    ) -> Result<usize, LogError<S::Error>> {
+        let required = record.len();
+        let data = buffer
+            .get_mut(..required)
+            .ok_or(LogError::BufferTooSmall { required })?;
+        self.storage
+            .read(record.offset, data)
+            .map_err(LogError::Storage)?;
+        Ok(required)
+    }
+
+    /// Check the record at the current position and return it if it is valid.
+    fn check(&mut self) -> Result<Option<Record>, LogError<S::Error>> {
+        let capacity = self.storage.capacity() as u32;
+        if self.position + RECORD_OVERHEAD > capacity {
+            return Ok(None);
+        }
+        let mut len = [0];
+        self.storage
+            .read(self.position, &mut len)
+            .map_err(LogError::Storage)?;
+        let [len] = len;
+        let offset = self.position + 1;
+        if offset + u32::from(len) + 2 > capacity {
+            return Ok(None);
+        }
+
+        let [e0, e1, e2, e3] = self.epoch.to_le_bytes();
+        let mut crc = crc16(CRC16_INIT, &[e0, e1, e2, e3, len]);
+        let mut chunk = [0; CHUNK_LEN];
+        let mut position = offset;
+        let mut left = usize::from(len);
+        while left > 0 {
+            let (data, _) = split_mut(&mut chunk, left);
+            let n = data.len();
+            self.storage
+                .read(position, data)
+                .map_err(LogError::Storage)?;
+            crc = crc16(crc, data);
+            position += n as u32;
+            left -= n;
+        }
+        let mut stored = [0; 2];
+        self.storage
+            .read(position, &mut stored)
+            .map_err(LogError::Storage)?;
+        if crc != u16::from_le_bytes(stored) {
+            return Ok(None);
+        }
+        Ok(Some(Record { offset, len }))
+    }
+}
+
+impl<S> Iterator for Records<'_, S>
+where
+    S: ReadStorage,
+{
+    type Item = Result<Record, LogError<S::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+        match self.check() {
+            Ok(Some(record)) => {
+                self.position = record.end();
+                Some(Ok(record))
+            }
+            Ok(None) => {
+                // the record was valid when the log was mounted
+                self.end = self.position;
+                Some(Err(LogError::Corrupted))
+            }
+            Err(e) => {
+                self.end = self.position;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Return the checksum of a record in the given epoch.
+fn record_crc(epoch: u32, len: u8, data: &[u8]) -> u16 {
+    let [e0, e1, e2, e3] = epoch.to_le_bytes();
+    crc16(crc16(CRC16_INIT, &[e0, e1, e2, e3, len]), data)
+}
@@ -0,0 +1,133 @@
+use crate::slice::{split, split_mut};
+use crate::ExportError;
+use core::fmt::Write;
+use core::ops::Range;
+use embedded_storage::ReadStorage;
+
+/// Number of data bytes per record.
+const RECORD_DATA_LEN: usize = 16;
+
+/// Read a region in chunks of up to `RECORD_DATA_LEN` bytes and pass them with their
+/// offset to a callback.
+fn for_each_chunk<S, F>(
+    storage: &mut S,
+    range: Range<u32>,
+    mut f: F,
+) -> Result<(), ExportError<S::Error>>
+where
+    S: ReadStorage,
+    F: FnMut(u32, &[u8]) -> core::fmt::Result,
+{
+    if range.end as usize > storage.capacity() {
+        return Err(ExportError::OutOfBounds);
+    }
+    let mut chunk = [0; RECORD_DATA_LEN];
+    let mut offset = range.start;
+    while offset < range.end {
+        let (data, _) = split_mut(&mut chunk, (range.end - offset) as usize);
+        storage.read(offset, data).map_err(ExportError::Storage)?;
+        f(offset, data).map_err(|_| ExportError::Format)?;
+        offset += data.len() as u32;
+    }
+    Ok(())
+}
+
+/// Write a Motorola S-record: type, byte count, address, data and checksum.
+fn write_srec_record<W: Write>(
+    out: &mut W,
+    record_type: u8,
+    address: &[u8],
+    data: &[u8],
+) -> core::fmt::Result {
+    let count = (address.len() + data.len() + 1) as u8;
+    let sum = address
+        .iter()
+        .chain(data)
+        .fold(count, |sum, byte| sum.wrapping_add(*byte));
+    write!(out, "S{}{:02X}", record_type, count)?;
+    for byte in address.iter().chain(data) {
+        write!(out, "{:02X}", byte)?;
+    }
+    writeln!(out, "{:02X}", !sum)
+}
+
+/// Export a region of a storage in the Motorola S-record format.
+///
+/// The output starts with an empty S0 header, followed by data records of 16 bytes at
+/// their offset in the storage, a record count and a termination record. The data records
+/// use 16-bit (S1), 24-bit (S2) or 32-bit (S3) addresses depending on the end of the region.
+pub fn write_srec<S, W>(
+    storage: &mut S,
+    range: Range<u32>,
+    out: &mut W,
+) -> Result<(), ExportError<S::Error>>
+where
+    S: ReadStorage,
+    W: Write,
+{
+    let address_len = match range.end {
+        0..=0x1_0000 => 2,
+        0x1_0001..=0x100_0000 => 3,
+        _ => 4,
+    };
+    write_srec_record(out, 0, &[0, 0], &[]).map_err(|_| ExportError::Format)?;
+    let mut records = 0u32;
+    for_each_chunk(storage, range, |offset, data| {
+        let address = offset.to_be_bytes();
+        let (_, address) = split(&address, 4 - address_len);
+        records += 1;
+        write_srec_record(out, address_len as u8 - 1, address, data)
+    })?;
+    let count = if records > 0xFFFF {
+        // S6 holds a 24-bit count
+        write_srec_record(out, 6, split(&records.to_be_bytes(), 1).1, &[])
+    } else {
+        write_srec_record(out, 5, &(records as u16).to_be_bytes(), &[])
+    };
+    count.map_err(|_| ExportError::Format)?;
+    let zero = [0; 4];
+    write_srec_record(
+        out,
+        11 - address_len as u8,
+        split(&zero, address_len).0,
+        &[],
+    )
+    .map_err(|_| ExportError::Format)
+}
+
+/// Write a hex dump of a region of a storage.
+///
+/// Each line shows the offset, 16 bytes in hexadecimal and their printable ASCII
+/// characters, like `hexdump -C`:
+///
+/// ```text
+/// 00000010  48 65 6c 6c 6f 00 ff ff  ff ff ff ff ff ff ff ff  |Hello...........|
+/// ```
+pub fn hexdump<S, W>(
+    storage: &mut S,
+    range: Range<u32>,
+    out: &mut W,
+) -> Result<(), ExportError<S::Error>>
+where
+    S: ReadStorage,
+    W: Write,
+{
+    for_each_chunk(storage, range, |offset, data| {
+        write!(out, "{:08x} ", offset)?;
+        for index in 0..RECORD_DATA_LEN {
+            if index % 8 == 0 {
+                out.write_char(' ')?;
+            }
+            match data.get(index) {
+                Some(byte) => write!(out, "{:02x} ", byte)?,
+                None => out.write_str("   ")?,
+            }
+        }
+        out.write_str(" |")?;
+        for byte in data {
+            let printable = byte.is_ascii_graphic() || *byte == b' ';
+            out.write_char(if printable { char::from(*byte) } else { '.' })?;
+        }
+        writeln!(out, "|")
+    })
+}
@@ -0,0 +1,122 @@
+use crate::{Eeprom24xTrait, Error, FaultError, Faults, FaultyEeprom};
+
+impl<T> FaultyEeprom<T> {
+    /// Create a new instance wrapping a device with the given faults.
+    pub fn new(eeprom: T, faults: Faults) -> Self {
+        FaultyEeprom {
+            eeprom,
+            faults,
+            accesses: 0,
+        }
+    }
+
+    /// Destroy the wrapper, return the device.
+    pub fn destroy(self) -> T {
+        self.eeprom
+    }
+
+    /// Set the faults injected into subsequent accesses.
+    ///
+    /// The access count is kept.
+    pub fn set_faults(&mut self, faults: Faults) {
+        self.faults = faults;
+    }
+
+    /// Return the number of accesses so far, including the failed ones.
+    pub fn accesses(&self) -> u32 {
+        self.accesses
+    }
+}
+
+/// Check whether a fault with the given period is due in an access.
+fn is_due(access: u32, period: u32) -> bool {
+    access.checked_rem(period) == Some(0)
+}
+
+impl<T> FaultyEeprom<T>
+where
+    T: Eeprom24xTrait,
+{
+    /// Count an access and return the error to inject into it, if any.
+    fn begin_access(&mut self) -> Result<(), Error<FaultError<T::Error>>> {
+        self.accesses = self.accesses.wrapping_add(1);
+        if is_due(self.accesses, self.faults.nack_every) {
+            Err(Error::Busy(FaultError::Nack))
+        } else if is_due(self.accesses, self.faults.timeout_every) {
+            Err(Error::I2C(FaultError::Timeout))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Corrupt the data read in the current access if due.
+    fn corrupt(&self, data: &mut [u8]) {
+        if is_due(self.accesses, self.faults.corrupt_every) {
+            for byte in data {
+                *byte ^= self.faults.corrupt_mask;
+            }
+        }
+    }
+}
+
+impl<T> Eeprom24xTrait for FaultyEeprom<T>
+where
+    T: Eeprom24xTrait,
+{
+    type Error = FaultError<T::Error>;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<Self::Error>> {
+        self.begin_access()?;
+        self.eeprom
+            .write_byte(address, data)
+            .map_err(|e| e.map_bus(FaultError::Device))
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Error<Self::Error>> {
+        self.begin_access()?;
+        let mut data = [self
+            .eeprom
+            .read_byte(address)
+            .map_err(|e| e.map_bus(FaultError::Device))?];
+        self.corrupt(&mut data);
+        Ok(data[0])
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<Self::Error>> {
+        self.begin_access()?;
+        self.eeprom
+            .read_data(address, data)
+            .map_err(|e| e.map_bus(FaultError::Device))?;
+        self.corrupt(data);
+        Ok(())
+    }
+
+    fn read_current_address(&mut self) -> Result<u8, Error<Self::Error>> {
+        self.begin_access()?;
+        let mut data = [self
+            .eeprom
+            .read_current_address()
+            .map_err(|e| e.map_bus(FaultError::Device))?];
+        self.corrupt(&mut data);
+        Ok(data[0])
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        self.begin_access()?;
+        self.eeprom
+            .write_page(address, data)
+            .map_err(|e| e.map_bus(FaultError::Device))
+    }
+
+    fn page_size(&self) -> usize {
+        self.eeprom.page_size()
+    }
+
+    fn capacity(&self) -> usize {
+        self.eeprom.capacity()
+    }
+
+    fn write_cycle_ms(&self) -> u32 {
+        self.eeprom.write_cycle_ms()
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    eeprom24x::MultiSizeAddr, eeprom24x::PageWrite, eeprom24x::WriteCycle, page_size, Eeprom24x,
+    Error, SlaveAddr,
+};
+use core::marker::PhantomData;
+use embedded_hal::i2c::I2c;
+
+/// Specialization for devices without page size limit (e.g. FRAMs)
+impl<I2C, E, AS, SN> Eeprom24x<I2C, page_size::Unlimited, AS, SN>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Create generic instance for devices without page size limit.
+    pub(crate) fn new(i2c: I2C, address: SlaveAddr, address_bits: u8) -> Self {
+        Eeprom24x {
+            i2c,
+            address,
+            address_bits,
+            secure_region_address: None,
+            write_pending: false,
+            max_transfer_len: usize::MAX,
+            _ps: PhantomData,
+            _as: PhantomData,
+            _sn: PhantomData,
+        }
+    }
+}
+
+impl<I2C, E, AS, SN> Eeprom24x<I2C, page_size::Unlimited, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+{
+    /// Write any amount of data starting in an address.
+    ///
+    /// The data is sent in a single transfer. If the data does not fit into the
+    /// device memory starting at the address, the error `Error::CapacityExceeded`
+    /// will be returned. If it would cross into a region accessed through a
+    /// different device address, the error `Error::PageOverflow` will be returned.
+    ///
+    /// These devices do not have a write cycle so that they can be accessed
+    /// again immediately.
+    pub fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        self.get_device_address(address)?;
+        let allowed = self.capacity().saturating_sub(address as usize);
+        if data.len() > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: data.len(),
+                allowed,
+            });
+        }
+        // memory beyond the memory address range is accessed through another device address
+        let block_size = 1 << (AS::ADDRESS_BYTES * 8);
+        self.access().write_page(address, data, block_size, false)
+    }
+}
+
+impl<I2C, E, AS, SN> PageWrite<E> for Eeprom24x<I2C, page_size::Unlimited, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+{
+    fn page_write(&mut self, address: u32, data: &[u8]) -> Result<(), Error<E>> {
+        self.write_page(address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<I2C, E, AS, SN> crate::Eeprom24xTrait for Eeprom24x<I2C, page_size::Unlimited, AS, SN>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+{
+    type Error = E;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Error<Self::Error>> {
+        self.write_byte(address, data)
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Error<Self::Error>> {
+        self.read_byte(address)
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Error<Self::Error>> {
+        self.read_data(address, data)
+    }
+
+    fn read_current_address(&mut self) -> Result<u8, Error<Self::Error>> {
+        self.read_current_address()
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<Self::Error>> {
+        self.write_page(address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        self.capacity()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn write_cycle_ms(&self) -> u32 {
+        page_size::Unlimited::WRITE_CYCLE_MS
+    }
+}
+
+#[cfg(feature = "two-byte-addr")]
+macro_rules! impl_for_frams {
+    ( $( [ $dev:expr, $address_bits:expr, $create:ident ] ),* ) => {
+        impl<I2C, E> Eeprom24x<I2C, page_size::Unlimited, crate::addr_size::TwoBytes, crate::unique_serial::No>
+        where
+            I2C: I2c<Error = E>,
+        {
+            $(
+            #[doc = concat!("Create a new instance of a ", $dev, " FRAM device")]
+            pub fn $create(i2c: I2C, address: SlaveAddr) -> Self {
+                Self::new(i2c, address, $address_bits)
+            }
+            )*
+        }
+    };
+}
+
+#[cfg(feature = "two-byte-addr")]
+impl_for_frams!(
+    ["MB85RC64", 13, new_mb85rc64],
+    ["MB85RC128", 14, new_mb85rc128],
+    ["MB85RC256", 15, new_mb85rc256],
+    ["MB85RC512", 16, new_mb85rc512],
+    ["FM24CL64", 13, new_fm24cl64],
+    ["FM24W256", 15, new_fm24w256]
+);
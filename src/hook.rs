@@ -0,0 +1,62 @@
+use crate::HookedI2c;
+use embedded_hal::i2c::{ErrorType, I2c, Operation, SevenBitAddress};
+
+impl<I2C, H> HookedI2c<I2C, H>
+where
+    I2C: ErrorType,
+    H: FnMut(&mut I2C) -> Result<(), I2C::Error>,
+{
+    /// Create a new instance wrapping an I²C bus with a hook called before each access.
+    pub fn new(i2c: I2C, hook: H) -> Self {
+        HookedI2c { i2c, hook }
+    }
+}
+
+impl<I2C, H> HookedI2c<I2C, H> {
+    /// Destroy the wrapper, return the I²C bus instance and the hook.
+    pub fn destroy(self) -> (I2C, H) {
+        (self.i2c, self.hook)
+    }
+}
+
+impl<I2C, H> ErrorType for HookedI2c<I2C, H>
+where
+    I2C: ErrorType,
+{
+    type Error = I2C::Error;
+}
+
+impl<I2C, H> I2c<SevenBitAddress> for HookedI2c<I2C, H>
+where
+    I2C: I2c<SevenBitAddress>,
+    H: FnMut(&mut I2C) -> Result<(), I2C::Error>,
+{
+    fn read(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        (self.hook)(&mut self.i2c)?;
+        self.i2c.read(address, read)
+    }
+
+    fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        (self.hook)(&mut self.i2c)?;
+        self.i2c.write(address, write)
+    }
+
+    fn write_read(
+        &mut self,
+        address: SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        (self.hook)(&mut self.i2c)?;
+        self.i2c.write_read(address, write, read)
+    }
+
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        (self.hook)(&mut self.i2c)?;
+        self.i2c.transaction(address, operations)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{addr_size::TwoBytes, Eeprom24x, Error, SlaveAddr};
+use embedded_hal::i2c::{Error as I2cError, ErrorKind, I2c, NoAcknowledgeSource};
+
+/// Memory address used for the lock status and lock commands (bit A10 set).
+const LOCK_ADDRESS: [u8; 2] = [0x04, 0x00];
+/// Memory address of the configurable device address register of M24xxxE-F devices.
+const CDA_ADDRESS: [u8; 2] = [0xC0, 0x00];
+
+/// Methods for interacting with the identification page of ST devices with two byte
+/// addresses. e.g. M24C32-D, M24C64-D, M24128-D, M24256-D, M24512-D and M24M01-D.
+impl<I2C, PS, SN, E> Eeprom24x<I2C, PS, TwoBytes, SN>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Check whether the identification page is locked.
+    ///
+    /// The lock status is determined by the acknowledge of a data byte sent to the
+    /// identification page with the address bit A10 set: the data byte is acknowledged
+    /// only while the page is unlocked. The data byte sent does not lock the page.
+    ///
+    /// Note that the datasheet recommends ending the sequence with a START condition,
+    /// which is not possible with `embedded-hal`. Depending on the device, the STOP
+    /// condition sent instead may start an internally-timed write cycle, during which the
+    /// device does not respond, so wait for the write cycle time before the next access.
+    /// Only ST devices with an identification page support this.
+    pub fn is_id_page_locked(&mut self) -> Result<bool, Error<E>> {
+        let id_page_addr = self.id_page_addr();
+        let payload = [LOCK_ADDRESS[0], LOCK_ADDRESS[1], 0];
+        match self.i2c.write(id_page_addr, &payload) {
+            Ok(()) => Ok(false),
+            Err(e) if e.kind() == ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data) => Ok(true),
+            Err(e) => Err(Error::from_bus(e, self.write_pending)),
+        }
+    }
+}
+
+/// Methods for interacting with the configurable device address (CDA) register of ST
+/// devices. e.g. M24256E-F.
+///
+/// The CDA register contains the chip enable bits C2, C1 and C0 in bits 3 to 1, which
+/// replace the E2, E1 and E0 pins for the device address, and the device address lock
+/// bit (DAL) in bit 0.
+impl<I2C, PS, SN, E> Eeprom24x<I2C, PS, TwoBytes, SN>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Read the configurable device address register.
+    pub fn read_cda(&mut self) -> Result<u8, Error<E>> {
+        let mut data = [0];
+        let result = self
+            .i2c
+            .write_read(self.id_page_addr(), &CDA_ADDRESS, &mut data);
+        self.finish_access(result, false).and(Ok(data[0]))
+    }
+
+    /// Program the chip enable bits of the configurable device address register.
+    ///
+    /// `pins` contains the new C2, C1 and C0 bits like in [`SlaveAddr::from_pins()`].
+    /// Higher bits are ignored. The device address lock bit is left cleared.
+    /// After a successful write, the driver uses the new device address.
+    ///
+    /// The EEPROM enters an internally-timed write cycle afterwards during which it does
+    /// not respond. The writes are ignored by the device if the device address is locked.
+    pub fn write_cda(&mut self, pins: u8) -> Result<(), Error<E>> {
+        let cda = (pins & 0b111) << 1;
+        let result = self
+            .i2c
+            .write(self.id_page_addr(), &[CDA_ADDRESS[0], CDA_ADDRESS[1], cda]);
+        self.finish_access(result, true)?;
+        self.address = SlaveAddr::from_pins(pins);
+        Ok(())
+    }
+}
+
+impl<I2C, PS, SN> Eeprom24x<I2C, PS, TwoBytes, SN> {
+    fn id_page_addr(&self) -> u8 {
+        0b101_1000 | (self.address.addr() & 0b111)
+    }
+}
@@ -0,0 +1,197 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::split_mut;
+use crate::{Journal, JournalError};
+use embedded_storage::{ReadStorage, Storage};
+
+/// Marker at the start of the journal header.
+const MAGIC: [u8; 2] = *b"JN";
+/// State of a journal holding a complete transaction which must be applied.
+const COMMITTED: u8 = 0xC0;
+/// State of a journal without pending transaction.
+const IDLE: u8 = 0x00;
+/// Size of the journal header: magic, state, length of the entries and their checksum.
+const HEADER_LEN: u32 = 7;
+/// Size of an entry header: data offset and length.
+const ENTRY_HEADER_LEN: u32 = 6;
+/// Size of the chunks in which the entries are copied.
+const CHUNK_LEN: usize = 64;
+
+impl<S> Journal<S> {
+    /// Destroy the journal, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S> Journal<S>
+where
+    S: Storage,
+{
+    /// Create a new instance reserving the first `journal_len` bytes of the storage for
+    /// the journal.
+    ///
+    /// A transaction committed before a power loss but not completely applied is applied
+    /// again. Any other pending writes are discarded.
+    ///
+    /// The journal header should not cross a page boundary, so the storage should start
+    /// at one.
+    pub fn new(storage: S, journal_len: usize) -> Result<Self, JournalError<S::Error>> {
+        if journal_len < (HEADER_LEN + ENTRY_HEADER_LEN) as usize
+            || journal_len > storage.capacity()
+        {
+            return Err(JournalError::JournalFull);
+        }
+        let mut journal = Journal {
+            storage,
+            journal_len: journal_len as u32,
+            end: HEADER_LEN,
+            crc: CRC16_INIT,
+        };
+        journal.recover()?;
+        Ok(journal)
+    }
+
+    /// Add a write at an offset of the data area to the transaction.
+    ///
+    /// The data is only written to the data area by [`commit()`](Self::commit). Reads
+    /// return the previous data until then. Returns `JournalError::JournalFull` if the
+    /// journal cannot hold the write.
+    pub fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), JournalError<S::Error>> {
+        let allowed = self.capacity().saturating_sub(offset as usize);
+        if data.len() > allowed {
+            return Err(JournalError::OutOfBounds);
+        }
+        let size = ENTRY_HEADER_LEN as usize + data.len();
+        if size > (self.journal_len - self.end) as usize || self.end as usize + size > 0xFFFF {
+            return Err(JournalError::JournalFull);
+        }
+        let [o0, o1, o2, o3] = offset.to_le_bytes();
+        let [l0, l1] = (data.len() as u16).to_le_bytes();
+        let header = [o0, o1, o2, o3, l0, l1];
+        self.storage
+            .write(self.end, &header)
+            .map_err(JournalError::Storage)?;
+        self.storage
+            .write(self.end + ENTRY_HEADER_LEN, data)
+            .map_err(JournalError::Storage)?;
+        self.crc = crc16(crc16(self.crc, &header), data);
+        self.end += size as u32;
+        Ok(())
+    }
+
+    /// Apply all the writes of the transaction.
+    ///
+    /// The transaction is marked as committed in the journal before the data area is
+    /// updated, so that it is completed when the journal is created after a power loss.
+    pub fn commit(&mut self) -> Result<(), JournalError<S::Error>> {
+        if self.end == HEADER_LEN {
+            return Ok(());
+        }
+        let [m0, m1] = MAGIC;
+        let [l0, l1] = ((self.end - HEADER_LEN) as u16).to_le_bytes();
+        let [c0, c1] = self.crc.to_le_bytes();
+        self.storage
+            .write(0, &[m0, m1, COMMITTED, l0, l1, c0, c1])
+            .map_err(JournalError::Storage)?;
+        self.apply(self.end)?;
+        self.finish()
+    }
+
+    /// Discard all the writes of the transaction.
+    pub fn abort(&mut self) {
+        self.end = HEADER_LEN;
+        self.crc = CRC16_INIT;
+    }
+
+    /// Apply a committed transaction found in the journal.
+    fn recover(&mut self) -> Result<(), JournalError<S::Error>> {
+        let mut header = [0; HEADER_LEN as usize];
+        self.storage
+            .read(0, &mut header)
+            .map_err(JournalError::Storage)?;
+        let [m0, m1, state, l0, l1, c0, c1] = header;
+        if [m0, m1] != MAGIC || state != COMMITTED {
+            return Ok(());
+        }
+        let end = HEADER_LEN + u32::from(u16::from_le_bytes([l0, l1]));
+        if end > self.journal_len || self.checksum(end)? != u16::from_le_bytes([c0, c1]) {
+            return self.finish();
+        }
+        self.apply(end)?;
+        self.finish()
+    }
+
+    /// Mark the journal as idle and start a new transaction.
+    fn finish(&mut self) -> Result<(), JournalError<S::Error>> {
+        self.storage
+            .write(2, &[IDLE])
+            .map_err(JournalError::Storage)?;
+        self.abort();
+        Ok(())
+    }
+
+    /// Return the checksum of the entries up to `end`.
+    fn checksum(&mut self, end: u32) -> Result<u16, JournalError<S::Error>> {
+        let mut crc = CRC16_INIT;
+        let mut chunk = [0; CHUNK_LEN];
+        let mut position = HEADER_LEN;
+        while position < end {
+            let (data, _) = split_mut(&mut chunk, (end - position) as usize);
+            self.storage
+                .read(position, data)
+                .map_err(JournalError::Storage)?;
+            crc = crc16(crc, data);
+            position += data.len() as u32;
+        }
+        Ok(crc)
+    }
+
+    /// Copy the data of the entries up to `end` to the data area.
+    fn apply(&mut self, end: u32) -> Result<(), JournalError<S::Error>> {
+        let mut chunk = [0; CHUNK_LEN];
+        let mut position = HEADER_LEN;
+        while position + ENTRY_HEADER_LEN <= end {
+            let mut header = [0; ENTRY_HEADER_LEN as usize];
+            self.storage
+                .read(position, &mut header)
+                .map_err(JournalError::Storage)?;
+            let [o0, o1, o2, o3, l0, l1] = header;
+            let mut offset = u32::from_le_bytes([o0, o1, o2, o3]);
+            let mut left = usize::from(u16::from_le_bytes([l0, l1]));
+            position += ENTRY_HEADER_LEN;
+            while left > 0 {
+                let (data, _) = split_mut(&mut chunk, left);
+                let len = data.len();
+                self.storage
+                    .read(position, data)
+                    .map_err(JournalError::Storage)?;
+                self.storage
+                    .write(self.journal_len + offset, data)
+                    .map_err(JournalError::Storage)?;
+                position += len as u32;
+                offset += len as u32;
+                left -= len;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S> ReadStorage for Journal<S>
+where
+    S: ReadStorage,
+{
+    type Error = S::Error;
+
+    /// Read from the data area.
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.storage.read(self.journal_len + offset, bytes)
+    }
+
+    /// Return the size of the data area.
+    fn capacity(&self) -> usize {
+        self.storage
+            .capacity()
+            .saturating_sub(self.journal_len as usize)
+    }
+}
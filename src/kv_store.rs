@@ -0,0 +1,391 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::{copy, split_mut};
+use crate::{KvError, KvStore};
+use embedded_storage::Storage;
+
+/// Marker at the start of a valid bank header.
+const MAGIC: [u8; 2] = *b"KV";
+/// Size of a bank header: magic, generation, key length and checksum.
+const HEADER_LEN: u32 = 9;
+/// Tag of a record containing a value.
+const TAG_VALUE: u8 = 0xA5;
+/// Tag of a record marking a key as removed.
+const TAG_REMOVED: u8 = 0x5A;
+/// Tag following the last record.
+const TAG_END: u8 = 0xFF;
+/// Size of the buffer used when checking and copying records.
+const CHUNK_LEN: usize = 16;
+/// Largest supported key length.
+const MAX_KEY_LEN: usize = 32;
+
+/// Header of a record: tag, key and value length
+#[derive(Clone, Copy)]
+struct Record<const K: usize> {
+    tag: u8,
+    key: [u8; K],
+    len: u8,
+}
+
+impl<const K: usize> Record<K> {
+    /// Offset of the value from the start of the record
+    const VALUE_OFFSET: u32 = 2 + K as u32;
+
+    /// Size of the record including the checksum
+    fn size(&self) -> u32 {
+        Self::VALUE_OFFSET + u32::from(self.len) + 2
+    }
+}
+
+impl<S, const K: usize> KvStore<S, K> {
+    /// Maximum length of a value in bytes.
+    pub const MAX_VALUE_LEN: usize = u8::MAX as usize;
+
+    /// Compile-time check of the key length.
+    const KEY_LEN_OK: () = assert!(K > 0 && K <= MAX_KEY_LEN, "unsupported key length");
+
+    /// Destroy the store, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+
+    fn new(storage: S) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::KEY_LEN_OK;
+        KvStore {
+            storage,
+            bank: 0,
+            generation: 0,
+            end: HEADER_LEN,
+        }
+    }
+}
+
+impl<S, const K: usize> KvStore<S, K>
+where
+    S: Storage,
+{
+    /// Create an empty store in the storage.
+    ///
+    /// The storage is split into two banks of which one holds the records while the
+    /// other one receives the live records during compaction. Any previous contents are
+    /// discarded. Returns `KvError::Full` if the storage is too small.
+    pub fn format(storage: S) -> Result<Self, KvError<S::Error>> {
+        let mut store = Self::new(storage);
+        if store.bank_size() <= HEADER_LEN {
+            return Err(KvError::Full);
+        }
+        // make sure that the other bank is not mounted instead
+        let other = store.base(1);
+        store.write(other, &[0; 2])?;
+        store.write(HEADER_LEN, &[TAG_END])?;
+        store.write_header(0, 1)?;
+        store.generation = 1;
+        Ok(store)
+    }
+
+    /// Open a store previously created with [`format()`](Self::format).
+    ///
+    /// Returns `KvError::NotFormatted` if the storage does not contain a store with
+    /// keys of this length.
+    pub fn mount(storage: S) -> Result<Self, KvError<S::Error>> {
+        let mut store = Self::new(storage);
+        if store.bank_size() <= HEADER_LEN {
+            return Err(KvError::NotFormatted);
+        }
+        let (bank, generation) = match (store.read_header(0)?, store.read_header(1)?) {
+            (Some(first), Some(second)) if is_newer(second, first) => (1, second),
+            (Some(first), _) => (0, first),
+            (None, Some(second)) => (1, second),
+            (None, None) => return Err(KvError::NotFormatted),
+        };
+        store.bank = bank;
+        store.generation = generation;
+        store.end = store.find_end()?;
+        Ok(store)
+    }
+
+    /// Read the value of a key into the buffer and return its length.
+    ///
+    /// Returns `None` if the key is not present and `KvError::BufferTooSmall` if the
+    /// value does not fit into the buffer.
+    pub fn get(
+        &mut self,
+        key: &[u8; K],
+        value: &mut [u8],
+    ) -> Result<Option<usize>, KvError<S::Error>> {
+        match self.lookup(key)? {
+            Some((offset, record)) if record.tag == TAG_VALUE => {
+                let len = usize::from(record.len);
+                let buffer = value
+                    .get_mut(..len)
+                    .ok_or(KvError::BufferTooSmall { required: len })?;
+                let offset = self.base(self.bank) + offset + Record::<K>::VALUE_OFFSET;
+                self.storage
+                    .read(offset, buffer)
+                    .map_err(KvError::Storage)?;
+                Ok(Some(len))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Store the value of a key.
+    ///
+    /// The record is appended after the existing ones. If the bank is full, the store is
+    /// compacted first. Returns `KvError::ValueTooLong` if the value is longer than
+    /// [`MAX_VALUE_LEN`](Self::MAX_VALUE_LEN) and `KvError::Full` if the record does not
+    /// fit even after compaction.
+    pub fn set(&mut self, key: &[u8; K], value: &[u8]) -> Result<(), KvError<S::Error>> {
+        let len = u8::try_from(value.len()).map_err(|_| KvError::ValueTooLong)?;
+        self.append(
+            Record {
+                tag: TAG_VALUE,
+                key: *key,
+                len,
+            },
+            value,
+        )
+    }
+
+    /// Remove a key.
+    ///
+    /// Nothing is written if the key is not present.
+    pub fn remove(&mut self, key: &[u8; K]) -> Result<(), KvError<S::Error>> {
+        match self.lookup(key)? {
+            Some((_, record)) if record.tag == TAG_VALUE => self.append(
+                Record {
+                    tag: TAG_REMOVED,
+                    key: *key,
+                    len: 0,
+                },
+                &[],
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Copy the current value of every key into the other bank and switch to it.
+    ///
+    /// This happens automatically when the bank is full. The new bank only becomes active
+    /// once all the records have been copied, so that an interruption leaves the store
+    /// unchanged. Note that finding out which records are current takes a number of
+    /// reads growing quadratically with the number of records.
+    pub fn compact(&mut self) -> Result<(), KvError<S::Error>> {
+        let source = self.base(self.bank);
+        let target_bank = 1 - self.bank;
+        let target = self.base(target_bank);
+        let mut offset = HEADER_LEN;
+        let mut target_end = HEADER_LEN;
+        while offset < self.end {
+            let record = self.read_record(source + offset)?;
+            let next = offset + record.size();
+            if record.tag == TAG_VALUE && !self.is_superseded(next, &record.key)? {
+                self.copy(source + offset, target + target_end, record.size())?;
+                target_end += record.size();
+            }
+            offset = next;
+        }
+        self.write(target + target_end, &[TAG_END])?;
+        let generation = self.generation.wrapping_add(1);
+        self.write_header(target_bank, generation)?;
+        self.bank = target_bank;
+        self.generation = generation;
+        self.end = target_end;
+        Ok(())
+    }
+
+    fn bank_size(&self) -> u32 {
+        (self.storage.capacity() / 2) as u32
+    }
+
+    fn base(&self, bank: u32) -> u32 {
+        bank * self.bank_size()
+    }
+
+    fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), KvError<S::Error>> {
+        self.storage.write(offset, data).map_err(KvError::Storage)
+    }
+
+    fn read_header(&mut self, bank: u32) -> Result<Option<u32>, KvError<S::Error>> {
+        let mut header = [0; HEADER_LEN as usize];
+        let offset = self.base(bank);
+        self.storage
+            .read(offset, &mut header)
+            .map_err(KvError::Storage)?;
+        let [m0, m1, g0, g1, g2, g3, key_len, c0, c1] = header;
+        let valid = [m0, m1] == MAGIC
+            && usize::from(key_len) == K
+            && crc16(CRC16_INIT, &[m0, m1, g0, g1, g2, g3, key_len])
+                == u16::from_le_bytes([c0, c1]);
+        Ok(valid.then_some(u32::from_le_bytes([g0, g1, g2, g3])))
+    }
+
+    fn write_header(&mut self, bank: u32, generation: u32) -> Result<(), KvError<S::Error>> {
+        let [m0, m1] = MAGIC;
+        let [g0, g1, g2, g3] = generation.to_le_bytes();
+        let data = [m0, m1, g0, g1, g2, g3, K as u8];
+        let [c0, c1] = crc16(CRC16_INIT, &data).to_le_bytes();
+        let offset = self.base(bank);
+        self.write(offset, &[m0, m1, g0, g1, g2, g3, K as u8, c0, c1])
+    }
+
+    /// Read the header of the record at an absolute offset.
+    fn read_record(&mut self, offset: u32) -> Result<Record<K>, KvError<S::Error>> {
+        let mut buffer = [0; MAX_KEY_LEN + 2];
+        let (header, _) = split_mut(&mut buffer, Record::<K>::VALUE_OFFSET as usize);
+        self.storage
+            .read(offset, header)
+            .map_err(KvError::Storage)?;
+        // the header holds the tag, at least one key byte and the length
+        let [tag, key_bytes @ .., len] = header else {
+            return Err(KvError::NotFormatted);
+        };
+        let mut key = [0; K];
+        copy(&mut key, key_bytes);
+        Ok(Record {
+            tag: *tag,
+            key,
+            len: *len,
+        })
+    }
+
+    /// Check whether a complete record is stored at an absolute offset.
+    fn is_valid(&mut self, offset: u32, record: &Record<K>) -> Result<bool, KvError<S::Error>> {
+        let mut crc = crc16(CRC16_INIT, &[record.tag]);
+        crc = crc16(crc, &record.key);
+        crc = crc16(crc, &[record.len]);
+        let mut position = offset + Record::<K>::VALUE_OFFSET;
+        let end = position + u32::from(record.len);
+        let mut buffer = [0; CHUNK_LEN];
+        while position < end {
+            let (chunk, _) = split_mut(&mut buffer, (end - position) as usize);
+            let len = chunk.len() as u32;
+            self.storage
+                .read(position, chunk)
+                .map_err(KvError::Storage)?;
+            crc = crc16(crc, chunk);
+            position += len;
+        }
+        let mut stored = [0; 2];
+        self.storage
+            .read(end, &mut stored)
+            .map_err(KvError::Storage)?;
+        Ok(crc == u16::from_le_bytes(stored))
+    }
+
+    /// Find the end of the complete records in the active bank.
+    fn find_end(&mut self) -> Result<u32, KvError<S::Error>> {
+        let base = self.base(self.bank);
+        let mut offset = HEADER_LEN;
+        while offset + Record::<K>::VALUE_OFFSET < self.bank_size() {
+            let record = self.read_record(base + offset)?;
+            // leave space for the end tag
+            let fits = offset + record.size() < self.bank_size();
+            if !matches!(record.tag, TAG_VALUE | TAG_REMOVED)
+                || !fits
+                || !self.is_valid(base + offset, &record)?
+            {
+                return Ok(offset);
+            }
+            offset += record.size();
+        }
+        Ok(offset)
+    }
+
+    /// Find the last record of a key in the active bank.
+    fn lookup(&mut self, key: &[u8; K]) -> Result<Option<(u32, Record<K>)>, KvError<S::Error>> {
+        let base = self.base(self.bank);
+        let mut offset = HEADER_LEN;
+        let mut found = None;
+        while offset < self.end {
+            let record = self.read_record(base + offset)?;
+            if record.key == *key {
+                found = Some((offset, record));
+            }
+            offset += record.size();
+        }
+        Ok(found)
+    }
+
+    /// Check whether a key has a record starting at or after an offset in the active bank.
+    fn is_superseded(&mut self, offset: u32, key: &[u8; K]) -> Result<bool, KvError<S::Error>> {
+        let base = self.base(self.bank);
+        let mut offset = offset;
+        while offset < self.end {
+            let record = self.read_record(base + offset)?;
+            if record.key == *key {
+                return Ok(true);
+            }
+            offset += record.size();
+        }
+        Ok(false)
+    }
+
+    fn copy(&mut self, from: u32, to: u32, len: u32) -> Result<(), KvError<S::Error>> {
+        let mut buffer = [0; CHUNK_LEN];
+        let mut done = 0;
+        while done < len {
+            let (chunk, _) = split_mut(&mut buffer, (len - done) as usize);
+            let chunk_len = chunk.len() as u32;
+            self.storage
+                .read(from + done, chunk)
+                .map_err(KvError::Storage)?;
+            self.storage
+                .write(to + done, chunk)
+                .map_err(KvError::Storage)?;
+            done += chunk_len;
+        }
+        Ok(())
+    }
+
+    fn append(&mut self, record: Record<K>, value: &[u8]) -> Result<(), KvError<S::Error>> {
+        // leave space for the end tag
+        if self.end + record.size() >= self.bank_size() {
+            self.compact()?;
+            if self.end + record.size() >= self.bank_size() {
+                return Err(KvError::Full);
+            }
+        }
+        let mut crc = crc16(CRC16_INIT, &[record.tag]);
+        crc = crc16(crc, &record.key);
+        crc = crc16(crc, &[record.len]);
+        let [c0, c1] = crc16(crc, value).to_le_bytes();
+
+        // the tag is written last so that an interrupted append leaves no record behind
+        let offset = self.base(self.bank) + self.end;
+        let value_offset = offset + Record::<K>::VALUE_OFFSET;
+        self.write(offset + 1, &record.key)?;
+        self.write(offset + 1 + K as u32, &[record.len])?;
+        if !value.is_empty() {
+            self.write(value_offset, value)?;
+        }
+        self.write(value_offset + u32::from(record.len), &[c0, c1, TAG_END])?;
+        self.write(offset, &[record.tag])?;
+        self.end += record.size();
+        Ok(())
+    }
+}
+
+/// Compare two generation counters as serial numbers, so that the counter can wrap around.
+fn is_newer(generation: u32, other: u32) -> bool {
+    generation.wrapping_sub(other) as i32 > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_generations() {
+        assert!(is_newer(2, 1));
+        assert!(!is_newer(1, 2));
+        assert!(!is_newer(1, 1));
+    }
+
+    #[test]
+    fn compares_wrapped_generations() {
+        let (first, second) = (u32::MAX, 0);
+        assert!(is_newer(second, first));
+        assert!(!is_newer(first, second));
+    }
+}
@@ -10,7 +10,71 @@
 //! - Write a byte to a memory address. See: [`write_byte()`].
 //! - Write a byte array (up to a memory page) to a memory address. See: [`write_page()`].
 //! - Read `CSx`-variant devices' factory-programmed unique serial. See: [`read_unique_serial()`].
+//! - Write and permanently lock the security register of `CSW`-variant devices.
+//!   See: [`write_security_register()`].
+//! - Configure the software write protection zones of 24CW devices.
+//!   See: [`set_write_protection()`].
+//! - Set the software write protection of SPD devices. See: [`set_reversible_write_protection()`].
+//! - Read the factory-programmed EUI-48 of AT24MAC402 devices. See: [`read_eui48()`].
+//! - Read the factory-programmed EUI-64 of AT24MAC602 devices. See: [`read_eui64()`].
+//! - Read the factory-programmed EUI-48/EUI-64 of Microchip 24AA02E48, 24AA02E64,
+//!   24AA025E48 and 24AA025E64 devices.
 //! - Use the device in generic code via the [`Eeprom24xTrait`].
+//! - Use a device whose type is only known at runtime via [`Eeprom24xDyn`] and [`Device`],
+//!   also through the `embedded-storage` traits via [`DynStorage`].
+//! - Use several devices on the same bus via [`SharedBus`].
+//! - Share a device between execution contexts via `SharedEeprom` (feature "`critical-section`").
+//! - Exercise error recovery paths via `FaultyEeprom` (feature "`fault-injection`").
+//! - Use cascaded devices as a single address space via [`EepromChain`].
+//! - Cache the device memory in RAM with write-back via [`CachedEeprom`].
+//! - Accumulate small writes into page writes via [`WriteCoalescer`].
+//! - Speed up byte-oriented sequential reads via [`ReadAhead`].
+//! - Append a stream of data in whole page writes via [`BufWriter`].
+//! - Parse a stream of data byte by byte without a transfer per byte via [`BufReader`].
+//! - Keep device settings in a wear-leveled key-value store via `KvStore` (feature "`kv-store`").
+//! - Keep a versioned configuration with migration of older versions via `ConfigBlock`
+//!   (feature "`config`").
+//! - Keep two copies of a configuration so that saving is power-loss safe via `DualConfig`
+//!   (feature "`config`").
+//! - Split a device into regions for several subsystems via [`Partition`].
+//! - Share a device between firmware components through named slots via `SlotFs`
+//!   (feature "`slot-fs`").
+//! - Apply updates spanning several pages atomically via `Journal` (feature "`journal`").
+//! - Correct single-bit errors transparently via `EccStorage` (feature "`ecc`").
+//! - Keep two copies of the data and repair the bad one via `Mirror` (feature "`mirror`").
+//! - Encrypt the data at rest via `EncryptedStorage` (feature "`encryption`").
+//! - Detect offline modifications of the data via `AuthenticatedBlock` (feature "`auth`").
+//! - Compress configurations with a user-supplied `Compressor` (feature "`compression`").
+//! - Track flags like consumed one-time tokens via [`EepromBitmap`].
+//! - Allocate fixed-size blocks dynamically via `BlockAllocator` (feature "`block-alloc`").
+//! - Persist an RNG seed across reboots via `SeedStore` (feature "`seed`").
+//! - Count boots with wear leveling via `BootCounter` (feature "`boot-counter`").
+//! - Access persisted values through typed handles via [`EepromCell`] and [`EepromArray`].
+//! - Read and write plain-old-data structs via `Storage::read_obj()` and `Storage::write_obj()`
+//!   (feature "`zerocopy`").
+//! - Use serializers speaking `embedded-io` via `EepromCursor` (feature "`embedded-io`"),
+//!   or `std::io` (feature "`std`").
+//! - Record events in a circular log via `RingLog` (feature "`ring-log`"), also as
+//!   formatted text via `LogWriter`.
+//! - Keep an audit trail in an append-only log via `EventLog` (feature "`event-log`").
+//! - Store timestamped samples with time range lookup via `TimeSeries` (feature
+//!   "`time-series`").
+//! - Use I²C FRAMs without page size limit or write cycle (e.g. MB85RC256).
+//! - Configure the sector security of M24LR dual-interface devices.
+//!   See: [`write_sector_security()`].
+//! - STORE/RECALL the contents of 47x16 EERAM devices. See: [`store()`].
+//! - Use DDR4 SPD EEPROMs with transparent page selection via [`Ee1004`].
+//! - Select an I²C multiplexer channel before each access via [`HookedI2c`].
+//! - Discover which devices are present on the bus. See: [`scan()`].
+//! - Purge credentials by overwriting a region several times. See: [`secure_erase()`].
+//! - Rewrite data periodically to extend its retention. See: [`refresh()`].
+//! - Export the memory contents as Motorola S-records. See: [`write_srec()`].
+//! - Print the memory contents for diagnostics. See: [`hexdump()`].
+//! - Stream the memory contents in chunks to any destination. See: [`dump()`].
+//! - Write the memory contents in chunks pulled from any source. See: [`restore()`].
+//! - Back up and restore the whole device with geometry and integrity checks.
+//!   See: `snapshot()` and `restore_snapshot()` (feature "`snapshot`").
+//! - Read the I²C-bus Device ID of supporting devices. See: [`read_device_id()`].
 //!
 //! [`read_byte()`]: Eeprom24x::read_byte
 //! [`read_data()`]: Eeprom24x::read_data
@@ -18,6 +82,14 @@
 //! [`write_byte()`]: Eeprom24x::write_byte
 //! [`write_page()`]: Eeprom24x::write_page
 //! [`read_unique_serial()`]: Eeprom24x::read_unique_serial
+//! [`read_device_id()`]: Eeprom24x::read_device_id
+//! [`write_security_register()`]: Eeprom24x::write_security_register
+//! [`set_write_protection()`]: Eeprom24x::set_write_protection
+//! [`set_reversible_write_protection()`]: Eeprom24x::set_reversible_write_protection
+//! [`write_sector_security()`]: Eeprom24x::write_sector_security
+//! [`store()`]: Eeprom24x::store
+//! [`read_eui48()`]: Eeprom24x::read_eui48
+//! [`read_eui64()`]: Eeprom24x::read_eui64
 //! [`Eeprom24xTrait`]: Eeprom24xTrait
 //!
 //! If an `embedded_hal::timer::CountDown` is available, the [`embedded-storage`] traits can
@@ -72,6 +144,20 @@
 //!
 //! ## Features
 //!
+//! ### Device families
+//!
+//! The constructors are grouped into device families behind the default features
+//! "`one-byte-addr`" (devices with 1-byte memory addresses, e.g. 24x02), "`two-byte-addr`"
+//! (devices with 2-byte memory addresses including the FRAMs, e.g. 24x256) and
+//! "`cs-devices`" (devices with a factory-programmed serial number or identification
+//! registers, e.g. 24CSx02, 24MAC402). Firmware using only some device families can disable
+//! the default features to leave out the others.
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", default-features = false, features = ["one-byte-addr"] }
+//! ```
+//!
 //! ### defmt-03
 //!
 //! To enable [defmt](https://crates.io/crates/defmt) (version `0.3.x`) support, when specifying the dependency on `eeprom24x`, add the feature "`defmt-03`".
@@ -81,6 +167,259 @@
 //! eeprom24x = { version = "0.7.2", features = ["defmt-03"] }
 //! ```
 //!
+//! ### alloc
+//!
+//! To enable helpers for boxing devices as `Eeprom24xTrait` trait objects, e.g. to store
+//! heterogeneous devices in a collection, and reads returning a `Vec` like
+//! `read_vec()` and `read_all_vec()`, add the feature "`alloc`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["alloc"] }
+//! ```
+//!
+//! ### critical-section
+//!
+//! To share a device between different execution contexts (e.g. the main context and
+//! interrupt handlers) through `SharedEeprom`, add the feature "`critical-section`".
+//! A [`critical-section`] implementation must be provided, e.g. by the HAL.
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["critical-section"] }
+//! ```
+//!
+//! [`critical-section`]: https://crates.io/crates/critical-section
+//!
+//! ### serde
+//!
+//! To enable [serde](https://crates.io/crates/serde) `Serialize` and `Deserialize`
+//! implementations for [`SlaveAddr`], [`Device`] and [`DeviceInfo`], e.g. to describe the
+//! populated device in a board configuration file, add the feature "`serde`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["serde"] }
+//! ```
+//!
+//! ### fault-injection
+//!
+//! To test how an application copes with failing accesses, e.g. its retry logic, through
+//! `FaultyEeprom`, add the feature "`fault-injection`". This is intended for tests only.
+//!
+//! ```toml
+//! [dev-dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["fault-injection"] }
+//! ```
+//!
+//! ### kv-store
+//!
+//! To keep small values like device settings in the append-based key-value store
+//! `KvStore`, add the feature "`kv-store`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["kv-store"] }
+//! ```
+//!
+//! ### config
+//!
+//! To keep a versioned configuration in a `ConfigBlock` or two copies of a configuration
+//! in a `DualConfig`, add the feature "`config`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["config"] }
+//! ```
+//!
+//! ### compression
+//!
+//! To compress the configurations of a `DualConfig` with a `Compressor`, add the
+//! feature "`compression`". This enables the feature "`config`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["compression"] }
+//! ```
+//!
+//! ### journal
+//!
+//! To apply updates spanning several pages atomically through a `Journal`, add the
+//! feature "`journal`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["journal"] }
+//! ```
+//!
+//! ### ecc
+//!
+//! To protect data with an error-correcting code through an `EccStorage`, add the
+//! feature "`ecc`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["ecc"] }
+//! ```
+//!
+//! ### mirror
+//!
+//! To keep two copies of the data in a `Mirror`, add the feature "`mirror`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["mirror"] }
+//! ```
+//!
+//! ### encryption
+//!
+//! To encrypt the data at rest through an `EncryptedStorage`, add the feature
+//! "`encryption`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["encryption"] }
+//! ```
+//!
+//! ### auth
+//!
+//! To authenticate data with a MAC in an `AuthenticatedBlock`, add the feature "`auth`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["auth"] }
+//! ```
+//!
+//! ### slot-fs
+//!
+//! To share a device between firmware components through the named slots of a
+//! `SlotFs`, add the feature "`slot-fs`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["slot-fs"] }
+//! ```
+//!
+//! ### block-alloc
+//!
+//! To allocate fixed-size blocks dynamically through a `BlockAllocator`, add the
+//! feature "`block-alloc`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["block-alloc"] }
+//! ```
+//!
+//! ### seed
+//!
+//! To persist an RNG seed in a `SeedStore`, add the feature "`seed`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["seed"] }
+//! ```
+//!
+//! ### boot-counter
+//!
+//! To count boots with a `BootCounter`, add the feature "`boot-counter`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["boot-counter"] }
+//! ```
+//!
+//! ### snapshot
+//!
+//! To back up and restore the whole device with `snapshot()` and
+//! `restore_snapshot()`, add the feature "`snapshot`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["snapshot"] }
+//! ```
+//!
+//! ### ring-log
+//!
+//! To record events in the circular log `RingLog`, add the feature "`ring-log`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["ring-log"] }
+//! ```
+//!
+//! ### event-log
+//!
+//! To keep an audit trail in the append-only log `EventLog`, add the feature
+//! "`event-log`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["event-log"] }
+//! ```
+//!
+//! ### time-series
+//!
+//! To store timestamped samples in a `TimeSeries`, add the feature "`time-series`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["time-series"] }
+//! ```
+//!
+//! ### zerocopy
+//!
+//! To read and write plain-old-data structs in their in-memory representation through
+//! `Storage::read_obj()` and `Storage::write_obj()`, add the feature "`zerocopy`".
+//! The layout is checked at compile time by the [zerocopy](https://crates.io/crates/zerocopy)
+//! `FromBytes` and `AsBytes` derives.
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["zerocopy"] }
+//! ```
+//!
+//! ### embedded-io
+//!
+//! To access a storage through the `embedded-io` traits with an `EepromCursor`, add
+//! the feature "`embedded-io`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["embedded-io"] }
+//! ```
+//!
+//! ### std
+//!
+//! To use an `EepromCursor` like a file through the `std::io` `Read`, `Write` and
+//! `Seek` traits, e.g. to copy the memory to a disk image with `std::io::copy()`, add the
+//! feature "`std`". This enables the feature "`embedded-io`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["std"] }
+//! ```
+//!
+//! ### cli
+//!
+//! To build the `eeprom24x-cli` tool for Linux, which dumps, flashes, verifies and erases
+//! the memory and reads the unique serial number of a device without writing a Rust
+//! program, enable the feature "`cli`". This enables the feature "`std`".
+//!
+//! ```sh
+//! cargo install eeprom24x --features cli
+//! eeprom24x-cli --device 24x256 --bus /dev/i2c-1 --address 0x50 dump backup.bin
+//! ```
+//!
+//! ### heapless
+//!
+//! To read data of a length only known at runtime into a `heapless::Vec` with
+//! `read_heapless()`, add the feature "`heapless`".
+//!
+//! ```toml
+//! [dependencies]
+//! eeprom24x = { version = "0.7.2", features = ["heapless"] }
+//! ```
+//!
 //! ## Usage examples (see also examples folder)
 //!
 //! To create a new instance you can use the `new_<device>` methods.
@@ -123,6 +462,40 @@
 //! # }
 //! ```
 //!
+//! ### Naming the device type
+//!
+//! Each supported device has a type alias which can be used e.g. in struct fields.
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use eeprom24x::{ Eeprom24x, Eeprom24x256, SlaveAddr };
+//!
+//! struct Board {
+//!     eeprom: Eeprom24x256<I2cdev>,
+//! }
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let board = Board { eeprom: Eeprom24x::new_24x256(dev, SlaveAddr::default()) };
+//! ```
+//!
+//! ### Using several devices on the same bus
+//!
+//! The drivers accept any `embedded-hal` I²C implementation, including `&mut I2C`
+//! for a temporarily borrowed bus. To keep several devices on the same bus at the same time,
+//! a [`SharedBus`] can be used:
+//!
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use eeprom24x::{ Eeprom24x, SharedBus, SlaveAddr };
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let bus = SharedBus::new(dev);
+//! let mut eeprom1 = Eeprom24x::new_24x256(bus.acquire(), SlaveAddr::from_pins(0));
+//! let mut eeprom2 = Eeprom24x::new_24x256(bus.acquire(), SlaveAddr::from_pins(1));
+//! let data = eeprom1.read_byte(0x1234).unwrap();
+//! eeprom2.write_byte(0x1234, data).unwrap();
+//! ```
+//!
 //! ### Writing and reading a byte
 //!
 //! ```no_run
@@ -170,8 +543,23 @@
 //! ```
 
 #![deny(missing_docs, unsafe_code)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::panic,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::unreachable
+    )
+)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::marker::PhantomData;
 
 /// All possible errors in this crate
@@ -180,10 +568,58 @@ use core::marker::PhantomData;
 pub enum Error<E> {
     /// I²C bus error
     I2C(E),
-    /// Too much data passed for a write
-    TooMuchData,
+    /// The device did not acknowledge its address after a write
+    ///
+    /// The device is most likely still in the internally-timed write cycle and the access
+    /// can be retried later.
+    Busy(E),
+    /// The device did not acknowledge its address
+    ///
+    /// The device is most likely absent, unpowered or configured with a different address.
+    NoDevice(E),
+    /// Write would cross a page boundary
+    ///
+    /// The data can be split into several page writes.
+    PageOverflow {
+        /// Number of bytes passed
+        requested: usize,
+        /// Number of bytes until the end of the page
+        allowed: usize,
+    },
+    /// Write would exceed the device capacity
+    CapacityExceeded {
+        /// Number of bytes passed
+        requested: usize,
+        /// Number of bytes until the end of the memory
+        allowed: usize,
+    },
     /// Memory address is out of range
-    InvalidAddr,
+    InvalidAddr {
+        /// Offending memory address
+        address: u32,
+        /// Size of the addressable memory in bytes
+        capacity: usize,
+    },
+    /// Device geometry is not valid
+    InvalidGeometry,
+    /// Slave address is not valid for the device
+    ///
+    /// e.g. a custom slave address setting bits used for memory addressing.
+    InvalidSlaveAddr,
+    /// The configured device geometry does not match the hardware
+    GeometryMismatch(GeometryMismatch),
+}
+
+/// Mismatch between the configured device geometry and the hardware
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryMismatch {
+    /// Written data could not be read back from the same memory address.
+    ///
+    /// This happens e.g. if the memory address size does not match.
+    AddressSize,
+    /// The memory is aliased within the configured capacity, i.e. the device is smaller.
+    Capacity,
 }
 
 /// Possible slave addresses
@@ -191,6 +627,7 @@ pub enum Error<E> {
 /// Note that in some devices some of the address bits are used for memory addressing and
 /// will therefore be ignored.
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SlaveAddr {
     /// Default slave address
@@ -204,6 +641,12 @@ pub enum SlaveAddr {
     /// e.g. For the 24xM01 devices use the A0 bit of the device address as the highest memory
     /// address bit. The value provided here will be ignored.
     Alternative(bool, bool, bool),
+    /// Custom 7-bit slave address
+    ///
+    /// This allows using devices strapped outside of the `0b101_0xxx` address range.
+    /// If the device uses some of the device address bits for memory addressing, these
+    /// bits must be zero, otherwise `Error::InvalidSlaveAddr` will be returned.
+    Custom(u8),
 }
 
 /// Memory address size markers
@@ -218,41 +661,48 @@ pub mod addr_size {
     #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
     #[derive(Debug)]
     pub struct TwoBytes(());
+    /// 2-byte memory address with the block select bit in place of the A2 bit of the
+    /// device address. e.g. for 24xx1025
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct TwoBytesBlockSelect(());
 }
 
 /// Page size markers
+///
+/// Devices supporting page writes use the [`Bytes`](page_size::Bytes) marker, which carries
+/// the page size as a const generic parameter. The named markers are aliases for the page
+/// sizes of the supported devices.
 pub mod page_size {
     /// No page write supported. e.g. for AT24x00
     #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
     #[derive(Debug)]
     pub struct No(());
-    /// 8-byte pages. e.g. for AT24x01, AT24x02
+    /// Pages of `N` bytes.
     #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
     #[derive(Debug)]
-    pub struct B8(());
+    pub struct Bytes<const N: usize>(());
+    /// 4-byte pages. e.g. for legacy 24C01A, 24C02A
+    pub type B4 = Bytes<4>;
+    /// 8-byte pages. e.g. for AT24x01, AT24x02
+    pub type B8 = Bytes<8>;
     /// 16-byte pages. e.g. for AT24x04, AT24x08, AT24x16
-    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-    #[derive(Debug)]
-    pub struct B16(());
+    pub type B16 = Bytes<16>;
     /// 32-byte pages. e.g. for AT24x32, AT24x64
-    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-    #[derive(Debug)]
-    pub struct B32(());
+    pub type B32 = Bytes<32>;
     /// 64-byte pages. e.g. for AT24x128, AT24x256
-    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-    #[derive(Debug)]
-    pub struct B64(());
+    pub type B64 = Bytes<64>;
     /// 128-byte pages. e.g. for AT24x512
-    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-    #[derive(Debug)]
-    pub struct B128(());
+    pub type B128 = Bytes<128>;
     /// 256-byte pages. e.g. for AT24xM01, AT24xM02
+    pub type B256 = Bytes<256>;
+    /// No page size limit. e.g. for FRAMs like MB85RC256
     #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
     #[derive(Debug)]
-    pub struct B256(());
+    pub struct Unlimited(());
 }
 
-/// Factory-supplied unique serial number markers
+/// Factory-supplied unique serial number and security feature markers
 pub mod unique_serial {
     /// Contains a factory-supplied unique serial number. e.g. for AT24CSx
     #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
@@ -262,6 +712,51 @@ pub mod unique_serial {
     #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
     #[derive(Debug)]
     pub struct No(());
+    /// Contains a factory-supplied EUI-48 and unique serial number in the extended
+    /// memory block. e.g. for AT24MAC402
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct Mac402(());
+    /// Contains a factory-supplied EUI-64 and unique serial number in the extended
+    /// memory block. e.g. for AT24MAC602
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct Mac602(());
+    /// Contains a factory-supplied EUI-48 in the write-protected block of the main memory.
+    /// e.g. for 24AA02E48
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct E48(());
+    /// Contains a factory-supplied EUI-64 in the write-protected block of the main memory.
+    /// e.g. for 24AA02E64
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct E64(());
+    /// Contains a factory-supplied unique serial number and a lockable user-programmable
+    /// security register. e.g. for AT24CSW0x0
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct Csw(());
+    /// No factory-supplied unique serial number but a configuration register for
+    /// software write protection. e.g. for 24CW160
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct Cw(());
+    /// No factory-supplied unique serial number but SPD software write protection.
+    /// e.g. for 34AA02
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct Spd(());
+    /// No factory-supplied unique serial number but a control register for
+    /// STORE/RECALL operations. e.g. for 47x16 EERAM
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct Eeram(());
+    /// No factory-supplied unique serial number but a system area with sector security
+    /// and configuration registers. e.g. for M24LR64E-R
+    #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+    #[derive(Debug)]
+    pub struct M24lr(());
 }
 
 /// EEPROM24X driver
@@ -274,6 +769,12 @@ pub struct Eeprom24x<I2C, PS, AS, SN> {
     address: SlaveAddr,
     /// Number or bits used for memory addressing.
     address_bits: u8,
+    /// Custom I²C device address of the secure region, if any.
+    secure_region_address: Option<u8>,
+    /// Whether the device may still be in the write cycle of the last write.
+    write_pending: bool,
+    /// Maximum number of bytes read in a single transfer.
+    max_transfer_len: usize,
     /// Page size marker type.
     _ps: PhantomData<PS>,
     /// Address size marker type.
@@ -282,6 +783,349 @@ pub struct Eeprom24x<I2C, PS, AS, SN> {
     _sn: PhantomData<SN>,
 }
 
+/// 24x00 device driver type (e.g. 24C00)
+pub type Eeprom24x00<I2C> = Eeprom24x<I2C, page_size::No, addr_size::OneByte, unique_serial::No>;
+/// 24x01A device driver type (e.g. 24C01A)
+pub type Eeprom24x01a<I2C> = Eeprom24x<I2C, page_size::B4, addr_size::OneByte, unique_serial::No>;
+/// 24x02A device driver type (e.g. 24C02A)
+pub type Eeprom24x02a<I2C> = Eeprom24x<I2C, page_size::B4, addr_size::OneByte, unique_serial::No>;
+/// 24x01 device driver type (e.g. AT24C01)
+pub type Eeprom24x01<I2C> = Eeprom24x<I2C, page_size::B8, addr_size::OneByte, unique_serial::No>;
+/// 24x02 device driver type (e.g. AT24C02)
+pub type Eeprom24x02<I2C> = Eeprom24x<I2C, page_size::B8, addr_size::OneByte, unique_serial::No>;
+/// 24CSx01 device driver type (e.g. AT24CS01)
+pub type Eeprom24csx01<I2C> = Eeprom24x<I2C, page_size::B8, addr_size::OneByte, unique_serial::Yes>;
+/// 24CSx02 device driver type (e.g. AT24CS02)
+pub type Eeprom24csx02<I2C> = Eeprom24x<I2C, page_size::B8, addr_size::OneByte, unique_serial::Yes>;
+/// 24x02E48 device driver type (e.g. 24AA02E48)
+pub type Eeprom24x02e48<I2C> =
+    Eeprom24x<I2C, page_size::B8, addr_size::OneByte, unique_serial::E48>;
+/// 24x02E64 device driver type (e.g. 24AA02E64)
+pub type Eeprom24x02e64<I2C> =
+    Eeprom24x<I2C, page_size::B8, addr_size::OneByte, unique_serial::E64>;
+/// 24MAC402 device driver type (e.g. AT24MAC402)
+pub type Eeprom24mac402<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Mac402>;
+/// 24MAC602 device driver type (e.g. AT24MAC602)
+pub type Eeprom24mac602<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Mac602>;
+/// 24CSWx01 device driver type (e.g. AT24CSW010)
+pub type Eeprom24cswx01<I2C> =
+    Eeprom24x<I2C, page_size::B8, addr_size::OneByte, unique_serial::Csw>;
+/// 24CSWx02 device driver type (e.g. AT24CSW020)
+pub type Eeprom24cswx02<I2C> =
+    Eeprom24x<I2C, page_size::B8, addr_size::OneByte, unique_serial::Csw>;
+/// 24x04 device driver type (e.g. AT24C04)
+pub type Eeprom24x04<I2C> = Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::No>;
+/// 24x08 device driver type (e.g. AT24C08)
+pub type Eeprom24x08<I2C> = Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::No>;
+/// 24x16 device driver type (e.g. AT24C16)
+pub type Eeprom24x16<I2C> = Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::No>;
+/// 24CSx04 device driver type (e.g. AT24CS04)
+pub type Eeprom24csx04<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Yes>;
+/// 24CSx08 device driver type (e.g. AT24CS08)
+pub type Eeprom24csx08<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Yes>;
+/// 24CSx16 device driver type (e.g. AT24CS16)
+pub type Eeprom24csx16<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Yes>;
+/// 24CSWx04 device driver type (e.g. AT24CSW040)
+pub type Eeprom24cswx04<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Csw>;
+/// 24CSWx08 device driver type (e.g. AT24CSW080)
+pub type Eeprom24cswx08<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Csw>;
+/// 24CSWx16 device driver type (e.g. AT24CSW160)
+pub type Eeprom24cswx16<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Csw>;
+/// 24x025E48 device driver type (e.g. 24AA025E48)
+pub type Eeprom24x025e48<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::E48>;
+/// 24x025E64 device driver type (e.g. 24AA025E64)
+pub type Eeprom24x025e64<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::E64>;
+/// M24x01 device driver type (e.g. M24C01)
+pub type EepromM24x01<I2C> = Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::No>;
+/// M24x02 device driver type (e.g. M24C02)
+pub type EepromM24x02<I2C> = Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::No>;
+/// CAT24x01 device driver type (e.g. CAT24C01)
+pub type EepromCat24x01<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::No>;
+/// CAT24x02 device driver type (e.g. CAT24C02)
+pub type EepromCat24x02<I2C> =
+    Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::No>;
+/// 34x02 SPD device driver type (e.g. 34AA02)
+pub type Eeprom34x02<I2C> = Eeprom24x<I2C, page_size::B16, addr_size::OneByte, unique_serial::Spd>;
+/// 24x32 device driver type (e.g. AT24C32)
+pub type Eeprom24x32<I2C> = Eeprom24x<I2C, page_size::B32, addr_size::TwoBytes, unique_serial::No>;
+/// 24x64 device driver type (e.g. AT24C64)
+pub type Eeprom24x64<I2C> = Eeprom24x<I2C, page_size::B32, addr_size::TwoBytes, unique_serial::No>;
+/// 24CWx16 device driver type (e.g. 24CW160)
+pub type Eeprom24cwx16<I2C> =
+    Eeprom24x<I2C, page_size::B32, addr_size::TwoBytes, unique_serial::Cw>;
+/// 24CWx32 device driver type (e.g. 24CW320)
+pub type Eeprom24cwx32<I2C> =
+    Eeprom24x<I2C, page_size::B32, addr_size::TwoBytes, unique_serial::Cw>;
+/// 24CWx64 device driver type (e.g. 24CW640)
+pub type Eeprom24cwx64<I2C> =
+    Eeprom24x<I2C, page_size::B32, addr_size::TwoBytes, unique_serial::Cw>;
+/// 24CWx128 device driver type (e.g. 24CW1280)
+pub type Eeprom24cwx128<I2C> =
+    Eeprom24x<I2C, page_size::B32, addr_size::TwoBytes, unique_serial::Cw>;
+/// 24CSx32 device driver type (e.g. AT24CS32)
+pub type Eeprom24csx32<I2C> =
+    Eeprom24x<I2C, page_size::B32, addr_size::TwoBytes, unique_serial::Yes>;
+/// 24CSx64 device driver type (e.g. AT24CS64)
+pub type Eeprom24csx64<I2C> =
+    Eeprom24x<I2C, page_size::B32, addr_size::TwoBytes, unique_serial::Yes>;
+/// 24x128 device driver type (e.g. AT24C128)
+pub type Eeprom24x128<I2C> = Eeprom24x<I2C, page_size::B64, addr_size::TwoBytes, unique_serial::No>;
+/// 24x256 device driver type (e.g. AT24C256)
+pub type Eeprom24x256<I2C> = Eeprom24x<I2C, page_size::B64, addr_size::TwoBytes, unique_serial::No>;
+/// 24x512 device driver type (e.g. AT24C512)
+pub type Eeprom24x512<I2C> =
+    Eeprom24x<I2C, page_size::B128, addr_size::TwoBytes, unique_serial::No>;
+/// 24x1025 device driver type (e.g. 24LC1025)
+pub type Eeprom24x1025<I2C> =
+    Eeprom24x<I2C, page_size::B128, addr_size::TwoBytesBlockSelect, unique_serial::No>;
+/// 24xM01 device driver type (e.g. AT24CM01)
+pub type Eeprom24xm01<I2C> =
+    Eeprom24x<I2C, page_size::B256, addr_size::TwoBytes, unique_serial::No>;
+/// 24xM02 device driver type (e.g. AT24CM02)
+pub type Eeprom24xm02<I2C> =
+    Eeprom24x<I2C, page_size::B256, addr_size::TwoBytes, unique_serial::No>;
+/// MB85RC64 FRAM device driver type
+pub type FramMb85rc64<I2C> =
+    Eeprom24x<I2C, page_size::Unlimited, addr_size::TwoBytes, unique_serial::No>;
+/// MB85RC128 FRAM device driver type
+pub type FramMb85rc128<I2C> =
+    Eeprom24x<I2C, page_size::Unlimited, addr_size::TwoBytes, unique_serial::No>;
+/// MB85RC256 FRAM device driver type
+pub type FramMb85rc256<I2C> =
+    Eeprom24x<I2C, page_size::Unlimited, addr_size::TwoBytes, unique_serial::No>;
+/// MB85RC512 FRAM device driver type
+pub type FramMb85rc512<I2C> =
+    Eeprom24x<I2C, page_size::Unlimited, addr_size::TwoBytes, unique_serial::No>;
+/// FM24CL64 FRAM device driver type
+pub type FramFm24cl64<I2C> =
+    Eeprom24x<I2C, page_size::Unlimited, addr_size::TwoBytes, unique_serial::No>;
+/// FM24W256 FRAM device driver type
+pub type FramFm24w256<I2C> =
+    Eeprom24x<I2C, page_size::Unlimited, addr_size::TwoBytes, unique_serial::No>;
+/// M24LR04E dual-interface device driver type (e.g. M24LR04E-R)
+pub type EepromM24lr04e<I2C> =
+    Eeprom24x<I2C, page_size::B4, addr_size::TwoBytes, unique_serial::M24lr>;
+/// M24LR16E dual-interface device driver type (e.g. M24LR16E-R)
+pub type EepromM24lr16e<I2C> =
+    Eeprom24x<I2C, page_size::B4, addr_size::TwoBytes, unique_serial::M24lr>;
+/// M24LR64E dual-interface device driver type (e.g. M24LR64E-R)
+pub type EepromM24lr64e<I2C> =
+    Eeprom24x<I2C, page_size::B4, addr_size::TwoBytes, unique_serial::M24lr>;
+/// 47x16 EERAM device driver type (e.g. 47L16)
+pub type Eeram47x16<I2C> =
+    Eeprom24x<I2C, page_size::Unlimited, addr_size::TwoBytes, unique_serial::Eeram>;
+
+/// EEPROM24X driver where the device geometry is only known at runtime
+///
+/// In contrast to [`Eeprom24x`], the page size, memory address size and capacity are
+/// runtime fields. This is useful for products populating different EEPROM footprints
+/// across hardware revisions where the device is detected at boot.
+///
+/// Memory address bits beyond the memory address size are transmitted in the lowest bits
+/// of the device address. Devices selecting the upper 64 KiB with the A2 bit instead
+/// (24xx1025) are not supported. Use [`Eeprom24x::new_24x1025()`] for them.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct Eeprom24xDyn<I2C> {
+    /// The concrete I²C device implementation.
+    i2c: I2C,
+    /// The I²C device address.
+    address: SlaveAddr,
+    /// Number or bits used for memory addressing.
+    address_bits: u8,
+    /// Number of bytes used to transmit the memory address.
+    address_bytes: u8,
+    /// Page size in bytes. Devices without page write support have a page size of 1.
+    page_size: usize,
+    /// Whether the device may still be in the write cycle of the last write.
+    write_pending: bool,
+    /// Maximum number of bytes read in a single transfer.
+    max_transfer_len: usize,
+}
+
+/// Confirmation token for irreversible operations
+///
+/// Operations which permanently change the device, like locking the security register,
+/// require this token so that they cannot be triggered by accident, e.g. through code
+/// completion. Create it with [`Irreversible::confirm()`] right where the operation is done.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct Irreversible(());
+
+impl Irreversible {
+    /// Confirm that the following operation cannot be undone.
+    pub fn confirm() -> Self {
+        Irreversible(())
+    }
+}
+
+/// EE1004 DDR4 SPD EEPROM driver (e.g. 34x04)
+///
+/// These devices contain 512 bytes organized in two 256-byte pages, of which only one is
+/// accessible at a time. The page is selected with the broadcast SPA0/SPA1 commands, which
+/// this driver sends before each access so that the whole memory can be accessed
+/// transparently, e.g. `read_data(0, &mut [0; 512])`. Accesses crossing the page boundary
+/// are split. The page size for page writes is 16 bytes.
+///
+/// Note that the page selection affects all EE1004 devices on the bus.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct Ee1004<I2C> {
+    /// The concrete I²C device implementation.
+    i2c: I2C,
+    /// The I²C device address.
+    address: SlaveAddr,
+    /// Whether the device may still be in the write cycle of the last write.
+    write_pending: bool,
+}
+
+/// Devices which can be selected at runtime
+///
+/// This allows selecting the device at runtime, e.g. from a configuration file or a
+/// build-time setting. See [`Eeprom24xDyn::new_from_device`].
+///
+/// This covers the EEPROMs which only differ in their geometry. Devices needing dedicated
+/// access logic, i.e. FRAMs (MB85RC and FM24 series), 24xx1025, 47x16 EERAM, M24LR and
+/// EE1004 devices, are only supported through their own constructors.
+///
+/// Devices can also be parsed from their part name (e.g. `"AT24C256"`) or from their
+/// generic name (e.g. `"24x256"`), ignoring case.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Device {
+    /// 24x00 device (e.g. 24C00)
+    MC24C00,
+    /// 24x01A device (e.g. 24C01A)
+    MC24C01A,
+    /// 24x02A device (e.g. 24C02A)
+    MC24C02A,
+    /// 24x01 device (e.g. AT24C01)
+    AT24C01,
+    /// 24x02 device (e.g. AT24C02)
+    AT24C02,
+    /// 24CSx01 device (e.g. AT24CS01)
+    AT24CS01,
+    /// 24CSx02 device (e.g. AT24CS02)
+    AT24CS02,
+    /// 24CSWx01 device (e.g. AT24CSW010)
+    AT24CSW010,
+    /// 24CSWx02 device (e.g. AT24CSW020)
+    AT24CSW020,
+    /// 24x02E48 device (e.g. 24AA02E48)
+    MC24AA02E48,
+    /// 24x02E64 device (e.g. 24AA02E64)
+    MC24AA02E64,
+    /// 24MAC402 device (e.g. AT24MAC402)
+    AT24MAC402,
+    /// 24MAC602 device (e.g. AT24MAC602)
+    AT24MAC602,
+    /// 24x04 device (e.g. AT24C04)
+    AT24C04,
+    /// 24x08 device (e.g. AT24C08)
+    AT24C08,
+    /// 24x16 device (e.g. AT24C16)
+    AT24C16,
+    /// 24CSx04 device (e.g. AT24CS04)
+    AT24CS04,
+    /// 24CSx08 device (e.g. AT24CS08)
+    AT24CS08,
+    /// 24CSx16 device (e.g. AT24CS16)
+    AT24CS16,
+    /// 24CSWx04 device (e.g. AT24CSW040)
+    AT24CSW040,
+    /// 24CSWx08 device (e.g. AT24CSW080)
+    AT24CSW080,
+    /// 24CSWx16 device (e.g. AT24CSW160)
+    AT24CSW160,
+    /// 24x025E48 device (e.g. 24AA025E48)
+    MC24AA025E48,
+    /// 24x025E64 device (e.g. 24AA025E64)
+    MC24AA025E64,
+    /// M24x01 device (e.g. M24C01)
+    M24C01,
+    /// M24x02 device (e.g. M24C02)
+    M24C02,
+    /// CAT24x01 device (e.g. CAT24C01)
+    CAT24C01,
+    /// CAT24x02 device (e.g. CAT24C02)
+    CAT24C02,
+    /// 34x02 SPD device (e.g. 34AA02)
+    MC34AA02,
+    /// 24x32 device (e.g. AT24C32)
+    AT24C32,
+    /// 24x64 device (e.g. AT24C64)
+    AT24C64,
+    /// 24CSx32 device (e.g. AT24CS32)
+    AT24CS32,
+    /// 24CSx64 device (e.g. AT24CS64)
+    AT24CS64,
+    /// 24CWx16 device (e.g. 24CW160)
+    MC24CW160,
+    /// 24CWx32 device (e.g. 24CW320)
+    MC24CW320,
+    /// 24CWx64 device (e.g. 24CW640)
+    MC24CW640,
+    /// 24CWx128 device (e.g. 24CW1280)
+    MC24CW1280,
+    /// 24x128 device (e.g. AT24C128)
+    AT24C128,
+    /// 24x256 device (e.g. AT24C256)
+    AT24C256,
+    /// 24x512 device (e.g. AT24C512)
+    AT24C512,
+    /// 24xM01 device (e.g. AT24CM01)
+    AT24CM01,
+    /// 24xM02 device (e.g. AT24CM02)
+    AT24CM02,
+}
+
+/// Device geometry and features
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// Number of bits used for memory addressing.
+    pub address_bits: u8,
+    /// Number of bytes used to transmit the memory address.
+    pub address_bytes: u8,
+    /// Page size in bytes. Devices without page write support have a page size of 1.
+    pub page_size: usize,
+    /// Whether the device contains a factory-supplied unique serial number.
+    pub unique_serial: bool,
+}
+
+/// I²C-bus Device ID as defined in the I²C-bus specification
+///
+/// This can be read with `read_device_id()` from devices supporting it.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceId {
+    /// 12-bit manufacturer identifier assigned by NXP.
+    pub manufacturer: u16,
+    /// 9-bit part identifier assigned by the manufacturer.
+    pub part: u16,
+    /// 3-bit die revision.
+    pub revision: u8,
+}
+
+/// Error returned when parsing an unknown device name
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDeviceError(());
+
 /// `Eeprom24x` type trait for use in generic code
 pub trait Eeprom24xTrait: private::Sealed {
     /// Inner implementation error.
@@ -310,8 +1154,8 @@ pub trait Eeprom24xTrait: private::Sealed {
     /// Write up to a page starting in an address.
     ///
     /// The maximum amount of data that can be written depends on the page
-    /// size of the device and its overall capacity. If too much data is passed,
-    /// the error `Error::TooMuchData` will be returned.
+    /// size of the device. If the data would cross a page boundary, the error
+    /// `Error::PageOverflow` will be returned.
     ///
     /// After writing a byte, the EEPROM enters an internally-timed write cycle
     /// to the nonvolatile memory.
@@ -321,10 +1165,1531 @@ pub trait Eeprom24xTrait: private::Sealed {
 
     /// Return device page size
     fn page_size(&self) -> usize;
+
+    /// Return device capacity in bytes
+    fn capacity(&self) -> usize;
+
+    /// Return the write cycle time in milliseconds
+    ///
+    /// This is the time to wait after a write before the device can be accessed again.
+    /// It is zero for devices without write cycle (e.g. FRAMs).
+    fn write_cycle_ms(&self) -> u32 {
+        5
+    }
+
+    /// Box the device as an `Eeprom24xTrait` trait object.
+    ///
+    /// This allows storing heterogeneous devices e.g. in a collection.
+    #[cfg(feature = "alloc")]
+    fn into_boxed<'a>(self) -> BoxedEeprom24x<'a, Self::Error>
+    where
+        Self: Sized + 'a,
+    {
+        alloc::boxed::Box::new(self)
+    }
+}
+
+/// Boxed `Eeprom24xTrait` trait object
+#[cfg(feature = "alloc")]
+pub type BoxedEeprom24x<'a, E> = alloc::boxed::Box<dyn Eeprom24xTrait<Error = E> + 'a>;
+
+/// Trait for devices providing a factory-programmed EUI-48 (MAC-48) identifier
+///
+/// This allows network stacks to obtain a MAC address from any device providing one
+/// without depending on the concrete device type.
+pub trait Eui48 {
+    /// Inner implementation error.
+    type Error;
+
+    /// Read the 48-bit extended unique identifier.
+    fn read_eui48(&mut self) -> Result<[u8; 6], Error<Self::Error>>;
+}
+
+/// Trait for devices providing a factory-programmed EUI-64 identifier
+///
+/// This allows network stacks (e.g. IEEE 802.15.4) to obtain an EUI-64 from any device
+/// providing one without depending on the concrete device type.
+pub trait Eui64 {
+    /// Inner implementation error.
+    type Error;
+
+    /// Read the 64-bit extended unique identifier.
+    fn read_eui64(&mut self) -> Result<[u8; 8], Error<Self::Error>>;
+}
+
+/// Trait for values with a fixed-size byte representation
+///
+/// This is implemented for the primitive integer and floating-point types (in
+/// little-endian byte order) and for `bool`. Implement it for your own types to access
+/// them through an [`EepromCell`].
+///
+/// ```
+/// use eeprom24x::Storable;
+///
+/// struct Calibration {
+///     offset: i16,
+///     gain: u16,
+/// }
+///
+/// impl Storable for Calibration {
+///     type Bytes = [u8; 4];
+///
+///     fn to_bytes(&self) -> Self::Bytes {
+///         let [o0, o1] = self.offset.to_le_bytes();
+///         let [g0, g1] = self.gain.to_le_bytes();
+///         [o0, o1, g0, g1]
+///     }
+///
+///     fn from_bytes(bytes: Self::Bytes) -> Self {
+///         let [o0, o1, g0, g1] = bytes;
+///         Calibration {
+///             offset: i16::from_le_bytes([o0, o1]),
+///             gain: u16::from_le_bytes([g0, g1]),
+///         }
+///     }
+/// }
+/// ```
+pub trait Storable: Sized {
+    /// Byte representation, usually a byte array.
+    type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default;
+
+    /// Return the byte representation of the value.
+    fn to_bytes(&self) -> Self::Bytes;
+
+    /// Create a value from its byte representation.
+    fn from_bytes(bytes: Self::Bytes) -> Self;
+}
+
+/// I²C bus which can be shared among several drivers in the same execution context
+///
+/// This allows, for example, using several EEPROMs on the same bus without resorting to
+/// external bus sharing crates. Each driver is created with its own handle obtained through
+/// [`SharedBus::acquire()`].
+///
+/// Note that a `SharedBus` cannot be shared between different execution contexts, e.g.
+/// between the main thread and an interrupt handler.
+#[derive(Debug)]
+pub struct SharedBus<I2C> {
+    bus: core::cell::RefCell<I2C>,
+}
+
+/// Handle to a [`SharedBus`] implementing the `embedded-hal` I²C traits
+#[derive(Debug)]
+pub struct SharedI2c<'a, I2C> {
+    bus: &'a core::cell::RefCell<I2C>,
+}
+
+/// Device which can be shared between different execution contexts
+///
+/// This allows, for example, accessing a device both from the main context and from
+/// interrupt handlers, e.g. for logging. Each access happens within a critical section
+/// so the accesses should be kept short.
+///
+/// This is only available when the feature "`critical-section`" is enabled.
+///
+/// ```no_run
+/// use linux_embedded_hal::I2cdev;
+/// use eeprom24x::{ Eeprom24x, SharedEeprom, SlaveAddr };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = SharedEeprom::new(Eeprom24x::new_24x256(dev, SlaveAddr::default()));
+/// // this can be done from an interrupt handler as well
+/// eeprom.lock(|eeprom| eeprom.write_byte(0x1234, 0xAB)).unwrap();
+/// ```
+#[cfg(feature = "critical-section")]
+#[derive(Debug)]
+pub struct SharedEeprom<T> {
+    eeprom: critical_section::Mutex<core::cell::RefCell<T>>,
+}
+
+/// Device wrapper injecting failures into the accesses for robustness testing
+///
+/// This wraps any [`Eeprom24xTrait`] implementation and makes some of the accesses fail
+/// as configured in [`Faults`] so that the recovery paths of the application can be tested.
+/// Every access method call counts as one access.
+///
+/// This is only available when the feature "`fault-injection`" is enabled.
+///
+/// ```no_run
+/// use linux_embedded_hal::I2cdev;
+/// use eeprom24x::{ Eeprom24x, Eeprom24xTrait, Error, Faults, FaultyEeprom, SlaveAddr };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let faults = Faults { nack_every: 3, ..Faults::default() };
+/// let mut eeprom = FaultyEeprom::new(eeprom, faults);
+/// eeprom.read_byte(0x1234).unwrap();
+/// eeprom.read_byte(0x1234).unwrap();
+/// match eeprom.read_byte(0x1234) {
+///     Err(Error::Busy(_)) => (),
+///     _ => panic!("Error::Busy not injected."),
+/// }
+/// ```
+#[cfg(feature = "fault-injection")]
+#[derive(Debug)]
+pub struct FaultyEeprom<T> {
+    eeprom: T,
+    faults: Faults,
+    accesses: u32,
+}
+
+/// Failures injected by [`FaultyEeprom`]
+///
+/// The periods count the accesses through the wrapper. A period of 0 disables the fault.
+/// If several faults are due in the same access, only the first one in the order of the
+/// fields is injected.
+#[cfg(feature = "fault-injection")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Faults {
+    /// Reject every n-th access as if the device did not acknowledge it.
+    ///
+    /// This is reported as `Error::Busy(FaultError::Nack)`.
+    pub nack_every: u32,
+    /// Fail every n-th access as if the bus timed out.
+    ///
+    /// This is reported as `Error::I2C(FaultError::Timeout)`.
+    pub timeout_every: u32,
+    /// Corrupt the data read in every n-th access.
+    pub corrupt_every: u32,
+    /// Bits flipped in each byte of the corrupted data.
+    pub corrupt_mask: u8,
+}
+
+/// Error of a device wrapped in a [`FaultyEeprom`]
+#[cfg(feature = "fault-injection")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultError<E> {
+    /// Injected missing acknowledge
+    Nack,
+    /// Injected bus timeout
+    Timeout,
+    /// Error of the wrapped device
+    Device(E),
+}
+
+/// I²C bus wrapper calling a user-supplied hook before each bus access
+///
+/// This can be used e.g. to select the channel of an I²C multiplexer like the TCA9548A before
+/// each access so that several identical devices with the same address can be used behind it.
+/// The hook receives the wrapped bus and its errors are returned as bus errors.
+///
+/// ```no_run
+/// use linux_embedded_hal::I2cdev;
+/// use eeprom24x::{ Eeprom24x, HookedI2c, SharedBus, SlaveAddr };
+/// use embedded_hal::i2c::I2c;
+///
+/// const MUX_ADDRESS: u8 = 0x70;
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let bus = SharedBus::new(dev);
+/// let i2c0 = HookedI2c::new(bus.acquire(), |i2c: &mut _| i2c.write(MUX_ADDRESS, &[1 << 0]));
+/// let i2c1 = HookedI2c::new(bus.acquire(), |i2c: &mut _| i2c.write(MUX_ADDRESS, &[1 << 1]));
+/// let mut eeprom0 = Eeprom24x::new_24x256(i2c0, SlaveAddr::default());
+/// let mut eeprom1 = Eeprom24x::new_24x256(i2c1, SlaveAddr::default());
+/// let data = eeprom0.read_byte(0x1234).unwrap();
+/// eeprom1.write_byte(0x1234, data).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct HookedI2c<I2C, H> {
+    i2c: I2C,
+    hook: H,
+}
+
+/// Chain of cascaded devices presented as a single address space
+///
+/// This owns up to 8 devices of the same type, usually at consecutive slave addresses, and
+/// makes their memories available back-to-back. It implements [`Eeprom24xTrait`] as well as
+/// the `embedded-storage` traits, splitting accesses at the device boundaries.
+/// The delay is used to wait for the write cycles to finish when writing over page
+/// boundaries through the `embedded-storage` traits.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct EepromChain<T, D, const N: usize> {
+    /// Chained devices
+    devices: [T; N],
+    /// Delay provider
+    delay: D,
+    /// Index of the last accessed device
+    current: usize,
+}
+
+/// Device wrapper caching memory lines in RAM
+///
+/// This keeps up to `N` lines of `P` bytes each of the device memory in RAM and implements
+/// the `embedded-storage` traits on top of them. Reads are served from the cache when
+/// possible and writes only modify the cache and mark the line as dirty. The dirty lines
+/// are written to the device with [`flush()`](CachedEeprom::flush) or when they are evicted
+/// to make room for another line. This reduces the read traffic and coalesces repeated writes
+/// to the same line into a single page write.
+///
+/// The line size `P` must be a power of two not larger than the device page size.
+/// The delay is used to wait for the write cycles to finish.
+///
+/// Note that any data not flushed is lost if the power is lost or the wrapper is destroyed.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ CachedEeprom, Eeprom24x, SlaveAddr };
+/// use embedded_storage::{ReadStorage, Storage};
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut cache: CachedEeprom<_, _, 4, 64> = CachedEeprom::new(eeprom, Delay).unwrap();
+/// cache.write(0x1234, &[0xAB]).unwrap();
+/// cache.write(0x1235, &[0xCD]).unwrap();
+/// let mut data = [0; 2];
+/// cache.read(0x1234, &mut data).unwrap();
+/// cache.flush().unwrap(); // a single page write
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct CachedEeprom<T, D, const N: usize, const P: usize> {
+    /// Cached device
+    eeprom: T,
+    /// Delay provider
+    delay: D,
+    /// Cache lines
+    lines: [cache::CacheLine<P>; N],
+    /// Index of the line to evict next
+    next_victim: usize,
+}
+
+/// Device wrapper accumulating small writes into page writes
+///
+/// This implements the `embedded-storage` traits and keeps the data written to consecutive
+/// addresses within a block of `P` bytes in RAM. The accumulated data is written to the
+/// device in a single page write with [`flush()`](WriteCoalescer::flush) or as soon as
+/// a write does not continue the pending data, e.g. because it crosses into the next block.
+/// This reduces the number of write cycles for code updating many adjacent fields one
+/// by one. Reads return the pending data.
+///
+/// The block size `P` must be a power of two not larger than the device page size.
+/// The delay is used to wait for the write cycles to finish.
+///
+/// Note that any data not flushed is lost if the power is lost or the wrapper is destroyed.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct WriteCoalescer<T, D, const P: usize> {
+    /// Wrapped device
+    eeprom: T,
+    /// Delay provider
+    delay: D,
+    /// Pending data at its offset within the block
+    buffer: [u8; P],
+    /// Memory address and length of the pending data, if any
+    pending: Option<(u32, usize)>,
+}
+
+/// Read-ahead buffer for sequential reads
+///
+/// This wraps any `embedded-storage` implementation (e.g. [`Storage`] or [`EepromChain`])
+/// and reads `N` bytes at once when a short read misses the buffer, so that byte-oriented
+/// consumers like parsers issue one multi-byte transfer instead of one transfer per byte.
+/// Reads of at least `N` bytes bypass the buffer. Writes are passed through and update
+/// the buffered data.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, ReadAhead, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut reader: ReadAhead<_, 64> = ReadAhead::new(Storage::new(eeprom, Delay));
+/// let mut sum = 0u32;
+/// for address in 0..256 {
+///     // four transfers in total
+///     sum += u32::from(reader.read_byte(address).unwrap());
+/// }
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct ReadAhead<S, const N: usize> {
+    /// Wrapped storage
+    storage: S,
+    /// Buffered data
+    buffer: [u8; N],
+    /// Memory address and length of the buffered data, if any
+    window: Option<(u32, usize)>,
+}
+
+/// Buffered reader for sequential reads
+///
+/// This reads the data of any `embedded-storage` implementation (e.g. [`Storage`]) from
+/// a position which advances with each read. Short reads are served from a [`ReadAhead`]
+/// buffer of `N` bytes, so that byte-oriented parsers issue one multi-byte transfer
+/// instead of one transfer per byte.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ BufReader, Eeprom24x, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut reader: BufReader<_, 64> = BufReader::new(Storage::new(eeprom, Delay), 0);
+/// // read a zero-terminated string
+/// while let Some(byte) = reader.read_byte().unwrap() {
+///     if byte == 0 {
+///         break;
+///     }
+///     print!("{}", char::from(byte));
+/// }
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct BufReader<S, const N: usize> {
+    /// Buffered storage
+    reader: ReadAhead<S, N>,
+    /// Offset of the next read
+    position: u32,
+}
+
+/// Buffered writer appending data in whole pages
+///
+/// This wraps any `embedded-storage` implementation (e.g. [`Storage`]) and accumulates
+/// the appended data in a buffer of one page of `P` bytes, which is written as soon as the
+/// page is complete. Many tiny writes thus result in the minimal number of page writes.
+/// The data of an incomplete page is written with [`flush()`](BufWriter::flush).
+///
+/// The page size `P` must be a power of two, ideally the device page size.
+///
+/// Note that any data not flushed is lost if the power is lost or the writer is destroyed.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ BufWriter, Eeprom24x, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut writer: BufWriter<_, 64> = BufWriter::new(Storage::new(eeprom, Delay), 0);
+/// for value in 0..100u16 {
+///     writer.write(&value.to_le_bytes()).unwrap();
+/// }
+/// writer.flush().unwrap(); // four page writes in total
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct BufWriter<S, const P: usize> {
+    /// Wrapped storage
+    storage: S,
+    /// Buffered data at its offset within the page
+    buffer: [u8; P],
+    /// Offset of the buffered data
+    start: u32,
+    /// Number of buffered bytes
+    len: usize,
+}
+
+/// Append-based key-value store
+///
+/// This keeps small values like device settings under fixed-size keys of `K` bytes in any
+/// `embedded-storage` implementation (e.g. [`Storage`]). Each update appends a record
+/// protected by a CRC so that the writes are spread over the memory. The storage is split
+/// into two banks and the current values are copied into the other bank when the active
+/// one is full.
+///
+/// Interrupted updates are discarded the next time the store is mounted.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, KvStore, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut store: KvStore<_, 4> = KvStore::format(Storage::new(eeprom, Delay)).unwrap();
+/// store.set(b"baud", &115_200u32.to_le_bytes()).unwrap();
+/// let mut value = [0; 4];
+/// assert_eq!(Some(4), store.get(b"baud", &mut value).unwrap());
+/// ```
+#[cfg(feature = "kv-store")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct KvStore<S, const K: usize> {
+    /// Wrapped storage
+    storage: S,
+    /// Index of the active bank
+    bank: u32,
+    /// Generation of the active bank
+    generation: u32,
+    /// Offset of the end of the records in the active bank
+    end: u32,
+}
+
+/// Error of a [`KvStore`]
+#[cfg(feature = "kv-store")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KvError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The storage does not contain a store with keys of this length
+    NotFormatted,
+    /// The value is longer than the maximum value length
+    ValueTooLong,
+    /// The buffer cannot hold the value
+    BufferTooSmall {
+        /// Length of the value
+        required: usize,
+    },
+    /// The record does not fit into the storage even after compaction
+    Full,
+}
+
+/// Versioned configuration block
+///
+/// This stores a configuration in any `embedded-storage` implementation (e.g. a
+/// [`Partition`]) behind a header with a magic, a schema version, the data length and a
+/// CRC. When a configuration with an older schema version is loaded, a user-supplied
+/// callback migrates it so that firmware updates keep the stored settings.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ ConfigBlock, ConfigError, Eeprom24x, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut config = ConfigBlock::new(Storage::new(eeprom, Delay), 0x4346_4731, 2);
+/// let mut buffer = [0; 64];
+/// let result = config.load(&mut buffer, |version, data, len| match version {
+///     // version 2 added a 16-bit timeout after the existing fields
+///     1 => {
+///         data.get_mut(len..len + 2)?.copy_from_slice(&500u16.to_le_bytes());
+///         Some(len + 2)
+///     }
+///     _ => None,
+/// });
+/// match result {
+///     Ok(len) => println!("{:?}", &buffer[..len]),
+///     Err(ConfigError::NotFound) => config.save(&[0; 8]).unwrap(),
+///     Err(e) => panic!("{}", e),
+/// }
+/// ```
+#[cfg(feature = "config")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct ConfigBlock<S> {
+    /// Wrapped storage
+    storage: S,
+    /// Magic identifying the configuration
+    magic: u32,
+    /// Current schema version
+    version: u16,
+}
+
+/// Power-loss-safe configuration with two copies
+///
+/// This splits any `embedded-storage` implementation (e.g. a [`Partition`]) into two
+/// slots each holding a copy of a configuration with a sequence number and a CRC. A new
+/// copy is written to the slot not holding the current copy and committed by writing its
+/// header last, so that a power cut while saving always leaves a valid copy to load.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ DualConfig, Eeprom24x, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut config = DualConfig::new(Storage::new(eeprom, Delay));
+/// let mut buffer = [0; 64];
+/// let len = config.load(&mut buffer).unwrap_or(0);
+/// config.save(b"ssid=home").unwrap();
+/// ```
+#[cfg(feature = "config")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct DualConfig<S> {
+    /// Wrapped storage
+    storage: S,
+    /// Slot and sequence number of the current copy, if any
+    active: Option<(u32, u32)>,
+    /// Whether the slots have been checked
+    scanned: bool,
+}
+
+/// Compressor used by [`DualConfig::save_compressed()`] and
+/// [`DualConfig::load_compressed()`]
+///
+/// This is implemented by the user, e.g. with the `heatshrink` crate.
+#[cfg(feature = "compression")]
+pub trait Compressor {
+    /// Compress the input into the output and return the compressed length, or `None` if
+    /// the output is too small.
+    fn compress(&mut self, input: &[u8], output: &mut [u8]) -> Option<usize>;
+
+    /// Decompress the input into the output and return the decompressed length, or `None`
+    /// if the input is invalid or the output is too small.
+    fn decompress(&mut self, input: &[u8], output: &mut [u8]) -> Option<usize>;
+}
+
+/// Error of a [`ConfigBlock`] or a [`DualConfig`]
+#[cfg(feature = "config")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The storage does not contain a configuration with this magic
+    NotFound,
+    /// The configuration does not match its checksum
+    Corrupted,
+    /// The configuration has a newer schema version or it could not be migrated
+    UnsupportedVersion {
+        /// Schema version of the stored configuration
+        version: u16,
+    },
+    /// The buffer cannot hold the configuration
+    BufferTooSmall {
+        /// Length of the configuration
+        required: usize,
+    },
+    /// The configuration does not fit into the storage
+    TooLarge,
+}
+
+/// Journal for updates spanning several pages
+///
+/// This reserves the start of any `embedded-storage` implementation (e.g. a
+/// [`Partition`]) for a journal and exposes the rest as the data area through the
+/// `ReadStorage` trait. The writes of a transaction are first recorded in the journal and
+/// only copied to the data area on commit, so that after a power loss the update is either
+/// fully applied or not applied at all.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, Journal, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut journal = Journal::new(Storage::new(eeprom, Delay), 256).unwrap();
+/// // both tables are updated together
+/// journal.write(0x000, &[1; 64]).unwrap();
+/// journal.write(0x400, &[2; 64]).unwrap();
+/// journal.commit().unwrap();
+/// ```
+#[cfg(feature = "journal")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct Journal<S> {
+    /// Wrapped storage
+    storage: S,
+    /// Size of the journal area
+    journal_len: u32,
+    /// End of the entries of the transaction in the journal
+    end: u32,
+    /// Checksum of the entries of the transaction
+    crc: u16,
+}
+
+/// Error of a [`Journal`]
+#[cfg(feature = "journal")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JournalError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The write exceeds the data area
+    OutOfBounds,
+    /// The journal cannot hold the write
+    JournalFull,
+}
+
+/// Storage protected by an error-correcting code
+///
+/// This stores a check byte after every 8 data bytes of any `embedded-storage`
+/// implementation (e.g. a [`Partition`]), i.e. 8 bytes of overhead per 64-byte page, using
+/// an extended Hamming (72, 64) code. Single-bit errors are corrected transparently on
+/// read and double-bit errors are detected. Each corrected or uncorrectable error is
+/// reported to a callback, e.g. to count them or to rewrite the affected data.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ EccEvent, EccStorage, Eeprom24x, SlaveAddr, Storage };
+/// use embedded_storage::{ReadStorage, Storage as _};
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut corrected = 0;
+/// let mut storage = EccStorage::new(Storage::new(eeprom, Delay), |event| {
+///     if let EccEvent::Corrected { .. } = event {
+///         corrected += 1;
+///     }
+/// });
+/// storage.write(0, &[0xAB; 16]).unwrap();
+/// let mut data = [0; 16];
+/// storage.read(0, &mut data).unwrap();
+/// ```
+#[cfg(feature = "ecc")]
+#[derive(Debug)]
+pub struct EccStorage<S, F> {
+    /// Wrapped storage
+    storage: S,
+    /// Callback reporting corrected and uncorrectable errors
+    report: F,
+}
+
+/// Error detected by an [`EccStorage`]
+#[cfg(feature = "ecc")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EccEvent {
+    /// A single-bit error was corrected
+    Corrected {
+        /// Offset of the affected 8-byte block
+        offset: u32,
+    },
+    /// A double-bit error was detected
+    Uncorrectable {
+        /// Offset of the affected 8-byte block
+        offset: u32,
+    },
+}
+
+/// Error of an [`EccStorage`]
+#[cfg(feature = "ecc")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EccError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The data of a block cannot be corrected
+    Uncorrectable {
+        /// Offset of the affected 8-byte block
+        offset: u32,
+    },
+    /// The access exceeds the capacity
+    OutOfBounds,
+}
+
+/// Storage keeping two copies of the data
+///
+/// This keeps a copy of the data in each half of any `embedded-storage` implementation
+/// (e.g. a [`Partition`]), in blocks of 14 data bytes protected by a CRC. When the copies
+/// of a block disagree on read, the bad copy is repaired from the good one. Call
+/// [`scrub()`](Mirror::scrub) periodically to repair errors in data which is rarely read.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, Mirror, SlaveAddr, Storage };
+/// use embedded_storage::{ReadStorage, Storage as _};
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut mirror = Mirror::new(Storage::new(eeprom, Delay));
+/// mirror.write(0, b"calibration").unwrap();
+/// let repaired = mirror.scrub().unwrap();
+/// ```
+#[cfg(feature = "mirror")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct Mirror<S> {
+    /// Wrapped storage
+    storage: S,
+}
+
+/// Error of a [`Mirror`]
+#[cfg(feature = "mirror")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MirrorError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// Both copies of a block are corrupted
+    Corrupted {
+        /// Offset of the affected block
+        offset: u32,
+    },
+    /// The access exceeds the capacity
+    OutOfBounds,
+}
+
+/// Filesystem of named slots
+///
+/// This keeps a directory of `N` entries at the start of any `embedded-storage`
+/// implementation (e.g. a [`Partition`]), each holding the name, offset, capacity, length
+/// and CRC of a slot, so that several firmware components can share a device without
+/// hard-coding offsets against each other.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, FsError, SlaveAddr, SlotFs, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut fs: SlotFs<_, 16> = SlotFs::mount(Storage::new(eeprom, Delay)).unwrap();
+/// let slot = match fs.open(b"wifi") {
+///     Err(FsError::NotFound) => fs.create(b"wifi", 96).unwrap(),
+///     slot => slot.unwrap(),
+/// };
+/// fs.write(&slot, b"ssid=home").unwrap();
+/// ```
+#[cfg(feature = "slot-fs")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct SlotFs<S, const N: usize> {
+    /// Wrapped storage
+    storage: S,
+}
+
+/// Slot of a [`SlotFs`]
+#[cfg(feature = "slot-fs")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slot {
+    /// Index of the directory entry
+    index: u8,
+    /// Maximum length of the data
+    capacity: u16,
+}
+
+/// Error of a [`SlotFs`]
+#[cfg(feature = "slot-fs")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FsError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The storage does not contain a filesystem with this number of directory entries
+    NotFormatted,
+    /// No slot with this name exists
+    NotFound,
+    /// A slot with this name already exists
+    AlreadyExists,
+    /// The name is empty or longer than 8 bytes
+    InvalidName,
+    /// All the directory entries are used
+    DirectoryFull,
+    /// There is no free space large enough for the slot
+    NoSpace,
+    /// The data is longer than the slot capacity
+    TooLarge,
+    /// The buffer cannot hold the slot data
+    BufferTooSmall {
+        /// Length of the slot data
+        required: usize,
+    },
+    /// The slot data does not match its checksum
+    Corrupted,
+}
+
+/// Allocator of fixed-size blocks
+///
+/// This manages blocks of a fixed size in any `embedded-storage` implementation (e.g. a
+/// [`Partition`]), for layouts with a variable number of records. Which blocks are in use
+/// is persisted in a bitmap at the start of the storage.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ BlockAllocator, Eeprom24x, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut allocator = BlockAllocator::new(Storage::new(eeprom, Delay), 32);
+/// let record = allocator.alloc(2).unwrap();
+/// allocator.write(record, 0, b"first record").unwrap();
+/// let record = allocator.realloc(record, 3).unwrap();
+/// allocator.free(record).unwrap();
+/// ```
+#[cfg(feature = "block-alloc")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct BlockAllocator<S> {
+    /// Wrapped storage
+    storage: S,
+    /// Size of a block in bytes
+    block_size: u32,
+    /// Number of blocks
+    blocks: u32,
+}
+
+/// Contiguous blocks allocated by a [`BlockAllocator`]
+#[cfg(feature = "block-alloc")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocation {
+    /// Index of the first block
+    pub start: u32,
+    /// Number of blocks
+    pub count: u32,
+}
+
+/// Error of a [`BlockAllocator`]
+#[cfg(feature = "block-alloc")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// There is no free run of blocks of this length
+    Full,
+    /// A block of the allocation is free
+    NotAllocated,
+    /// The allocation or access exceeds the blocks
+    OutOfBounds,
+}
+
+/// Persisted RNG seed
+///
+/// This keeps an RNG seed with a counter in any `embedded-storage` implementation (e.g. a
+/// [`Partition`]), so that devices without a hardware RNG can seed their RNG differently
+/// at each boot. Two copies are kept so that a power cut while writing always leaves a
+/// valid copy.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, SeedStore, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut seeds = SeedStore::new(Storage::new(eeprom, Delay));
+/// let seed = seeds.next_seed().unwrap();
+/// // seed the RNG, then store some of its output for the next boot
+/// seeds.rotate(&[0x5A; 32]).unwrap();
+/// ```
+#[cfg(feature = "seed")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct SeedStore<S> {
+    /// Wrapped storage
+    storage: S,
+}
+
+/// Error of a [`SeedStore`]
+#[cfg(feature = "seed")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The storage does not contain a valid seed
+    NotInitialized,
+}
+
+/// Wear-leveled boot counter
+///
+/// This counts boots in any `embedded-storage` implementation (e.g. a [`Partition`]).
+/// Each count is protected by a CRC and written to the next slot of the storage, so that
+/// the writes are spread over the whole storage and a power cut while writing keeps the
+/// previous count.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ BootCounter, Eeprom24x, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut counter = BootCounter::new(Storage::new(eeprom, Delay));
+/// let boots = counter.increment_boot_count().unwrap();
+/// assert_eq!(boots, counter.boot_count().unwrap());
+/// ```
+#[cfg(feature = "boot-counter")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct BootCounter<S> {
+    /// Wrapped storage
+    storage: S,
+    /// Slot and value of the current count, if any
+    current: Option<(u32, u32)>,
+    /// Whether the slots have been checked
+    scanned: bool,
+}
+
+/// Error of a [`BootCounter`]
+#[cfg(feature = "boot-counter")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The storage cannot hold a count
+    TooSmall,
+}
+
+/// Message authentication code used by an [`AuthenticatedBlock`]
+///
+/// This is implemented by the user, e.g. with HMAC-SHA256 of the `hmac` crate and a key
+/// unique to the device.
+#[cfg(feature = "auth")]
+pub trait Mac {
+    /// Tag computed over the data, e.g. `[u8; 32]`
+    type Tag: AsRef<[u8]> + AsMut<[u8]> + Default;
+
+    /// Add data to the tag computation.
+    fn update(&mut self, data: &[u8]);
+
+    /// Return the tag of the data added since the last call and start a new computation.
+    fn finalize(&mut self) -> Self::Tag;
+}
+
+/// Block of data authenticated with a MAC
+///
+/// This stores data in any `embedded-storage` implementation (e.g. a [`Partition`])
+/// behind a header with its length and a tag computed with a user-supplied keyed [`Mac`].
+/// Loading data modified without knowing the key, e.g. by writing to the removed chip,
+/// fails with `AuthError::TamperDetected`.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ AuthenticatedBlock, Eeprom24x, Mac, SlaveAddr, Storage };
+///
+/// struct HmacSha256;
+///
+/// impl Mac for HmacSha256 {
+///     type Tag = [u8; 32];
+///
+///     fn update(&mut self, data: &[u8]) {
+///         // e.g. hmac.update(data)
+///     }
+///
+///     fn finalize(&mut self) -> [u8; 32] {
+///         // e.g. hmac.finalize_reset().into_bytes().into()
+///         [0; 32]
+///     }
+/// }
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut block = AuthenticatedBlock::new(Storage::new(eeprom, Delay), HmacSha256);
+/// block.save(b"max_current=2000").unwrap();
+/// let mut buffer = [0; 64];
+/// let len = block.load(&mut buffer).unwrap();
+/// ```
+#[cfg(feature = "auth")]
+#[derive(Debug)]
+pub struct AuthenticatedBlock<S, M> {
+    /// Wrapped storage
+    storage: S,
+    /// MAC computing the tags
+    mac: M,
+}
+
+/// Error of an [`AuthenticatedBlock`]
+#[cfg(feature = "auth")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The data does not match its tag
+    TamperDetected,
+    /// The buffer cannot hold the data
+    BufferTooSmall {
+        /// Length of the data
+        required: usize,
+    },
+    /// The data is longer than the maximum length
+    TooLarge,
+}
+
+/// Stream cipher used by an [`EncryptedStorage`]
+///
+/// This is implemented by the user, e.g. with the ChaCha20 cipher with 64-bit nonce of the
+/// `chacha20` crate and a key unique to the device.
+#[cfg(feature = "encryption")]
+pub trait Keystream {
+    /// XOR the keystream of a nonce into the data, starting at the beginning of the
+    /// keystream.
+    ///
+    /// Encryption and decryption are the same operation.
+    fn apply_keystream(&mut self, nonce: &[u8; 8], data: &mut [u8]);
+}
+
+/// Storage encrypting the data at rest
+///
+/// This encrypts the data of any `embedded-storage` implementation (e.g. a [`Partition`])
+/// in regions of 32 bytes with a user-supplied [`Keystream`], so that the data cannot be
+/// read by probing the bus or reading the removed chip. Each region is stored with a write
+/// counter which is part of its nonce and incremented at each write, so that a keystream
+/// is never reused. This does not detect modifications of the data.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ EncryptedStorage, Eeprom24x, Keystream, SlaveAddr, Storage };
+/// use embedded_storage::{ReadStorage, Storage as _};
+///
+/// struct Cipher;
+///
+/// impl Keystream for Cipher {
+///     fn apply_keystream(&mut self, nonce: &[u8; 8], data: &mut [u8]) {
+///         // e.g. ChaCha20Legacy::new(&key, nonce).apply_keystream(data)
+///     }
+/// }
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut storage = EncryptedStorage::new(Storage::new(eeprom, Delay), Cipher);
+/// storage.write(0, b"wifi-password").unwrap();
+/// let mut data = [0; 13];
+/// storage.read(0, &mut data).unwrap();
+/// ```
+#[cfg(feature = "encryption")]
+#[derive(Debug)]
+pub struct EncryptedStorage<S, C> {
+    /// Wrapped storage
+    storage: S,
+    /// Cipher encrypting the data
+    cipher: C,
+}
+
+/// Error of an [`EncryptedStorage`]
+#[cfg(feature = "encryption")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncryptionError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The access exceeds the capacity
+    OutOfBounds,
+}
+
+/// Error of [`secure_erase()`]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraseError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The region exceeds the capacity
+    OutOfBounds,
+    /// A byte did not hold the written pattern when read back
+    VerifyFailed {
+        /// Offset of the byte
+        offset: u32,
+    },
+}
+
+/// Cursor over a storage implementing the `embedded-io` traits
+///
+/// This implements `embedded_io::Read`, `Write` and `Seek` over any `embedded-storage`
+/// implementation (e.g. a [`Partition`]), so that serializers and parsers speaking
+/// `embedded-io` can use the EEPROM directly. Writes are buffered up to the next 64-byte
+/// boundary and written when the buffer is full, on [`flush()`](embedded_io::Write::flush)
+/// and before reading.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ EepromCursor, Eeprom24x, SlaveAddr, Storage };
+/// use embedded_io::{Read, Seek, SeekFrom, Write};
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut cursor = EepromCursor::new(Storage::new(eeprom, Delay));
+/// cursor.write_all(b"hello").unwrap();
+/// cursor.flush().unwrap();
+/// cursor.seek(SeekFrom::Start(0)).unwrap();
+/// let mut data = [0; 5];
+/// cursor.read_exact(&mut data).unwrap();
+/// ```
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct EepromCursor<S> {
+    /// Wrapped storage
+    storage: S,
+    /// Current position
+    position: u32,
+    /// Data waiting to be written
+    buffer: [u8; 64],
+    /// Offset of the buffered data
+    buffer_start: u32,
+    /// Number of buffered bytes
+    buffered: usize,
+}
+
+/// Error of an [`EepromCursor`]
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The write reached the end of the storage
+    EndOfStorage,
+    /// The seek position is negative or too large
+    InvalidSeek,
+}
+
+/// Error of an export like [`write_srec()`] or [`hexdump()`]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The region exceeds the capacity
+    OutOfBounds,
+    /// The output could not be written
+    Format,
+}
+
+/// Error of a transfer like [`dump()`] or [`restore()`]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferError<E, C> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The region exceeds the capacity
+    OutOfBounds,
+    /// Error returned by the callback
+    Callback(C),
+}
+
+/// Error of a [`snapshot()`] or [`restore_snapshot()`]
+#[cfg(feature = "snapshot")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotError<E, C> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// Error returned by the callback
+    Callback(C),
+    /// The source does not contain a snapshot
+    InvalidHeader,
+    /// The snapshot was taken from a device with another capacity or page size
+    GeometryMismatch,
+    /// The source ended before the end of the snapshot
+    Truncated,
+    /// The snapshot does not match its CRC
+    Corrupted,
+}
+
+/// Contiguous region of a storage
+///
+/// This implements the `embedded-storage` traits for a region of `len` bytes starting at
+/// an offset of the wrapped storage (e.g. [`Storage`]), so that several subsystems can be
+/// placed in the same device. Accesses outside of the region are rejected.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, Partition, SlaveAddr, Storage };
+/// use embedded_storage::Storage as _;
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut storage = Storage::new(eeprom, Delay);
+/// let mut settings = Partition::new(&mut storage, 0x1000, 0x400).unwrap();
+/// settings.write(0, &[0xAB]).unwrap(); // writes to the memory address 0x1000
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct Partition<'a, S> {
+    /// Wrapped storage
+    storage: &'a mut S,
+    /// Offset of the partition in the storage
+    offset: u32,
+    /// Length of the partition in bytes
+    len: usize,
+}
+
+/// Bitmap stored in a region
+///
+/// This keeps one bit per flag in any `embedded-storage` implementation (e.g. a
+/// [`Partition`]), for example to track consumed one-time tokens or provisioned slots.
+/// Changing a bit reads and writes only the byte containing it.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, EepromBitmap, Partition, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut storage = Storage::new(eeprom, Delay);
+/// let mut tokens = EepromBitmap::new(Partition::new(&mut storage, 0x7F00, 0x100).unwrap());
+/// if !tokens.get(42).unwrap() {
+///     tokens.set(42).unwrap();
+/// }
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct EepromBitmap<S> {
+    /// Wrapped storage
+    storage: S,
+}
+
+/// Typed handle for a value stored at a fixed memory address
+///
+/// This replaces magic offsets scattered through the code with named handles which read
+/// and write a [`Storable`] value in any `embedded-storage` implementation (e.g.
+/// [`Storage`]). Handles can be declared as constants.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, EepromCell, SlaveAddr, Storage };
+///
+/// const BAUD_RATE: EepromCell<u32> = EepromCell::new(0x10);
+/// const BOOT_COUNT: EepromCell<u16> = EepromCell::new(0x14);
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut storage = Storage::new(eeprom, Delay);
+/// let baud_rate = BAUD_RATE.get(&mut storage).unwrap();
+/// let boot_count = BOOT_COUNT.get(&mut storage).unwrap();
+/// BOOT_COUNT.set(&mut storage, &boot_count.wrapping_add(1)).unwrap();
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct EepromCell<T> {
+    /// Memory address of the value
+    address: u32,
+    _value: PhantomData<fn() -> T>,
+}
+
+/// Typed handle for `N` values stored back-to-back from a fixed memory address
+///
+/// This is useful for tables like a number of stored presets. Each element takes the size
+/// of the byte representation of its [`Storable`] type and the index is checked against
+/// the number of elements.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, EepromArray, SlaveAddr, Storage };
+///
+/// const PRESETS: EepromArray<u64, 8> = EepromArray::new(0x100);
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut storage = Storage::new(eeprom, Delay);
+/// PRESETS.set(&mut storage, 3, &0x1234).unwrap();
+/// let preset = PRESETS.get(&mut storage, 3).unwrap();
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct EepromArray<T, const N: usize> {
+    /// Memory address of the first element
+    address: u32,
+    _value: PhantomData<fn() -> T>,
+}
+
+/// Error of an [`EepromArray`]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The index is not lower than the number of elements
+    OutOfBounds {
+        /// Requested index
+        index: usize,
+        /// Number of elements
+        len: usize,
+    },
+}
+
+/// Circular log overwriting the oldest records
+///
+/// This keeps records of up to 255 bytes in any `embedded-storage` implementation
+/// (e.g. a [`Partition`]) and drops the oldest records when it is full, like a black-box
+/// recorder. The head and tail pointers are persisted in two alternating slots when the
+/// tail moves and after 16 records, and the records are protected by a CRC. Records
+/// written completely after the last pointer update are recovered when the log is mounted.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, RingLog, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut log = RingLog::mount(Storage::new(eeprom, Delay)).unwrap();
+/// log.push(b"motor overcurrent").unwrap();
+/// let mut buffer = [0; RingLog::<()>::MAX_RECORD_LEN];
+/// log.for_each(&mut buffer, |record| println!("{:?}", record)).unwrap();
+/// ```
+#[cfg(feature = "ring-log")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct RingLog<S> {
+    /// Wrapped storage
+    storage: S,
+    /// Number of pointer updates
+    generation: u32,
+    /// Position of the next record
+    head: u32,
+    /// Position of the oldest record
+    tail: u32,
+    /// Sequence number of the next record
+    seq: u32,
+    /// Sequence number of the next record at the last pointer update
+    saved_seq: u32,
+}
+
+/// Adapter appending formatted text to a [`RingLog`]
+///
+/// This implements `core::fmt::Write` so that `write!()` and `writeln!()` can be used for
+/// persistent debug logs. Each line is appended as a record. The text of an incomplete
+/// line is only appended with [`flush()`](LogWriter::flush). Create it with
+/// [`RingLog::writer()`].
+///
+/// ```no_run
+/// use core::fmt::Write;
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, RingLog, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut log = RingLog::mount(Storage::new(eeprom, Delay)).unwrap();
+/// let temperature = 85;
+/// writeln!(log.writer(), "overtemperature: {} °C", temperature).unwrap();
+/// ```
+#[cfg(feature = "ring-log")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct LogWriter<'a, S> {
+    /// Log receiving the lines
+    log: &'a mut RingLog<S>,
+    /// Text of the current line
+    line: [u8; 255],
+    /// Length of the current line
+    len: usize,
+}
+
+/// Append-only event log
+///
+/// This appends records of up to 255 bytes to any `embedded-storage` implementation
+/// (e.g. a [`Partition`]) until it is full, for audit trails which must not lose any entry.
+/// Each record is protected by a CRC. When the log is mounted, the records are scanned
+/// and a record torn by a power loss is discarded.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, EventLog, SlaveAddr, Storage };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut log = EventLog::mount(Storage::new(eeprom, Delay)).unwrap();
+/// log.append(b"door opened").unwrap();
+/// let mut buffer = [0; EventLog::<()>::MAX_RECORD_LEN];
+/// let mut records = log.records();
+/// while let Some(record) = records.next() {
+///     let len = records.read(&record.unwrap(), &mut buffer).unwrap();
+///     println!("{:?}", &buffer[..len]);
+/// }
+/// ```
+#[cfg(feature = "event-log")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct EventLog<S> {
+    /// Wrapped storage
+    storage: S,
+    /// Number of times the storage was formatted
+    epoch: u32,
+    /// Offset of the end of the records
+    end: u32,
+}
+
+/// Iterator over the valid records of an [`EventLog`]
+///
+/// The data of each record can be read with [`read()`](Records::read).
+#[cfg(feature = "event-log")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct Records<'a, S> {
+    /// Storage of the log
+    storage: &'a mut S,
+    /// Epoch of the log
+    epoch: u32,
+    /// Offset of the next record
+    position: u32,
+    /// Offset of the end of the records
+    end: u32,
+}
+
+/// Record of an [`EventLog`]
+#[cfg(feature = "event-log")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record {
+    /// Offset of the data in the storage
+    offset: u32,
+    /// Length of the data
+    len: u8,
+}
+
+/// Store of timestamped records
+///
+/// This keeps records of `N` bytes with a `u32` timestamp in any `embedded-storage`
+/// implementation (e.g. a [`Partition`]), overwriting the oldest record when it is full.
+/// As the timestamps are increasing, the records of a time range are found by binary
+/// search without reading the whole storage. Each record is protected by a CRC.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Eeprom24x, SlaveAddr, Storage, TimeSeries };
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24x::new_24x256(dev, SlaveAddr::default());
+/// let mut series: TimeSeries<_, 2> = TimeSeries::mount(Storage::new(eeprom, Delay)).unwrap();
+/// series.append(1_700_000_000, &[0x12, 0x34]).unwrap();
+/// for record in series.range(1_600_000_000..1_800_000_000).unwrap() {
+///     let (timestamp, data) = record.unwrap();
+///     println!("{}: {:?}", timestamp, data);
+/// }
+/// ```
+#[cfg(feature = "time-series")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct TimeSeries<S, const N: usize> {
+    /// Wrapped storage
+    storage: S,
+    /// Slot of the oldest record
+    head: u32,
+    /// Number of records
+    len: u32,
+    /// Timestamp of the newest record
+    last: u32,
+}
+
+/// Iterator over the records of a [`TimeSeries`] in a time range
+#[cfg(feature = "time-series")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct Samples<'a, S, const N: usize> {
+    /// Store of the records
+    series: &'a mut TimeSeries<S, N>,
+    /// Index of the next record
+    index: usize,
+    /// Index following the last record of the range
+    end: usize,
+}
+
+/// Error of a [`TimeSeries`]
+#[cfg(feature = "time-series")]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeSeriesError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The storage cannot hold a record
+    TooSmall,
+    /// The timestamp is not newer than the timestamp of the newest record
+    NotIncreasing,
+    /// There is no record at this index
+    OutOfBounds,
+    /// A record does not match its checksum
+    Corrupted,
+}
+
+/// Error of a `RingLog` or an `EventLog`
+#[cfg(any(feature = "ring-log", feature = "event-log"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogError<E> {
+    /// Error of the underlying storage
+    Storage(E),
+    /// The storage does not contain a log
+    NotFormatted,
+    /// The record is longer than the maximum record length
+    RecordTooLong,
+    /// The buffer cannot hold the record
+    BufferTooSmall {
+        /// Length of the record
+        required: usize,
+    },
+    /// A record does not match its checksum
+    Corrupted,
+    /// The storage cannot hold any record
+    Full,
 }
 
 /// EEPROM24X extension which supports the `embedded-storage` traits but requires an
 /// `embedded_hal::delay::DelayNs` to handle the timeouts when writing over page boundaries
+///
+/// The delay is not used for devices without write cycle (e.g. FRAMs).
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug)]
 pub struct Storage<I2C, PS, AS, SN, D> {
@@ -332,19 +2697,150 @@ pub struct Storage<I2C, PS, AS, SN, D> {
     pub eeprom: Eeprom24x<I2C, PS, AS, SN>,
     /// Delay provider
     delay: D,
+    /// Maximum number of bytes written at once
+    max_write_len: usize,
+}
+
+/// [`Eeprom24xDyn`] extension which supports the `embedded-storage` traits
+///
+/// This is the counterpart of [`Storage`] for devices whose geometry is only known at
+/// runtime. The delay is used to wait for the write cycle after each page.
+///
+/// ```no_run
+/// use linux_embedded_hal::{Delay, I2cdev};
+/// use eeprom24x::{ Device, DynStorage, Eeprom24xDyn, SlaveAddr };
+/// use embedded_storage::Storage as _;
+///
+/// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+/// let eeprom = Eeprom24xDyn::new_from_device(dev, SlaveAddr::default(), Device::AT24C256);
+/// let mut storage = DynStorage::new(eeprom, Delay);
+/// storage.write(0x3E, &[0xAB, 0xCD, 0xEF]).unwrap(); // written in two pages
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct DynStorage<I2C, D> {
+    /// Eeprom driver over which the Storage traits are implemented
+    pub eeprom: Eeprom24xDyn<I2C>,
+    /// Delay provider
+    delay: D,
 }
 
 mod private {
-    use crate::{addr_size, Eeprom24x};
+    use crate::{addr_size, Ee1004, Eeprom24x, Eeprom24xDyn, EepromChain};
 
     pub trait Sealed {}
 
     impl Sealed for addr_size::OneByte {}
     impl Sealed for addr_size::TwoBytes {}
+    impl Sealed for addr_size::TwoBytesBlockSelect {}
     impl<I2C, PS, AS, SN> Sealed for Eeprom24x<I2C, PS, AS, SN> {}
+    impl<I2C> Sealed for Eeprom24xDyn<I2C> {}
+    impl<I2C> Sealed for Ee1004<I2C> {}
+    impl<T, D, const N: usize> Sealed for EepromChain<T, D, N> {}
+    #[cfg(feature = "fault-injection")]
+    impl<T> Sealed for crate::FaultyEeprom<T> {}
+    #[cfg(feature = "alloc")]
+    impl<T: Sealed + ?Sized> Sealed for alloc::boxed::Box<T> {}
 }
 
+mod access;
+mod array;
+#[cfg(feature = "auth")]
+mod auth;
+mod bitmap;
+#[cfg(feature = "block-alloc")]
+mod block_alloc;
+#[cfg(feature = "boot-counter")]
+mod boot_counter;
+#[cfg(feature = "alloc")]
+mod boxed;
+mod buf_reader;
+mod buf_writer;
+mod cache;
+mod cell;
+mod chain;
+mod coalesce;
+#[cfg(feature = "config")]
+mod config;
+#[cfg(any(
+    feature = "kv-store",
+    feature = "ring-log",
+    feature = "event-log",
+    feature = "config",
+    feature = "journal",
+    feature = "mirror",
+    feature = "slot-fs",
+    feature = "time-series",
+    feature = "seed",
+    feature = "boot-counter",
+    feature = "snapshot"
+))]
+mod crc;
+#[cfg(feature = "embedded-io")]
+mod cursor;
+mod device;
+mod device_id;
+#[cfg(feature = "config")]
+mod dual_config;
+mod dyn_storage;
+#[cfg(feature = "ecc")]
+mod ecc;
+mod ee1004;
 mod eeprom24x;
+pub use crate::eeprom24x::WriteCycle;
+mod eeprom24x_dyn;
+mod eeram;
+#[cfg(feature = "encryption")]
+mod encryption;
+mod erase;
+pub use crate::erase::secure_erase;
+mod error;
+mod export;
+pub use crate::export::{hexdump, write_srec};
+#[cfg(feature = "event-log")]
+mod event_log;
+#[cfg(feature = "fault-injection")]
+mod fault_injection;
+mod fram;
+mod hook;
+mod id_page;
+#[cfg(feature = "journal")]
+mod journal;
+#[cfg(feature = "kv-store")]
+mod kv_store;
+#[cfg(feature = "ring-log")]
+mod log_writer;
+mod m24lr;
+#[cfg(feature = "mirror")]
+mod mirror;
+mod partition;
+mod probe;
+mod read_ahead;
+pub use crate::probe::scan;
+mod recover;
+mod refresh;
+pub use crate::refresh::{refresh, refresh_if_older};
+#[cfg(feature = "ring-log")]
+mod ring_log;
+#[cfg(feature = "seed")]
+mod seed;
 mod serial_number;
+mod shared_bus;
+#[cfg(feature = "critical-section")]
+mod shared_eeprom;
 mod slave_addr;
+mod slice;
+#[cfg(feature = "slot-fs")]
+mod slot_fs;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+#[cfg(feature = "snapshot")]
+pub use crate::snapshot::{restore_snapshot, snapshot};
+mod spd;
 mod storage;
+mod temperature;
+#[cfg(feature = "time-series")]
+mod time_series;
+mod transfer;
+pub use crate::transfer::{dump, restore};
+mod write_protection;
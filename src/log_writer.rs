@@ -0,0 +1,60 @@
+use crate::slice::split;
+use crate::{LogError, LogWriter, RingLog};
+use core::fmt;
+use embedded_storage::Storage;
+
+impl<S> RingLog<S> {
+    /// Return an adapter appending formatted text to the log, one record per line.
+    pub fn writer(&mut self) -> LogWriter<'_, S> {
+        LogWriter {
+            log: self,
+            line: [0; RingLog::<()>::MAX_RECORD_LEN],
+            len: 0,
+        }
+    }
+}
+
+impl<S> LogWriter<'_, S>
+where
+    S: Storage,
+{
+    /// Append the text of an incomplete line as a record.
+    pub fn flush(&mut self) -> Result<(), LogError<S::Error>> {
+        if self.len > 0 {
+            self.log.push(split(&self.line, self.len).0)?;
+            self.len = 0;
+        }
+        Ok(())
+    }
+}
+
+impl<S> fmt::Write for LogWriter<'_, S>
+where
+    S: Storage,
+{
+    /// Add text to the current line.
+    ///
+    /// Each complete line is appended as a record without its line break. Lines longer
+    /// than [`MAX_RECORD_LEN`](RingLog::MAX_RECORD_LEN) are split into several records.
+    /// Errors of the log are reported as `fmt::Error`.
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        for byte in text.bytes() {
+            if byte == b'\n' {
+                // empty lines are kept as empty records
+                self.log
+                    .push(split(&self.line, self.len).0)
+                    .map_err(|_| fmt::Error)?;
+                self.len = 0;
+                continue;
+            }
+            if self.len == self.line.len() {
+                self.flush().map_err(|_| fmt::Error)?;
+            }
+            if let Some(target) = self.line.get_mut(self.len) {
+                *target = byte;
+                self.len += 1;
+            }
+        }
+        Ok(())
+    }
+}
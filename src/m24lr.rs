@@ -0,0 +1,122 @@
+use crate::{addr_size::TwoBytes, page_size::B4, unique_serial, Eeprom24x, Error};
+use embedded_hal::i2c::I2c;
+
+/// Bit selecting the system area in the device address.
+const SYSTEM_AREA_BIT: u8 = 0b100;
+/// Size of a memory sector in bytes.
+const SECTOR_SIZE: usize = 128;
+/// System area address of the I²C password.
+const PASSWORD_ADDRESS: [u8; 2] = [0x09, 0x00];
+/// Validation code to present the I²C password.
+const PRESENT_PASSWORD: u8 = 0x09;
+/// Validation code to change the I²C password.
+const WRITE_PASSWORD: u8 = 0x07;
+/// System area address of the configuration register.
+const CONFIGURATION_ADDRESS: [u8; 2] = [0x09, 0x10];
+/// System area address of the 64-bit unique identifier.
+const UID_ADDRESS: [u8; 2] = [0x09, 0x14];
+
+/// Methods for interacting with the system area of M24LR dual-interface devices.
+/// e.g. M24LR64E-R.
+///
+/// The user memory is accessed at the device address `0x53`, i.e.
+/// `SlaveAddr::Alternative(false, true, true)` must be used. The system area is accessed
+/// at `0x57`, i.e. with the E2 bit set.
+/// Writing to the system area requires presenting the I²C password beforehand and
+/// triggers an internally-timed write cycle.
+impl<I2C, E> Eeprom24x<I2C, B4, TwoBytes, unique_serial::M24lr>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Present the I²C password to unlock the write access to protected sectors and to
+    /// the system area.
+    ///
+    /// The device does not report whether the password is correct.
+    pub fn present_password(&mut self, password: u32) -> Result<(), Error<E>> {
+        self.send_password(PRESENT_PASSWORD, password)
+    }
+
+    /// Change the I²C password.
+    ///
+    /// The current password must have been presented beforehand.
+    pub fn write_password(&mut self, password: u32) -> Result<(), Error<E>> {
+        self.send_password(WRITE_PASSWORD, password)
+    }
+
+    /// Read the sector security status byte of a memory sector.
+    ///
+    /// The sectors are 128 bytes long. Returns `Error::InvalidAddr` if the sector does
+    /// not exist.
+    pub fn read_sector_security(&mut self, sector: u8) -> Result<u8, Error<E>> {
+        self.check_sector(sector)?;
+        let mut data = [0];
+        let result = self
+            .i2c
+            .write_read(self.system_area_addr(), &[0, sector], &mut data);
+        self.finish_access(result, false).and(Ok(data[0]))
+    }
+
+    /// Write the sector security status byte of a memory sector.
+    ///
+    /// The sectors are 128 bytes long. Returns `Error::InvalidAddr` if the sector does
+    /// not exist.
+    pub fn write_sector_security(&mut self, sector: u8, status: u8) -> Result<(), Error<E>> {
+        self.check_sector(sector)?;
+        let result = self
+            .i2c
+            .write(self.system_area_addr(), &[0, sector, status]);
+        self.finish_access(result, true)
+    }
+
+    /// Read the configuration register.
+    pub fn read_configuration(&mut self) -> Result<u8, Error<E>> {
+        let mut data = [0];
+        let result =
+            self.i2c
+                .write_read(self.system_area_addr(), &CONFIGURATION_ADDRESS, &mut data);
+        self.finish_access(result, false).and(Ok(data[0]))
+    }
+
+    /// Write the configuration register.
+    pub fn write_configuration(&mut self, configuration: u8) -> Result<(), Error<E>> {
+        let [high, low] = CONFIGURATION_ADDRESS;
+        let result = self
+            .i2c
+            .write(self.system_area_addr(), &[high, low, configuration]);
+        self.finish_access(result, true)
+    }
+
+    /// Read the 64-bit unique identifier.
+    pub fn read_uid(&mut self) -> Result<[u8; 8], Error<E>> {
+        let mut uid = [0; 8];
+        let result = self
+            .i2c
+            .write_read(self.system_area_addr(), &UID_ADDRESS, &mut uid);
+        self.finish_access(result, false).and(Ok(uid))
+    }
+
+    fn send_password(&mut self, code: u8, password: u32) -> Result<(), Error<E>> {
+        let [high, low] = PASSWORD_ADDRESS;
+        let [p0, p1, p2, p3] = password.to_be_bytes();
+        let result = self.i2c.write(
+            self.system_area_addr(),
+            &[high, low, p0, p1, p2, p3, code, p0, p1, p2, p3],
+        );
+        self.finish_access(result, true)
+    }
+
+    fn check_sector(&self, sector: u8) -> Result<(), Error<E>> {
+        if usize::from(sector) >= self.capacity() / SECTOR_SIZE {
+            return Err(Error::InvalidAddr {
+                address: u32::from(sector) * SECTOR_SIZE as u32,
+                capacity: self.capacity(),
+            });
+        }
+        Ok(())
+    }
+
+    fn system_area_addr(&self) -> u8 {
+        self.address.addr() | SYSTEM_AREA_BIT
+    }
+}
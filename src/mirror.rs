@@ -0,0 +1,185 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::{copy, split, split_mut};
+use crate::{Mirror, MirrorError};
+use embedded_storage::{ReadStorage, Storage};
+
+/// Size of a block in each copy: data and checksum.
+const BLOCK_LEN: usize = 16;
+/// Number of data bytes in a block.
+const BLOCK_DATA_LEN: usize = BLOCK_LEN - 2;
+
+type Block = [u8; BLOCK_LEN];
+
+impl<S> Mirror<S> {
+    /// Create a new instance keeping a copy in each half of the storage.
+    pub fn new(storage: S) -> Self {
+        Mirror { storage }
+    }
+
+    /// Destroy the mirror, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S> Mirror<S>
+where
+    S: Storage,
+{
+    /// Fill both copies with zeros.
+    ///
+    /// This is needed once before use, as blocks whose copies are both invalid cannot be
+    /// read.
+    pub fn format(&mut self) -> Result<(), MirrorError<S::Error>> {
+        let block = seal([0; BLOCK_DATA_LEN]);
+        for index in 0..self.blocks() {
+            self.write_block(index, &block)?;
+        }
+        Ok(())
+    }
+
+    /// Check both copies of all the blocks and repair the bad copies.
+    ///
+    /// Returns the number of repaired blocks. Call this periodically so that an error in
+    /// one copy is repaired before the other copy is damaged too.
+    pub fn scrub(&mut self) -> Result<usize, MirrorError<S::Error>> {
+        let mut repaired = 0;
+        for index in 0..self.blocks() {
+            let (_, was_repaired) = self.read_block(index)?;
+            if was_repaired {
+                repaired += 1;
+            }
+        }
+        Ok(repaired)
+    }
+
+    fn blocks(&self) -> u32 {
+        (self.storage.capacity() / 2 / BLOCK_LEN) as u32
+    }
+
+    fn copy_offset(&self, copy: u32, index: u32) -> u32 {
+        copy * (self.storage.capacity() / 2) as u32 + index * BLOCK_LEN as u32
+    }
+
+    /// Read a block, repairing a bad copy. Returns the data and whether a copy was
+    /// repaired.
+    fn read_block(
+        &mut self,
+        index: u32,
+    ) -> Result<([u8; BLOCK_DATA_LEN], bool), MirrorError<S::Error>> {
+        let mut first: Block = [0; BLOCK_LEN];
+        let mut second: Block = [0; BLOCK_LEN];
+        self.storage
+            .read(self.copy_offset(0, index), &mut first)
+            .map_err(MirrorError::Storage)?;
+        self.storage
+            .read(self.copy_offset(1, index), &mut second)
+            .map_err(MirrorError::Storage)?;
+        // the first copy is written first, so it holds the newer data if both are valid
+        let (good, bad_copy) = match (is_valid(&first), is_valid(&second)) {
+            (true, true) if first == second => return Ok((data(&first), false)),
+            (true, _) => (first, 1),
+            (false, true) => (second, 0),
+            (false, false) => {
+                return Err(MirrorError::Corrupted {
+                    offset: index * BLOCK_DATA_LEN as u32,
+                })
+            }
+        };
+        self.storage
+            .write(self.copy_offset(bad_copy, index), &good)
+            .map_err(MirrorError::Storage)?;
+        Ok((data(&good), true))
+    }
+
+    fn write_block(&mut self, index: u32, block: &Block) -> Result<(), MirrorError<S::Error>> {
+        for copy in 0..2 {
+            self.storage
+                .write(self.copy_offset(copy, index), block)
+                .map_err(MirrorError::Storage)?;
+        }
+        Ok(())
+    }
+
+    fn check_bounds(&self, offset: u32, len: usize) -> Result<(), MirrorError<S::Error>> {
+        if len > self.capacity().saturating_sub(offset as usize) {
+            return Err(MirrorError::OutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+impl<S> ReadStorage for Mirror<S>
+where
+    S: Storage,
+{
+    type Error = MirrorError<S::Error>;
+
+    /// Read data, repairing the bad copy of the blocks whose copies disagree.
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.check_bounds(offset, bytes.len())?;
+        let mut offset = offset as usize;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let (data, _) = self.read_block((offset / BLOCK_DATA_LEN) as u32)?;
+            let (_, source) = split(&data, offset % BLOCK_DATA_LEN);
+            let (chunk, rest) = split_mut(core::mem::take(&mut bytes), source.len());
+            offset += copy(chunk, source);
+            bytes = rest;
+        }
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.blocks() as usize * BLOCK_DATA_LEN
+    }
+}
+
+impl<S> Storage for Mirror<S>
+where
+    S: Storage,
+{
+    /// Write data to both copies.
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check_bounds(offset, bytes.len())?;
+        let mut offset = offset as usize;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let index = (offset / BLOCK_DATA_LEN) as u32;
+            let start = offset % BLOCK_DATA_LEN;
+            let len = bytes.len().min(BLOCK_DATA_LEN - start);
+            let mut data = if len < BLOCK_DATA_LEN {
+                self.read_block(index)?.0
+            } else {
+                [0; BLOCK_DATA_LEN]
+            };
+            let (_, target) = split_mut(&mut data, start);
+            let len = copy(target, bytes);
+            let (_, rest) = split(bytes, len);
+            self.write_block(index, &seal(data))?;
+            offset += len;
+            bytes = rest;
+        }
+        Ok(())
+    }
+}
+
+/// Append the checksum to the data of a block.
+fn seal(data: [u8; BLOCK_DATA_LEN]) -> Block {
+    let mut block = [0; BLOCK_LEN];
+    let (block_data, block_crc) = split_mut(&mut block, BLOCK_DATA_LEN);
+    copy(block_data, &data);
+    copy(block_crc, &crc16(CRC16_INIT, &data).to_le_bytes());
+    block
+}
+
+fn data(block: &Block) -> [u8; BLOCK_DATA_LEN] {
+    let mut data = [0; BLOCK_DATA_LEN];
+    copy(&mut data, block);
+    data
+}
+
+fn is_valid(block: &Block) -> bool {
+    let (data, crc) = split(block, BLOCK_DATA_LEN);
+    crc16(CRC16_INIT, data).to_le_bytes() == crc
+}
@@ -0,0 +1,70 @@
+use crate::{Error, Partition};
+use embedded_storage::{ReadStorage, Storage};
+
+impl<'a, S, E> Partition<'a, S>
+where
+    S: ReadStorage<Error = Error<E>>,
+{
+    /// Create a partition of `len` bytes starting at `offset` in the storage.
+    ///
+    /// Returns `Error::CapacityExceeded` if the partition does not fit into the storage.
+    pub fn new(storage: &'a mut S, offset: u32, len: usize) -> Result<Self, Error<E>> {
+        let allowed = storage.capacity().saturating_sub(offset as usize);
+        if len > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: len,
+                allowed,
+            });
+        }
+        Ok(Partition {
+            storage,
+            offset,
+            len,
+        })
+    }
+}
+
+impl<S> Partition<'_, S> {
+    /// Return the offset of the partition in the storage.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+}
+
+impl<S, E> ReadStorage for Partition<'_, S>
+where
+    S: ReadStorage<Error = Error<E>>,
+{
+    type Error = Error<E>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let end = (offset as usize).checked_add(bytes.len());
+        if !matches!(end, Some(end) if end <= self.len) {
+            return Err(Error::InvalidAddr {
+                address: offset,
+                capacity: self.len,
+            });
+        }
+        self.storage.read(self.offset + offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        self.len
+    }
+}
+
+impl<S, E> Storage for Partition<'_, S>
+where
+    S: Storage<Error = Error<E>>,
+{
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let allowed = self.len.saturating_sub(offset as usize);
+        if bytes.len() > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: bytes.len(),
+                allowed,
+            });
+        }
+        self.storage.write(self.offset + offset, bytes)
+    }
+}
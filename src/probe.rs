@@ -0,0 +1,41 @@
+use crate::{Error, SlaveAddr};
+use embedded_hal::i2c::{Error as I2cError, ErrorKind, I2c};
+
+/// Probe an I²C address with a zero-length write.
+///
+/// Returns whether the address was acknowledged.
+pub(crate) fn probe<I2C, E>(i2c: &mut I2C, address: u8) -> Result<bool, Error<E>>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    match i2c.write(address, &[]) {
+        Ok(()) => Ok(true),
+        Err(e) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => Ok(false),
+        Err(e) => Err(Error::I2C(e)),
+    }
+}
+
+/// Scan the EEPROM address range `0x50..=0x57` for devices.
+///
+/// Each address is probed with a zero-length write. Bit `n` of the returned mask is set if
+/// a device acknowledged address `0x50 + n`, i.e. for the slave address
+/// `SlaveAddr::from_pins(n)`. Bus errors other than a missing acknowledge are returned.
+///
+/// Note that devices using some of the device address bits for memory addressing
+/// (e.g. 24x16) acknowledge several addresses. Devices within an internally-timed write
+/// cycle do not acknowledge and will therefore not be detected. Some platforms do not
+/// support zero-length writes.
+pub fn scan<I2C, E>(i2c: &mut I2C) -> Result<u8, Error<E>>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    let mut mask = 0;
+    for pins in 0..8 {
+        if probe(i2c, SlaveAddr::from_pins(pins).addr())? {
+            mask |= 1 << pins;
+        }
+    }
+    Ok(mask)
+}
@@ -0,0 +1,108 @@
+use crate::slice::split_mut;
+use crate::ReadAhead;
+use core::cmp::min;
+use embedded_storage::{ReadStorage, Storage};
+
+impl<S, const N: usize> ReadAhead<S, N> {
+    /// Create a new read-ahead buffer wrapping a storage.
+    pub fn new(storage: S) -> Self {
+        ReadAhead {
+            storage,
+            buffer: [0; N],
+            window: None,
+        }
+    }
+
+    /// Destroy the wrapper, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+
+    /// Drop the buffered data.
+    ///
+    /// This must be done after the memory has been modified through other means.
+    pub fn invalidate(&mut self) {
+        self.window = None;
+    }
+
+    /// Return the buffered data for a memory range, if completely available.
+    fn buffered(&self, offset: u32, len: usize) -> Option<&[u8]> {
+        let (address, available) = self.window?;
+        let start = offset.checked_sub(address)? as usize;
+        let end = start.checked_add(len)?;
+        if end > available {
+            return None;
+        }
+        self.buffer.get(start..end)
+    }
+}
+
+impl<S, const N: usize> ReadAhead<S, N>
+where
+    S: ReadStorage,
+{
+    /// Read a single byte from an address.
+    pub fn read_byte(&mut self, address: u32) -> Result<u8, S::Error> {
+        let mut data = [0];
+        self.read(address, &mut data)?;
+        Ok(data[0])
+    }
+}
+
+impl<S, const N: usize> ReadStorage for ReadAhead<S, N>
+where
+    S: ReadStorage,
+{
+    type Error = S::Error;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        if bytes.len() >= N {
+            return self.storage.read(offset, bytes);
+        }
+        if self.buffered(offset, bytes.len()).is_none() {
+            let len = min(N, self.storage.capacity().saturating_sub(offset as usize));
+            if len < bytes.len() {
+                // let the storage report the invalid range
+                return self.storage.read(offset, bytes);
+            }
+            self.window = None;
+            let (buffer, _) = split_mut(&mut self.buffer, len);
+            self.storage.read(offset, buffer)?;
+            self.window = Some((offset, buffer.len()));
+        }
+        if let Some(data) = self.buffered(offset, bytes.len()) {
+            bytes.copy_from_slice(data);
+        }
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.storage.capacity()
+    }
+}
+
+impl<S, const N: usize> Storage for ReadAhead<S, N>
+where
+    S: Storage,
+{
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let result = self.storage.write(offset, bytes);
+        match (&result, self.window) {
+            (Ok(()), Some((address, len))) => {
+                let buffered = self.buffer.iter_mut().take(len);
+                for (current, byte) in (address..).zip(buffered) {
+                    if let Some(written) = current
+                        .checked_sub(offset)
+                        .and_then(|index| bytes.get(index as usize))
+                    {
+                        *byte = *written;
+                    }
+                }
+            }
+            // the write may have been partially done
+            (Err(_), _) => self.window = None,
+            (Ok(()), None) => (),
+        }
+        result
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Error;
+use embedded_hal::i2c::{Error as I2cError, ErrorKind, I2c};
+
+/// Reserved address whose read header `0xFF` keeps SDA released during the 8 clock cycles.
+const RECOVERY_ADDRESS: u8 = 0x7F;
+
+/// Run the software reset sequence: START, 9 clock cycles with SDA high, START, STOP.
+///
+/// The clock cycles are generated by transmitting the read header of the reserved address
+/// `0x7F` (`0xFF`) followed by the acknowledge clock cycle. The final START and STOP
+/// conditions are generated with a zero-length write to the device.
+/// Missing acknowledges and arbitration losses are expected and ignored, since a device
+/// stuck in a read holds SDA low until it has clocked out its current byte.
+pub(crate) fn bus_recover<I2C, E>(i2c: &mut I2C, address: u8) -> Result<(), Error<E>>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    ignore_expected(i2c.read(RECOVERY_ADDRESS, &mut []))?;
+    ignore_expected(i2c.write(address, &[]))
+}
+
+fn ignore_expected<E: I2cError>(result: Result<(), E>) -> Result<(), Error<E>> {
+    match result {
+        Err(e)
+            if !matches!(
+                e.kind(),
+                ErrorKind::NoAcknowledge(_) | ErrorKind::ArbitrationLoss
+            ) =>
+        {
+            Err(Error::I2C(e))
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,56 @@
+use crate::slice::split_mut;
+use core::ops::Range;
+use embedded_storage::Storage;
+
+/// Size of the chunks in which the data is rewritten.
+///
+/// The chunks are aligned to their size so that a chunk never spans more pages than
+/// necessary for devices with pages of up to 64 bytes.
+const CHUNK_LEN: usize = 64;
+
+/// Read and rewrite the data of a region to restore its charge.
+///
+/// The data retention of EEPROMs decreases at high temperatures. Refreshing the data
+/// periodically, e.g. once a year, keeps it readable beyond the specified retention time
+/// at the cost of one write cycle per page.
+pub fn refresh<S>(storage: &mut S, range: Range<u32>) -> Result<(), S::Error>
+where
+    S: Storage,
+{
+    let mut chunk = [0; CHUNK_LEN];
+    let mut offset = range.start;
+    while offset < range.end {
+        let len = ((range.end - offset) as usize).min(CHUNK_LEN - offset as usize % CHUNK_LEN);
+        let (data, _) = split_mut(&mut chunk, len);
+        storage.read(offset, data)?;
+        storage.write(offset, data)?;
+        offset += data.len() as u32;
+    }
+    Ok(())
+}
+
+/// Refresh a region if it was last refreshed at least `max_age` ago.
+///
+/// The time of the last refresh is stored as a little-endian `u32` at `stamp_offset`,
+/// which must lie outside of the region, in the same unit as `now` (e.g. days). An erased
+/// stamp (`0xFFFF_FFFF`) is treated as too old. Returns whether the region was refreshed.
+pub fn refresh_if_older<S>(
+    storage: &mut S,
+    range: Range<u32>,
+    stamp_offset: u32,
+    now: u32,
+    max_age: u32,
+) -> Result<bool, S::Error>
+where
+    S: Storage,
+{
+    let mut stamp = [0; 4];
+    storage.read(stamp_offset, &mut stamp)?;
+    let stamp = u32::from_le_bytes(stamp);
+    if stamp != u32::MAX && now.wrapping_sub(stamp) < max_age {
+        return Ok(false);
+    }
+    refresh(storage, range)?;
+    storage.write(stamp_offset, &now.to_le_bytes())?;
+    Ok(true)
+}
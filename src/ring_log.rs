@@ -0,0 +1,363 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::{split, split_mut};
+use crate::{LogError, RingLog};
+use embedded_storage::Storage;
+
+/// Marker at the start of a valid pointer slot.
+const MAGIC: [u8; 2] = *b"RL";
+/// Size of a pointer slot: magic, generation, head, tail, sequence number and checksum.
+const SLOT_LEN: u32 = 20;
+/// Size of the header holding the two pointer slots.
+const HEADER_LEN: u32 = 2 * SLOT_LEN;
+/// Size of a record header: length and sequence number.
+const RECORD_HEADER_LEN: u32 = 5;
+/// Size of a record without the data.
+const RECORD_OVERHEAD: u32 = RECORD_HEADER_LEN + 2;
+/// Number of records after which the pointers are persisted even if the tail did not move.
+///
+/// The records appended since are found again by `recover()`, so this only bounds the
+/// length of the scan when mounting.
+const SAVE_INTERVAL: u32 = 16;
+
+/// Persisted state of the log
+#[derive(Clone, Copy)]
+struct Pointers {
+    generation: u32,
+    head: u32,
+    tail: u32,
+    seq: u32,
+}
+
+impl<S> RingLog<S> {
+    /// Maximum length of a record in bytes.
+    pub const MAX_RECORD_LEN: usize = u8::MAX as usize;
+
+    /// Destroy the log, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+
+    /// Return whether the log contains no records.
+    pub fn is_empty(&self) -> bool {
+        self.head == self.tail
+    }
+}
+
+impl<S> RingLog<S>
+where
+    S: Storage,
+{
+    /// Create an empty log in the storage.
+    ///
+    /// The storage starts with a header holding the head and tail pointers followed by
+    /// the records. Any previous records are discarded. Returns `LogError::Full` if the
+    /// storage is too small.
+    pub fn format(storage: S) -> Result<Self, LogError<S::Error>> {
+        let mut log = RingLog {
+            storage,
+            generation: 0,
+            head: 0,
+            tail: 0,
+            seq: 0,
+            saved_seq: 0,
+        };
+        if log.data_len() <= RECORD_OVERHEAD {
+            return Err(LogError::Full);
+        }
+        // continue the sequence numbers so that no old record can be recovered
+        if let Some(previous) = log.read_pointers()? {
+            log.generation = previous.generation;
+            log.seq = previous.seq;
+            if previous.head < log.data_len() && previous.tail < log.data_len() {
+                log.head = previous.head;
+                log.tail = previous.tail;
+                log.recover()?;
+                log.head = 0;
+                log.tail = 0;
+            }
+        }
+        log.save()?;
+        Ok(log)
+    }
+
+    /// Open a log previously created with [`format()`](Self::format).
+    ///
+    /// Records which were written completely before a power loss but not yet recorded in
+    /// the head pointer are recovered. Returns `LogError::NotFormatted` if the storage does
+    /// not contain a log.
+    pub fn mount(storage: S) -> Result<Self, LogError<S::Error>> {
+        let mut log = RingLog {
+            storage,
+            generation: 0,
+            head: 0,
+            tail: 0,
+            seq: 0,
+            saved_seq: 0,
+        };
+        if log.data_len() <= RECORD_OVERHEAD {
+            return Err(LogError::NotFormatted);
+        }
+        let pointers = log.read_pointers()?.ok_or(LogError::NotFormatted)?;
+        if pointers.head >= log.data_len() || pointers.tail >= log.data_len() {
+            return Err(LogError::NotFormatted);
+        }
+        log.generation = pointers.generation;
+        log.head = pointers.head;
+        log.tail = pointers.tail;
+        log.seq = pointers.seq;
+        log.saved_seq = pointers.seq;
+        log.recover()?;
+        Ok(log)
+    }
+
+    /// Append a record, dropping the oldest records if necessary.
+    ///
+    /// The pointers are only persisted when the oldest records are dropped and after 16
+    /// records, so that the header does not wear out faster than the records. The records
+    /// appended since are recovered when the log is mounted.
+    ///
+    /// Records of a fixed length can be stored by always appending data of that length.
+    /// Returns `LogError::RecordTooLong` if the record is longer than
+    /// [`MAX_RECORD_LEN`](Self::MAX_RECORD_LEN) or does not fit into the storage.
+    pub fn push(&mut self, data: &[u8]) -> Result<(), LogError<S::Error>> {
+        let len = u8::try_from(data.len()).map_err(|_| LogError::RecordTooLong)?;
+        let size = RECORD_OVERHEAD + u32::from(len);
+        if size >= self.data_len() {
+            return Err(LogError::RecordTooLong);
+        }
+        if self.used() + size >= self.data_len() {
+            while self.used() + size >= self.data_len() {
+                let (oldest, _) = self.read_record_header(self.tail)?;
+                let oldest = RECORD_OVERHEAD + u32::from(oldest);
+                if oldest > self.used() {
+                    return Err(LogError::Corrupted);
+                }
+                self.tail = self.advance(self.tail, oldest);
+            }
+            // the dropped records must be gone before they are overwritten
+            self.save()?;
+        }
+
+        let [s0, s1, s2, s3] = self.seq.to_le_bytes();
+        let header = [len, s0, s1, s2, s3];
+        let crc = crc16(crc16(CRC16_INIT, &header), data);
+        self.write_wrapped(self.head, &header)?;
+        self.write_wrapped(self.advance(self.head, RECORD_HEADER_LEN), data)?;
+        let crc_position = self.advance(self.head, RECORD_HEADER_LEN + u32::from(len));
+        self.write_wrapped(crc_position, &crc.to_le_bytes())?;
+        self.head = self.advance(self.head, size);
+        self.seq = self.seq.wrapping_add(1);
+        if self.seq.wrapping_sub(self.saved_seq) >= SAVE_INTERVAL {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Read the oldest record into the buffer and return its length.
+    ///
+    /// Returns `None` if the log is empty.
+    pub fn peek(&mut self, buffer: &mut [u8]) -> Result<Option<usize>, LogError<S::Error>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        self.read_record(self.tail, buffer).map(Some)
+    }
+
+    /// Read the oldest record into the buffer, remove it and return its length.
+    ///
+    /// Returns `None` if the log is empty.
+    pub fn pop(&mut self, buffer: &mut [u8]) -> Result<Option<usize>, LogError<S::Error>> {
+        let len = self.peek(buffer)?;
+        if let Some(len) = len {
+            self.tail = self.advance(self.tail, RECORD_OVERHEAD + len as u32);
+            self.save()?;
+        }
+        Ok(len)
+    }
+
+    /// Read all the records from the oldest to the newest one.
+    ///
+    /// Each record is read into the buffer and passed to the closure.
+    pub fn for_each<F>(&mut self, buffer: &mut [u8], mut f: F) -> Result<(), LogError<S::Error>>
+    where
+        F: FnMut(&[u8]),
+    {
+        let mut position = self.tail;
+        while position != self.head {
+            let len = self.read_record(position, buffer)?;
+            f(split(buffer, len).0);
+            position = self.advance(position, RECORD_OVERHEAD + len as u32);
+        }
+        Ok(())
+    }
+
+    /// Remove all the records.
+    pub fn clear(&mut self) -> Result<(), LogError<S::Error>> {
+        self.tail = self.head;
+        self.save()
+    }
+
+    fn data_len(&self) -> u32 {
+        (self.storage.capacity() as u32).saturating_sub(HEADER_LEN)
+    }
+
+    fn used(&self) -> u32 {
+        (self.head + self.data_len() - self.tail) % self.data_len()
+    }
+
+    fn advance(&self, position: u32, len: u32) -> u32 {
+        (position + len) % self.data_len()
+    }
+
+    fn read_wrapped(&mut self, position: u32, bytes: &mut [u8]) -> Result<(), LogError<S::Error>> {
+        let first = (self.data_len() - position) as usize;
+        let (start, end) = split_mut(bytes, first);
+        self.storage
+            .read(HEADER_LEN + position, start)
+            .map_err(LogError::Storage)?;
+        if !end.is_empty() {
+            self.storage
+                .read(HEADER_LEN, end)
+                .map_err(LogError::Storage)?;
+        }
+        Ok(())
+    }
+
+    fn write_wrapped(&mut self, position: u32, bytes: &[u8]) -> Result<(), LogError<S::Error>> {
+        let first = (self.data_len() - position) as usize;
+        let (start, end) = split(bytes, first);
+        if !start.is_empty() {
+            self.storage
+                .write(HEADER_LEN + position, start)
+                .map_err(LogError::Storage)?;
+        }
+        if !end.is_empty() {
+            self.storage
+                .write(HEADER_LEN, end)
+                .map_err(LogError::Storage)?;
+        }
+        Ok(())
+    }
+
+    fn read_record_header(&mut self, position: u32) -> Result<(u8, u32), LogError<S::Error>> {
+        let mut header = [0; RECORD_HEADER_LEN as usize];
+        self.read_wrapped(position, &mut header)?;
+        let [len, s0, s1, s2, s3] = header;
+        Ok((len, u32::from_le_bytes([s0, s1, s2, s3])))
+    }
+
+    /// Read the data of the record at a position into the buffer and check it.
+    fn read_record(
+        &mut self,
+        position: u32,
+        buffer: &mut [u8],
+    ) -> Result<usize, LogError<S::Error>> {
+        let (len, seq) = self.read_record_header(position)?;
+        if !self.is_valid(position, len, seq, buffer)? {
+            return Err(LogError::Corrupted);
+        }
+        Ok(usize::from(len))
+    }
+
+    /// Read the data of a record into the buffer and compare its checksum.
+    fn is_valid(
+        &mut self,
+        position: u32,
+        len: u8,
+        seq: u32,
+        buffer: &mut [u8],
+    ) -> Result<bool, LogError<S::Error>> {
+        let required = usize::from(len);
+        let data = buffer
+            .get_mut(..required)
+            .ok_or(LogError::BufferTooSmall { required })?;
+        self.read_wrapped(self.advance(position, RECORD_HEADER_LEN), data)?;
+        let mut stored = [0; 2];
+        let crc_position = self.advance(position, RECORD_HEADER_LEN + u32::from(len));
+        self.read_wrapped(crc_position, &mut stored)?;
+        let [s0, s1, s2, s3] = seq.to_le_bytes();
+        let crc = crc16(crc16(CRC16_INIT, &[len, s0, s1, s2, s3]), data);
+        Ok(crc == u16::from_le_bytes(stored))
+    }
+
+    /// Adopt the complete records written after the persisted head.
+    fn recover(&mut self) -> Result<(), LogError<S::Error>> {
+        let mut buffer = [0; u8::MAX as usize];
+        let mut recovered = false;
+        loop {
+            let (len, seq) = self.read_record_header(self.head)?;
+            let size = RECORD_OVERHEAD + u32::from(len);
+            if seq != self.seq
+                || self.used() + size >= self.data_len()
+                || !self.is_valid(self.head, len, seq, &mut buffer)?
+            {
+                break;
+            }
+            self.head = self.advance(self.head, size);
+            self.seq = self.seq.wrapping_add(1);
+            recovered = true;
+        }
+        if recovered {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Read the valid pointer slot with the highest generation.
+    fn read_pointers(&mut self) -> Result<Option<Pointers>, LogError<S::Error>> {
+        let mut found: Option<Pointers> = None;
+        for slot in 0..2 {
+            let mut data = [0; SLOT_LEN as usize];
+            self.storage
+                .read(slot * SLOT_LEN, &mut data)
+                .map_err(LogError::Storage)?;
+            let [m0, m1, g0, g1, g2, g3, h0, h1, h2, h3, t0, t1, t2, t3, s0, s1, s2, s3, c0, c1] =
+                data;
+            let crc = crc16(
+                CRC16_INIT,
+                &[
+                    m0, m1, g0, g1, g2, g3, h0, h1, h2, h3, t0, t1, t2, t3, s0, s1, s2, s3,
+                ],
+            );
+            if [m0, m1] != MAGIC || crc != u16::from_le_bytes([c0, c1]) {
+                continue;
+            }
+            let pointers = Pointers {
+                generation: u32::from_le_bytes([g0, g1, g2, g3]),
+                head: u32::from_le_bytes([h0, h1, h2, h3]),
+                tail: u32::from_le_bytes([t0, t1, t2, t3]),
+                seq: u32::from_le_bytes([s0, s1, s2, s3]),
+            };
+            if !matches!(found, Some(other) if other.generation > pointers.generation) {
+                found = Some(pointers);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Persist the pointers in the slot not holding the current ones.
+    fn save(&mut self) -> Result<(), LogError<S::Error>> {
+        let generation = self.generation.wrapping_add(1);
+        let [m0, m1] = MAGIC;
+        let [g0, g1, g2, g3] = generation.to_le_bytes();
+        let [h0, h1, h2, h3] = self.head.to_le_bytes();
+        let [t0, t1, t2, t3] = self.tail.to_le_bytes();
+        let [s0, s1, s2, s3] = self.seq.to_le_bytes();
+        let data = [
+            m0, m1, g0, g1, g2, g3, h0, h1, h2, h3, t0, t1, t2, t3, s0, s1, s2, s3,
+        ];
+        let [c0, c1] = crc16(CRC16_INIT, &data).to_le_bytes();
+        let slot = generation % 2;
+        self.storage
+            .write(
+                slot * SLOT_LEN,
+                &[
+                    m0, m1, g0, g1, g2, g3, h0, h1, h2, h3, t0, t1, t2, t3, s0, s1, s2, s3, c0, c1,
+                ],
+            )
+            .map_err(LogError::Storage)?;
+        self.generation = generation;
+        self.saved_seq = self.seq;
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::{copy, split, split_mut};
+use crate::{SeedError, SeedStore};
+use embedded_storage::Storage;
+
+/// Length of the seed in bytes.
+const SEED_LEN: usize = 32;
+/// Size of a slot: seed, counter and checksum.
+const SLOT_LEN: usize = SEED_LEN + 6;
+
+/// Valid copy of the seed
+struct SeedCopy {
+    /// Slot holding the copy
+    slot: u32,
+    /// Stored seed
+    seed: [u8; SEED_LEN],
+    /// Number of seeds returned or rotated
+    counter: u32,
+}
+
+impl<S> SeedStore<S> {
+    /// Create a new instance keeping two copies of the seed at the start of the storage.
+    pub fn new(storage: S) -> Self {
+        SeedStore { storage }
+    }
+
+    /// Destroy the seed store, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S> SeedStore<S>
+where
+    S: Storage,
+{
+    /// Return a seed which is different at each call, also across reboots.
+    ///
+    /// The counter is incremented and persisted before the seed is returned, so a seed is
+    /// never returned twice even if the power is lost. The returned seed is the stored
+    /// seed with the counter XORed into its last bytes, so it should be fed to an RNG which
+    /// diffuses its seed like ChaCha. Returns `SeedError::NotInitialized` if no seed was
+    /// stored with [`rotate()`](Self::rotate).
+    pub fn next_seed(&mut self) -> Result<[u8; SEED_LEN], SeedError<S::Error>> {
+        let SeedCopy {
+            slot,
+            mut seed,
+            counter,
+        } = self.current()?.ok_or(SeedError::NotInitialized)?;
+        let counter = counter.wrapping_add(1);
+        self.write_slot(1 - slot, &seed, counter)?;
+        let (_, last) = split_mut(&mut seed, SEED_LEN - 4);
+        for (byte, counter) in last.iter_mut().zip(counter.to_le_bytes()) {
+            *byte ^= counter;
+        }
+        Ok(seed)
+    }
+
+    /// Store a new seed, e.g. from the output of the RNG or a hardware noise source.
+    ///
+    /// Rotating the seed regularly means that knowing a stored seed does not reveal the
+    /// future seeds.
+    pub fn rotate(&mut self, seed: &[u8; SEED_LEN]) -> Result<(), SeedError<S::Error>> {
+        let (slot, counter) = match self.current()? {
+            Some(copy) => (1 - copy.slot, copy.counter.wrapping_add(1)),
+            None => (0, 0),
+        };
+        self.write_slot(slot, seed, counter)
+    }
+
+    /// Return the newest valid copy.
+    fn current(&mut self) -> Result<Option<SeedCopy>, SeedError<S::Error>> {
+        let first = self.read_slot(0)?;
+        let second = self.read_slot(1)?;
+        Ok(match (first, second) {
+            // the counters are compared with wrapping arithmetic to handle the rollover
+            (Some(a), Some(b)) if (b.counter.wrapping_sub(a.counter) as i32) > 0 => Some(b),
+            (Some(a), _) => Some(a),
+            (None, b) => b,
+        })
+    }
+
+    fn read_slot(&mut self, slot: u32) -> Result<Option<SeedCopy>, SeedError<S::Error>> {
+        let mut raw = [0; SLOT_LEN];
+        self.storage
+            .read(slot * SLOT_LEN as u32, &mut raw)
+            .map_err(SeedError::Storage)?;
+        let (data, crc) = split(&raw, SLOT_LEN - 2);
+        if crc16(CRC16_INIT, data).to_le_bytes() != crc {
+            return Ok(None);
+        }
+        let (seed_bytes, counter_bytes) = split(data, SEED_LEN);
+        let mut seed = [0; SEED_LEN];
+        copy(&mut seed, seed_bytes);
+        let mut counter = [0; 4];
+        copy(&mut counter, counter_bytes);
+        Ok(Some(SeedCopy {
+            slot,
+            seed,
+            counter: u32::from_le_bytes(counter),
+        }))
+    }
+
+    fn write_slot(
+        &mut self,
+        slot: u32,
+        seed: &[u8; SEED_LEN],
+        counter: u32,
+    ) -> Result<(), SeedError<S::Error>> {
+        let mut raw = [0; SLOT_LEN];
+        let (data, crc) = split_mut(&mut raw, SLOT_LEN - 2);
+        let (seed_bytes, counter_bytes) = split_mut(data, SEED_LEN);
+        copy(seed_bytes, seed);
+        copy(counter_bytes, &counter.to_le_bytes());
+        copy(crc, &crc16(CRC16_INIT, data).to_le_bytes());
+        self.storage
+            .write(slot * SLOT_LEN as u32, &raw)
+            .map_err(SeedError::Storage)
+    }
+}
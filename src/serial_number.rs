@@ -1,37 +1,205 @@
 use crate::{
     addr_size::{OneByte, TwoBytes},
-    unique_serial, Eeprom24x, Error,
+    eeprom24x::build_payload,
+    unique_serial, Eeprom24x, Error, Eui48, Eui64, Irreversible,
 };
-use embedded_hal::i2c::I2c;
+use embedded_hal::i2c::{Error as I2cError, ErrorKind, I2c, NoAcknowledgeSource};
 
 /// Determine the peripheral address for accessing the secure region
 /// of 24CS devices.
 fn secure_region_addr(address_bits: u8, base_addr: u8) -> u8 {
     match address_bits {
-        7 | 8 | 12 | 13 => 0b101_1000 | (base_addr & 0b111), // CS01,CS02, CS32, CS64
-        9 => 0b101_1000 | (base_addr & 0b110),               // CS04
-        10 => 0b101_1000 | (base_addr & 0b100),              // CS08
-        11 => 0b101_1000,                                    // CS16
-        _ => unreachable!(),
+        9 => 0b101_1000 | (base_addr & 0b110),  // CS04
+        10 => 0b101_1000 | (base_addr & 0b100), // CS08
+        11 => 0b101_1000,                       // CS16
+        _ => 0b101_1000 | (base_addr & 0b111),  // CS01,CS02, CS32, CS64
     }
 }
 
+impl<I2C, PS, AS, SN> Eeprom24x<I2C, PS, AS, SN> {
+    /// Determine the peripheral address for accessing the secure region, considering
+    /// a custom address if set.
+    fn secure_region_device_address(&self) -> u8 {
+        self.secure_region_address
+            .unwrap_or_else(|| secure_region_addr(self.address_bits, self.address.addr()))
+    }
+}
+
+macro_rules! impl_secure_region_address {
+    ( $( $SN:ident ),* ) => {
+        $(
+        impl<I2C, PS, AS> Eeprom24x<I2C, PS, AS, unique_serial::$SN> {
+            /// Set a custom I²C device address for accessing the secure region.
+            ///
+            /// By default, the address is derived from the device address as documented for
+            /// the device, i.e. `0b101_1xxx`. This allows using compatible devices or boards
+            /// where this does not apply. Pass `None` to restore the default.
+            pub fn set_secure_region_address(&mut self, address: Option<u8>) {
+                self.secure_region_address = address;
+            }
+        }
+        )*
+    };
+}
+impl_secure_region_address!(Yes, Csw);
+
+/// Size in bytes of the secure region of 24CS devices.
+const CS_SECURE_REGION_SIZE: usize = 16;
+/// Size in bytes of the security register of 24CSW devices.
+const CSW_SECURE_REGION_SIZE: usize = 32;
+
+/// Check that a read of `len` bytes starting at `offset` fits into a secure region.
+fn check_secure_region_access<E>(offset: u8, len: usize, size: usize) -> Result<(), Error<E>> {
+    if usize::from(offset) >= size {
+        return Err(Error::InvalidAddr {
+            address: offset.into(),
+            capacity: size,
+        });
+    }
+    if usize::from(offset) + len > size {
+        return Err(Error::CapacityExceeded {
+            requested: len,
+            allowed: size - usize::from(offset),
+        });
+    }
+    Ok(())
+}
+
 /// Methods for interacting with the factory-programmed unique serial number
 /// for devices with one byte addresses. e.g. 24CSx01, 24CSx02,24CSx04, 24CSx08,
 /// and 24CSx16.
 impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::Yes>
 where
     I2C: I2c<Error = E>,
+    E: I2cError,
 {
     /// Read the 128-bit unique serial number.
     pub fn read_unique_serial(&mut self) -> Result<[u8; 16], Error<E>> {
-        let addr = secure_region_addr(self.address_bits, self.address.addr());
         let mut serial_bytes = [0u8; 16];
-        self.i2c
-            .write_read(addr, &[0x80], &mut serial_bytes)
-            .map_err(Error::I2C)?;
+        self.read_security_region(0, &mut serial_bytes)?;
         Ok(serial_bytes)
     }
+
+    /// Read the 16-byte secure region starting at `offset` within it.
+    ///
+    /// Returns `Error::InvalidAddr` if the offset is out of range and
+    /// `Error::CapacityExceeded` if the data would extend beyond the secure region.
+    pub fn read_security_region(&mut self, offset: u8, data: &mut [u8]) -> Result<(), Error<E>> {
+        check_secure_region_access(offset, data.len(), CS_SECURE_REGION_SIZE)?;
+        let addr = self.secure_region_device_address();
+        let result = self.i2c.write_read(addr, &[0x80 + offset], data);
+        self.finish_access(result, false)
+    }
+}
+
+/// Offset of the user-programmable part of the security register of 24CSW devices.
+const USER_SECURITY_REGISTER_OFFSET: u8 = 0x10;
+/// Size in bytes of the user-programmable part of the security register of 24CSW devices.
+const USER_SECURITY_REGISTER_SIZE: usize = 16;
+/// Address byte of the security register lock command of 24CSW devices.
+const LOCK_SECURITY_REGISTER: u8 = 0x60;
+
+/// Determine the peripheral address for the lock commands of 24CSW devices.
+fn lock_control_addr(secure_region_addr: u8) -> u8 {
+    0b011_0000 | (secure_region_addr & 0b111)
+}
+
+/// Methods for interacting with the security register of 24CSW devices.
+/// e.g. 24CSWx01, 24CSWx02, 24CSWx04, 24CSWx08 and 24CSWx16.
+///
+/// The security register contains the 128-bit unique serial number followed by 16
+/// user-programmable bytes, which can be permanently locked.
+impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::Csw>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Read the 128-bit unique serial number.
+    pub fn read_unique_serial(&mut self) -> Result<[u8; 16], Error<E>> {
+        let mut serial_bytes = [0u8; 16];
+        self.read_security_region(0, &mut serial_bytes)?;
+        Ok(serial_bytes)
+    }
+
+    /// Read the 32-byte security register starting at `offset` within it.
+    ///
+    /// This includes the unique serial number and the user-programmable bytes.
+    /// Returns `Error::InvalidAddr` if the offset is out of range and
+    /// `Error::CapacityExceeded` if the data would extend beyond the security register.
+    pub fn read_security_region(&mut self, offset: u8, data: &mut [u8]) -> Result<(), Error<E>> {
+        check_secure_region_access(offset, data.len(), CSW_SECURE_REGION_SIZE)?;
+        let addr = self.secure_region_device_address();
+        let result = self.i2c.write_read(addr, &[offset], data);
+        self.finish_access(result, false)
+    }
+
+    /// Write to the 16 user-programmable bytes of the security register starting at
+    /// `offset` within them.
+    ///
+    /// Returns `Error::InvalidAddr` if the offset is out of range and
+    /// `Error::CapacityExceeded` if the data does not fit into the user-programmable bytes.
+    /// The writes are ignored by the device once the security register has been locked.
+    ///
+    /// After writing, the EEPROM enters an internally-timed write cycle
+    /// to the nonvolatile memory.
+    /// During this time all inputs are disabled and the EEPROM will not
+    /// respond until the write is complete.
+    pub fn write_security_register(&mut self, offset: u8, data: &[u8]) -> Result<(), Error<E>> {
+        if usize::from(offset) >= USER_SECURITY_REGISTER_SIZE {
+            return Err(Error::InvalidAddr {
+                address: offset.into(),
+                capacity: USER_SECURITY_REGISTER_SIZE,
+            });
+        }
+        let allowed = USER_SECURITY_REGISTER_SIZE - usize::from(offset);
+        if data.len() > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: data.len(),
+                allowed,
+            });
+        }
+        if data.is_empty() {
+            return Ok(());
+        }
+        let addr = self.secure_region_device_address();
+        let mut buffer = [0; 1 + USER_SECURITY_REGISTER_SIZE];
+        let payload = build_payload(&mut buffer, &[USER_SECURITY_REGISTER_OFFSET + offset], data)?;
+        let result = self.i2c.write(addr, payload);
+        self.finish_access(result, true)
+    }
+
+    /// Permanently lock the user-programmable bytes of the security register.
+    ///
+    /// **This cannot be undone.** Afterwards, the security register is read-only.
+    /// The operation must be confirmed with an [`Irreversible`] token.
+    ///
+    /// After locking, the EEPROM enters an internally-timed write cycle.
+    pub fn lock_security_register(&mut self, confirmation: Irreversible) -> Result<(), Error<E>> {
+        let Irreversible(()) = confirmation;
+        let addr = lock_control_addr(self.secure_region_device_address());
+        let result = self.i2c.write(addr, &[LOCK_SECURITY_REGISTER, 0]);
+        self.finish_access(result, true)
+    }
+}
+
+impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::Csw>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Check whether the security register has been locked.
+    ///
+    /// The lock command address byte is sent to the lock control address without any data
+    /// byte, so nothing is written. The device acknowledges it only while the security
+    /// register is unlocked.
+    pub fn is_security_register_locked(&mut self) -> Result<bool, Error<E>> {
+        let addr = lock_control_addr(self.secure_region_device_address());
+        match self.i2c.write(addr, &[LOCK_SECURITY_REGISTER]) {
+            Ok(()) => Ok(false),
+            Err(e) if e.kind() == ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data) => Ok(true),
+            Err(e) => Err(Error::from_bus(e, self.write_pending)),
+        }
+    }
 }
 
 /// Methods for interacting with the factory-programmed unique serial number
@@ -39,14 +207,171 @@ where
 impl<I2C, PS, E> Eeprom24x<I2C, PS, TwoBytes, unique_serial::Yes>
 where
     I2C: I2c<Error = E>,
+    E: I2cError,
 {
     /// Read the 128-bit unique serial number.
     pub fn read_unique_serial(&mut self) -> Result<[u8; 16], Error<E>> {
-        let secure_region_addr = 0b101_1000 | (self.address.addr() & 0b111);
         let mut serial_bytes = [0u8; 16];
-        self.i2c
-            .write_read(secure_region_addr, &[0x08, 0x0], &mut serial_bytes)
-            .map_err(Error::I2C)?;
+        self.read_security_region(0, &mut serial_bytes)?;
         Ok(serial_bytes)
     }
+
+    /// Read the 16-byte secure region starting at `offset` within it.
+    ///
+    /// Returns `Error::InvalidAddr` if the offset is out of range and
+    /// `Error::CapacityExceeded` if the data would extend beyond the secure region.
+    pub fn read_security_region(&mut self, offset: u8, data: &mut [u8]) -> Result<(), Error<E>> {
+        check_secure_region_access(offset, data.len(), CS_SECURE_REGION_SIZE)?;
+        let addr = self.secure_region_device_address();
+        let result = self.i2c.write_read(addr, &[0x08, offset], data);
+        self.finish_access(result, false)
+    }
+}
+
+/// Determine the peripheral address for accessing the extended memory block
+/// of 24MAC devices.
+fn extended_block_addr(base_addr: u8) -> u8 {
+    0b101_1000 | (base_addr & 0b111)
+}
+
+impl<I2C, PS, SN, E> Eeprom24x<I2C, PS, OneByte, SN>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Read from the extended memory block of 24MAC devices.
+    fn read_extended_block(&mut self, address: u8, data: &mut [u8]) -> Result<(), Error<E>> {
+        let result =
+            self.i2c
+                .write_read(extended_block_addr(self.address.addr()), &[address], data);
+        self.finish_access(result, false)
+    }
+}
+
+macro_rules! impl_mac_serial {
+    ( $( $SN:ident ),* ) => {
+        $(
+        /// Methods for interacting with the factory-programmed unique serial number
+        /// in the extended memory block of 24MAC devices.
+        impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::$SN>
+        where
+            I2C: I2c<Error = E>,
+            E: I2cError,
+        {
+            /// Read the 128-bit unique serial number.
+            pub fn read_unique_serial(&mut self) -> Result<[u8; 16], Error<E>> {
+                let mut serial_bytes = [0u8; 16];
+                self.read_extended_block(0x80, &mut serial_bytes)?;
+                Ok(serial_bytes)
+            }
+        }
+        )*
+    };
+}
+impl_mac_serial!(Mac402, Mac602);
+
+/// Methods for reading the factory-programmed EUI-48 of the AT24MAC402.
+impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::Mac402>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Read the factory-programmed EUI-48, e.g. for use as an Ethernet MAC address.
+    pub fn read_eui48(&mut self) -> Result<[u8; 6], Error<E>> {
+        let mut eui = [0u8; 6];
+        self.read_extended_block(0x9A, &mut eui)?;
+        Ok(eui)
+    }
+}
+
+impl<I2C, PS, E> Eui48 for Eeprom24x<I2C, PS, OneByte, unique_serial::Mac402>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    type Error = E;
+
+    fn read_eui48(&mut self) -> Result<[u8; 6], Error<Self::Error>> {
+        self.read_eui48()
+    }
+}
+
+/// Methods for reading the factory-programmed EUI-64 of the AT24MAC602.
+impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::Mac602>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Read the factory-programmed EUI-64, e.g. for use by IEEE 802.15.4 network stacks.
+    pub fn read_eui64(&mut self) -> Result<[u8; 8], Error<E>> {
+        let mut eui = [0u8; 8];
+        self.read_extended_block(0x98, &mut eui)?;
+        Ok(eui)
+    }
+}
+
+impl<I2C, PS, E> Eui64 for Eeprom24x<I2C, PS, OneByte, unique_serial::Mac602>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    type Error = E;
+
+    fn read_eui64(&mut self) -> Result<[u8; 8], Error<Self::Error>> {
+        self.read_eui64()
+    }
+}
+
+/// Methods for reading the factory-programmed EUI-48 of Microchip devices.
+/// e.g. 24AA02E48 and 24AA025E48.
+impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::E48>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Read the factory-programmed EUI-48, e.g. for use as an Ethernet MAC address.
+    pub fn read_eui48(&mut self) -> Result<[u8; 6], Error<E>> {
+        let mut eui = [0u8; 6];
+        self.read_data(0xFA, &mut eui)?;
+        Ok(eui)
+    }
+}
+
+impl<I2C, PS, E> Eui48 for Eeprom24x<I2C, PS, OneByte, unique_serial::E48>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    type Error = E;
+
+    fn read_eui48(&mut self) -> Result<[u8; 6], Error<Self::Error>> {
+        self.read_eui48()
+    }
+}
+
+/// Methods for reading the factory-programmed EUI-64 of Microchip devices.
+/// e.g. 24AA02E64 and 24AA025E64.
+impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::E64>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    /// Read the factory-programmed EUI-64, e.g. for use by IEEE 802.15.4 network stacks.
+    pub fn read_eui64(&mut self) -> Result<[u8; 8], Error<E>> {
+        let mut eui = [0u8; 8];
+        self.read_data(0xF8, &mut eui)?;
+        Ok(eui)
+    }
+}
+
+impl<I2C, PS, E> Eui64 for Eeprom24x<I2C, PS, OneByte, unique_serial::E64>
+where
+    I2C: I2c<Error = E>,
+    E: I2cError,
+{
+    type Error = E;
+
+    fn read_eui64(&mut self) -> Result<[u8; 8], Error<Self::Error>> {
+        self.read_eui64()
+    }
 }
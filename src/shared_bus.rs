@@ -0,0 +1,61 @@
+use crate::{SharedBus, SharedI2c};
+use core::cell::RefCell;
+use embedded_hal::i2c::{ErrorType, I2c, Operation, SevenBitAddress};
+
+impl<I2C> SharedBus<I2C> {
+    /// Create a new shared bus from an I²C bus instance.
+    pub fn new(i2c: I2C) -> Self {
+        SharedBus {
+            bus: RefCell::new(i2c),
+        }
+    }
+
+    /// Acquire a handle to the bus which can be passed to a driver.
+    pub fn acquire(&self) -> SharedI2c<'_, I2C> {
+        SharedI2c { bus: &self.bus }
+    }
+
+    /// Destroy the shared bus, return the I²C bus instance.
+    pub fn into_inner(self) -> I2C {
+        self.bus.into_inner()
+    }
+}
+
+impl<I2C> ErrorType for SharedI2c<'_, I2C>
+where
+    I2C: ErrorType,
+{
+    type Error = I2C::Error;
+}
+
+// The bus cannot be borrowed twice as the handles are not `Sync` and each
+// borrow is released before returning.
+impl<I2C> I2c<SevenBitAddress> for SharedI2c<'_, I2C>
+where
+    I2C: I2c<SevenBitAddress>,
+{
+    fn read(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().read(address, read)
+    }
+
+    fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().write(address, write)
+    }
+
+    fn write_read(
+        &mut self,
+        address: SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().write_read(address, write, read)
+    }
+
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().transaction(address, operations)
+    }
+}
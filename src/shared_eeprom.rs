@@ -0,0 +1,29 @@
+use crate::SharedEeprom;
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+impl<T> SharedEeprom<T> {
+    /// Create a new instance wrapping a device.
+    ///
+    /// This can be used to initialize a `static`.
+    pub const fn new(eeprom: T) -> Self {
+        SharedEeprom {
+            eeprom: Mutex::new(RefCell::new(eeprom)),
+        }
+    }
+
+    /// Run a closure with exclusive access to the device within a critical section.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within the closure passed to another `lock()` call
+    /// on the same instance.
+    pub fn lock<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.eeprom.borrow_ref_mut(cs)))
+    }
+
+    /// Destroy the wrapper, return the device.
+    pub fn into_inner(self) -> T {
+        self.eeprom.into_inner().into_inner()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::SlaveAddr;
+use core::fmt;
 
 impl Default for SlaveAddr {
     /// Default slave address
@@ -7,14 +8,48 @@ impl Default for SlaveAddr {
     }
 }
 
+impl fmt::Display for SlaveAddr {
+    /// Format the resolved 7-bit slave address, e.g. `0x50`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x}", self.addr())
+    }
+}
+
 impl SlaveAddr {
-    /// Get slave address as u8
-    pub(crate) fn addr(self) -> u8 {
+    /// Create a slave address from the A2, A1 and A0 pin strapping given as a 3-bit
+    /// integer, as usually documented in schematics.
+    ///
+    /// e.g. `SlaveAddr::from_pins(0b101)` is equivalent to
+    /// `SlaveAddr::Alternative(true, false, true)`. Higher bits are ignored.
+    pub fn from_pins(pins: u8) -> Self {
+        SlaveAddr::Alternative(pins & 0b100 != 0, pins & 0b010 != 0, pins & 0b001 != 0)
+    }
+
+    /// Get the 7-bit slave address as u8
+    ///
+    /// Note that devices using some of the device address bits for memory addressing
+    /// (e.g. 24x16) are accessed through further addresses depending on the memory address.
+    /// See `Eeprom24x::device_address()`.
+    pub fn addr(self) -> u8 {
         match self {
             SlaveAddr::Default => 0b101_0000,
             SlaveAddr::Alternative(a2, a1, a0) => {
                 SlaveAddr::default().addr() | ((a2 as u8) << 2) | ((a1 as u8) << 1) | a0 as u8
             }
+            SlaveAddr::Custom(addr) => addr,
+        }
+    }
+
+    /// Check whether the slave address can be used with a device with the given addressing.
+    ///
+    /// Custom addresses must be 7-bit addresses and must not set any of the device address
+    /// bits used for memory addressing. See `devaddr()` for the parameters.
+    pub(crate) fn is_valid(self, num_address_bits: u8, shift: u8) -> bool {
+        match self {
+            SlaveAddr::Custom(addr) => {
+                addr <= 0x7F && (u32::from(addr) & memmask(num_address_bits, shift)) == 0
+            }
+            _ => true,
         }
     }
 
@@ -27,16 +62,20 @@ impl SlaveAddr {
     /// number of address bits that are moved into the device address, but this overflows in many
     /// cases and requires special handling for num_address_bits < shift.
     pub(crate) fn devaddr(self, memory_address: u32, num_address_bits: u8, shift: u8) -> u8 {
-        // the part in parentheses creates num_address_bits ones; after right-shifting, 0..3 ones
-        // remain; the calculations have to be done in u32 to prevent overflow
-        let memmask: u32 = ((1 << num_address_bits) - 1) >> shift;
         // the inverse is the part of the device address that we keep
-        let devmask = !memmask as u8;
+        let devmask = !memmask(num_address_bits, shift) as u8;
         let hi_addr_bits = memory_address >> shift;
         (self.addr() & devmask) | hi_addr_bits as u8
     }
 }
 
+/// Mask of the device address bits used for memory addressing.
+fn memmask(num_address_bits: u8, shift: u8) -> u32 {
+    // the part in parentheses creates num_address_bits ones; after right-shifting, 0..3 ones
+    // remain; the calculations have to be done in u32 to prevent overflow
+    ((1 << num_address_bits) - 1) >> shift
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,6 +106,43 @@ mod tests {
         assert_eq!(0b101_0111, SlaveAddr::Alternative(true, true, true).addr());
     }
 
+    #[test]
+    fn can_create_from_pins() {
+        assert_eq!(
+            SlaveAddr::Alternative(false, false, false),
+            SlaveAddr::from_pins(0)
+        );
+        assert_eq!(
+            SlaveAddr::Alternative(true, false, true),
+            SlaveAddr::from_pins(0b101)
+        );
+        assert_eq!(
+            SlaveAddr::Alternative(false, true, false),
+            SlaveAddr::from_pins(0b1010)
+        );
+        assert_eq!(0b101_0111, SlaveAddr::from_pins(7).addr());
+    }
+
+    #[test]
+    fn can_use_custom_address() {
+        assert_eq!(0b101_1000, SlaveAddr::Custom(0b101_1000).addr());
+        assert_eq!(
+            0b011_0101,
+            SlaveAddr::Custom(0b011_0100).devaddr(0b1_0000_0000, 9, 8)
+        );
+    }
+
+    #[test]
+    fn validates_custom_address() {
+        assert!(SlaveAddr::Custom(0x7F).is_valid(8, 8));
+        assert!(!SlaveAddr::Custom(0x80).is_valid(8, 8));
+        assert!(SlaveAddr::Custom(0b011_0100).is_valid(10, 8));
+        assert!(!SlaveAddr::Custom(0b011_0101).is_valid(10, 8));
+        assert!(!SlaveAddr::Custom(0b011_0110).is_valid(10, 8));
+        assert!(SlaveAddr::Custom(0b011_0110).is_valid(17, 16));
+        assert!(SlaveAddr::Alternative(true, true, true).is_valid(11, 8));
+    }
+
     #[test]
     fn assemble_devaddr() {
         assert_eq!(0b101_0001, SlaveAddr::Default.devaddr(0b1_1111_1111, 9, 8));
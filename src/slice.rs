@@ -0,0 +1,24 @@
+//! Slice helpers which cannot panic
+//!
+//! The parts returned are shorter than requested only if the slice is. Loops advance by the
+//! length of the parts they actually got, so that a logic error never skips data.
+
+/// Split a slice after its first `mid` elements, or at its end if it is shorter.
+pub(crate) fn split<T>(slice: &[T], mid: usize) -> (&[T], &[T]) {
+    slice.split_at(mid.min(slice.len()))
+}
+
+/// Split a mutable slice after its first `mid` elements, or at its end if it is shorter.
+pub(crate) fn split_mut<T>(slice: &mut [T], mid: usize) -> (&mut [T], &mut [T]) {
+    let mid = mid.min(slice.len());
+    slice.split_at_mut(mid)
+}
+
+/// Copy as many elements as fit from `src` to the start of `dst` and return their number.
+pub(crate) fn copy<T: Copy>(dst: &mut [T], src: &[T]) -> usize {
+    let len = dst.len().min(src.len());
+    let (dst, _) = split_mut(dst, len);
+    let (src, _) = split(src, len);
+    dst.copy_from_slice(src);
+    len
+}
@@ -0,0 +1,274 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::split_mut;
+use crate::{FsError, Slot, SlotFs};
+use embedded_storage::Storage;
+
+/// Marker at the start of the header.
+const MAGIC: [u8; 2] = *b"SF";
+/// Size of the header: magic and number of directory entries.
+const HEADER_LEN: u32 = 4;
+/// Maximum length of a slot name.
+const NAME_LEN: usize = 8;
+/// Size of a directory entry: name, offset, capacity, length and checksum.
+const ENTRY_LEN: u32 = 18;
+/// Size of the chunks in which the slot data is read to compute its checksum.
+const CHUNK_LEN: usize = 16;
+
+/// Directory entry
+#[derive(Clone, Copy, Default)]
+struct Entry {
+    name: [u8; NAME_LEN],
+    offset: u32,
+    capacity: u16,
+    len: u16,
+    crc: u16,
+}
+
+impl Entry {
+    fn is_used(&self) -> bool {
+        self.name != [0; NAME_LEN] && self.name != [0xFF; NAME_LEN]
+    }
+
+    fn end(&self) -> u32 {
+        self.offset + u32::from(self.capacity)
+    }
+
+    fn to_bytes(self) -> [u8; ENTRY_LEN as usize] {
+        let [n0, n1, n2, n3, n4, n5, n6, n7] = self.name;
+        let [o0, o1, o2, o3] = self.offset.to_le_bytes();
+        let [c0, c1] = self.capacity.to_le_bytes();
+        let [l0, l1] = self.len.to_le_bytes();
+        let [r0, r1] = self.crc.to_le_bytes();
+        [
+            n0, n1, n2, n3, n4, n5, n6, n7, o0, o1, o2, o3, c0, c1, l0, l1, r0, r1,
+        ]
+    }
+
+    fn from_bytes(bytes: [u8; ENTRY_LEN as usize]) -> Self {
+        let [n0, n1, n2, n3, n4, n5, n6, n7, o0, o1, o2, o3, c0, c1, l0, l1, r0, r1] = bytes;
+        Entry {
+            name: [n0, n1, n2, n3, n4, n5, n6, n7],
+            offset: u32::from_le_bytes([o0, o1, o2, o3]),
+            capacity: u16::from_le_bytes([c0, c1]),
+            len: u16::from_le_bytes([l0, l1]),
+            crc: u16::from_le_bytes([r0, r1]),
+        }
+    }
+}
+
+impl Slot {
+    /// Return the maximum length of the slot data in bytes.
+    pub fn capacity(&self) -> usize {
+        usize::from(self.capacity)
+    }
+}
+
+impl<S, const N: usize> SlotFs<S, N> {
+    /// Destroy the filesystem, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+}
+
+impl<S, const N: usize> SlotFs<S, N>
+where
+    S: Storage,
+{
+    /// Create an empty filesystem with a directory of `N` entries in the storage.
+    pub fn format(storage: S) -> Result<Self, FsError<S::Error>> {
+        let mut fs = SlotFs { storage };
+        let entries = u8::try_from(N).map_err(|_| FsError::DirectoryFull)?;
+        if fs.data_start() as usize > fs.storage.capacity() {
+            return Err(FsError::NoSpace);
+        }
+        let [m0, m1] = MAGIC;
+        fs.storage
+            .write(0, &[m0, m1, entries, 0])
+            .map_err(FsError::Storage)?;
+        for index in 0..N {
+            fs.write_entry(index, Entry::default())?;
+        }
+        Ok(fs)
+    }
+
+    /// Open a filesystem previously created with [`format()`](Self::format).
+    ///
+    /// Returns `FsError::NotFormatted` if the storage does not contain a filesystem with
+    /// `N` directory entries.
+    pub fn mount(mut storage: S) -> Result<Self, FsError<S::Error>> {
+        let mut header = [0; HEADER_LEN as usize];
+        storage.read(0, &mut header).map_err(FsError::Storage)?;
+        let [m0, m1, entries, _] = header;
+        if [m0, m1] != MAGIC || usize::from(entries) != N {
+            return Err(FsError::NotFormatted);
+        }
+        Ok(SlotFs { storage })
+    }
+
+    /// Create a slot of `capacity` bytes.
+    ///
+    /// Names are up to 8 bytes long. The space is allocated in the first gap between the
+    /// existing slots which is large enough.
+    pub fn create(&mut self, name: &[u8], capacity: usize) -> Result<Slot, FsError<S::Error>> {
+        let name = to_name(name)?;
+        let capacity = u16::try_from(capacity).map_err(|_| FsError::NoSpace)?;
+        let entries = self.read_entries()?;
+        if entries
+            .iter()
+            .any(|entry| entry.is_used() && entry.name == name)
+        {
+            return Err(FsError::AlreadyExists);
+        }
+        let index = entries
+            .iter()
+            .position(|entry| !entry.is_used())
+            .ok_or(FsError::DirectoryFull)?;
+
+        // candidates are the start of the data area and the end of each slot
+        let fits = |offset: u32| {
+            let end = offset + u32::from(capacity);
+            end as usize <= self.storage.capacity()
+                && entries
+                    .iter()
+                    .all(|entry| !entry.is_used() || end <= entry.offset || offset >= entry.end())
+        };
+        let offset = core::iter::once(self.data_start())
+            .chain(entries.iter().filter(|e| e.is_used()).map(Entry::end))
+            .filter(|offset| fits(*offset))
+            .min()
+            .ok_or(FsError::NoSpace)?;
+
+        let entry = Entry {
+            name,
+            offset,
+            capacity,
+            len: 0,
+            crc: CRC16_INIT,
+        };
+        self.write_entry(index, entry)?;
+        Ok(Slot {
+            index: index as u8,
+            capacity,
+        })
+    }
+
+    /// Open an existing slot.
+    pub fn open(&mut self, name: &[u8]) -> Result<Slot, FsError<S::Error>> {
+        let name = to_name(name)?;
+        let entries = self.read_entries()?;
+        let (index, entry) = entries
+            .iter()
+            .enumerate()
+            .find(|(_, entry)| entry.is_used() && entry.name == name)
+            .ok_or(FsError::NotFound)?;
+        Ok(Slot {
+            index: index as u8,
+            capacity: entry.capacity,
+        })
+    }
+
+    /// Delete a slot and release its space.
+    pub fn delete(&mut self, name: &[u8]) -> Result<(), FsError<S::Error>> {
+        let slot = self.open(name)?;
+        self.write_entry(usize::from(slot.index), Entry::default())
+    }
+
+    /// Read the data of a slot into the buffer and return its length.
+    ///
+    /// Returns `FsError::Corrupted` if the data does not match its checksum.
+    pub fn read(&mut self, slot: &Slot, buffer: &mut [u8]) -> Result<usize, FsError<S::Error>> {
+        let entry = self.read_entry(usize::from(slot.index))?;
+        let len = usize::from(entry.len);
+        let data = buffer
+            .get_mut(..len)
+            .ok_or(FsError::BufferTooSmall { required: len })?;
+        self.storage
+            .read(entry.offset, data)
+            .map_err(FsError::Storage)?;
+        if crc16(CRC16_INIT, data) != entry.crc {
+            return Err(FsError::Corrupted);
+        }
+        Ok(len)
+    }
+
+    /// Replace the data of a slot.
+    ///
+    /// Returns `FsError::TooLarge` if the data is longer than the slot capacity.
+    pub fn write(&mut self, slot: &Slot, data: &[u8]) -> Result<(), FsError<S::Error>> {
+        if data.len() > slot.capacity() {
+            return Err(FsError::TooLarge);
+        }
+        let mut entry = self.read_entry(usize::from(slot.index))?;
+        self.storage
+            .write(entry.offset, data)
+            .map_err(FsError::Storage)?;
+        entry.len = data.len() as u16;
+        entry.crc = crc16(CRC16_INIT, data);
+        self.write_entry(usize::from(slot.index), entry)
+    }
+
+    /// Check the data of all the slots against their checksum.
+    ///
+    /// Returns the number of slots whose data is corrupted.
+    pub fn check(&mut self) -> Result<usize, FsError<S::Error>> {
+        let entries = self.read_entries()?;
+        let mut corrupted = 0;
+        for entry in entries.iter().filter(|entry| entry.is_used()) {
+            let mut crc = CRC16_INIT;
+            let mut chunk = [0; CHUNK_LEN];
+            let mut position = entry.offset;
+            let mut left = usize::from(entry.len);
+            while left > 0 {
+                let (data, _) = split_mut(&mut chunk, left);
+                let len = data.len();
+                self.storage
+                    .read(position, data)
+                    .map_err(FsError::Storage)?;
+                crc = crc16(crc, data);
+                position += len as u32;
+                left -= len;
+            }
+            if crc != entry.crc {
+                corrupted += 1;
+            }
+        }
+        Ok(corrupted)
+    }
+
+    fn data_start(&self) -> u32 {
+        HEADER_LEN + N as u32 * ENTRY_LEN
+    }
+
+    fn read_entry(&mut self, index: usize) -> Result<Entry, FsError<S::Error>> {
+        let mut bytes = [0; ENTRY_LEN as usize];
+        self.storage
+            .read(HEADER_LEN + index as u32 * ENTRY_LEN, &mut bytes)
+            .map_err(FsError::Storage)?;
+        Ok(Entry::from_bytes(bytes))
+    }
+
+    fn read_entries(&mut self) -> Result<[Entry; N], FsError<S::Error>> {
+        let mut entries = [Entry::default(); N];
+        for (index, entry) in entries.iter_mut().enumerate() {
+            *entry = self.read_entry(index)?;
+        }
+        Ok(entries)
+    }
+
+    fn write_entry(&mut self, index: usize, entry: Entry) -> Result<(), FsError<S::Error>> {
+        self.storage
+            .write(HEADER_LEN + index as u32 * ENTRY_LEN, &entry.to_bytes())
+            .map_err(FsError::Storage)
+    }
+}
+
+/// Pad a name with zeros.
+fn to_name<E>(name: &[u8]) -> Result<[u8; NAME_LEN], FsError<E>> {
+    let mut padded = [0; NAME_LEN];
+    let target = padded.get_mut(..name.len()).ok_or(FsError::InvalidName)?;
+    target.copy_from_slice(name);
+    if padded == [0; NAME_LEN] || padded == [0xFF; NAME_LEN] {
+        return Err(FsError::InvalidName);
+    }
+    Ok(padded)
+}
@@ -0,0 +1,121 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::slice::split;
+use crate::transfer::{dump, restore};
+use crate::{SnapshotError, TransferError};
+use embedded_storage::{ReadStorage, Storage};
+
+/// Marker at the start of a snapshot.
+const MAGIC: [u8; 4] = *b"E24S";
+/// Version of the snapshot format.
+const VERSION: u8 = 1;
+/// Size of the header: magic, version, capacity and page size.
+const HEADER_LEN: usize = 13;
+
+/// Write a snapshot of the whole storage to a callback.
+///
+/// The snapshot consists of a header with the geometry of the device (capacity and
+/// `page_size`), the data and a CRC over both. It can be written back to a device with the
+/// same geometry with [`restore_snapshot()`]. The data is read with [`dump()`], so only
+/// one chunk is kept in RAM.
+pub fn snapshot<S, F, C>(
+    storage: &mut S,
+    page_size: usize,
+    mut f: F,
+) -> Result<(), SnapshotError<S::Error, C>>
+where
+    S: ReadStorage,
+    F: FnMut(&[u8]) -> Result<(), C>,
+{
+    let header = header(storage.capacity(), page_size);
+    f(&header).map_err(SnapshotError::Callback)?;
+    let mut crc = crc16(CRC16_INIT, &header);
+    let range = 0..storage.capacity() as u32;
+    dump(storage, range, |data| {
+        crc = crc16(crc, data);
+        f(data)
+    })
+    .map_err(from_transfer)?;
+    f(&crc.to_le_bytes()).map_err(SnapshotError::Callback)
+}
+
+/// Write a snapshot taken with [`snapshot()`] pulled in chunks from a callback back to the
+/// storage.
+///
+/// The callback fills the buffer it is given and returns the number of bytes it put
+/// there, or 0 at the end of the source. The header is checked before anything is
+/// written: `SnapshotError::InvalidHeader` is returned if the source does not contain a
+/// snapshot and `SnapshotError::GeometryMismatch` if it was taken from a device with
+/// another capacity or `page_size`.
+///
+/// The data is written as it arrives with [`restore()`], so that only one chunk is kept in
+/// RAM. The CRC can thus only be checked at the end: if `SnapshotError::Truncated` or
+/// `SnapshotError::Corrupted` is returned, the contents of the storage are incomplete and
+/// the snapshot should be restored again.
+pub fn restore_snapshot<S, F, C>(
+    storage: &mut S,
+    page_size: usize,
+    mut f: F,
+) -> Result<(), SnapshotError<S::Error, C>>
+where
+    S: Storage,
+    F: FnMut(&mut [u8]) -> Result<usize, C>,
+{
+    let mut received = [0; HEADER_LEN];
+    pull(&mut f, &mut received)?;
+    let expected = header(storage.capacity(), page_size);
+    if received.get(..5) != expected.get(..5) {
+        return Err(SnapshotError::InvalidHeader);
+    }
+    if received != expected {
+        return Err(SnapshotError::GeometryMismatch);
+    }
+    let mut crc = crc16(CRC16_INIT, &received);
+    let range = 0..storage.capacity() as u32;
+    let written = restore(storage, range, |buffer| {
+        let len = f(buffer)?;
+        crc = crc16(crc, split(buffer, len).0);
+        Ok(len)
+    })
+    .map_err(from_transfer)?;
+    if written < storage.capacity() {
+        return Err(SnapshotError::Truncated);
+    }
+    let mut stored = [0; 2];
+    pull(&mut f, &mut stored)?;
+    if u16::from_le_bytes(stored) != crc {
+        return Err(SnapshotError::Corrupted);
+    }
+    Ok(())
+}
+
+fn header(capacity: usize, page_size: usize) -> [u8; HEADER_LEN] {
+    let [m0, m1, m2, m3] = MAGIC;
+    let [c0, c1, c2, c3] = (capacity as u32).to_le_bytes();
+    let [p0, p1, p2, p3] = (page_size as u32).to_le_bytes();
+    [m0, m1, m2, m3, VERSION, c0, c1, c2, c3, p0, p1, p2, p3]
+}
+
+/// Fill the buffer completely from the callback.
+fn pull<E, F, C>(f: &mut F, buffer: &mut [u8]) -> Result<(), SnapshotError<E, C>>
+where
+    F: FnMut(&mut [u8]) -> Result<usize, C>,
+{
+    let mut filled = 0;
+    while let Some(rest) = buffer.get_mut(filled..).filter(|rest| !rest.is_empty()) {
+        let len = f(rest).map_err(SnapshotError::Callback)?.min(rest.len());
+        if len == 0 {
+            return Err(SnapshotError::Truncated);
+        }
+        filled += len;
+    }
+    Ok(())
+}
+
+fn from_transfer<E, C>(error: TransferError<E, C>) -> SnapshotError<E, C> {
+    match error {
+        TransferError::Storage(e) => SnapshotError::Storage(e),
+        TransferError::Callback(e) => SnapshotError::Callback(e),
+        // the whole storage is transferred
+        TransferError::OutOfBounds => SnapshotError::GeometryMismatch,
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{addr_size::OneByte, unique_serial, Eeprom24x, Error, Irreversible};
+use embedded_hal::i2c::I2c;
+
+/// Device address of the set reversible software write protection (RSWP) command.
+const RSWP_ADDR: u8 = 0b011_0001;
+/// Device address of the clear reversible software write protection (CRSWP) command.
+const CRSWP_ADDR: u8 = 0b011_0011;
+/// Device address of the permanent software write protection (PSWP) command without the
+/// address pin bits.
+const PSWP_ADDR: u8 = 0b011_0000;
+
+/// Methods for interacting with the software write protection of SPD devices.
+/// e.g. 34x02.
+///
+/// The software write protection protects the lower half of the memory (`0x00..=0x7F`).
+/// The commands are sent to the control addresses `0x30..=0x37`.
+impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::Spd>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Set the reversible software write protection (RSWP).
+    ///
+    /// Note that the device only accepts this command while the A0 pin is driven at the
+    /// high voltage `VHV`, as described in the datasheet.
+    /// After this command, the EEPROM enters an internally-timed write cycle.
+    pub fn set_reversible_write_protection(&mut self) -> Result<(), Error<E>> {
+        self.send_write_protection_command(RSWP_ADDR)
+    }
+
+    /// Clear the reversible software write protection (CRSWP).
+    ///
+    /// Note that the device only accepts this command while the A0 pin is driven at the
+    /// high voltage `VHV`, as described in the datasheet.
+    /// After this command, the EEPROM enters an internally-timed write cycle.
+    pub fn clear_reversible_write_protection(&mut self) -> Result<(), Error<E>> {
+        self.send_write_protection_command(CRSWP_ADDR)
+    }
+
+    /// Set the permanent software write protection (PSWP).
+    ///
+    /// **This cannot be undone.** Afterwards, the lower half of the memory is read-only.
+    /// The operation must be confirmed with an [`Irreversible`] token.
+    ///
+    /// After this command, the EEPROM enters an internally-timed write cycle.
+    pub fn set_permanent_write_protection(
+        &mut self,
+        confirmation: Irreversible,
+    ) -> Result<(), Error<E>> {
+        let Irreversible(()) = confirmation;
+        let addr = PSWP_ADDR | (self.address.addr() & 0b111);
+        self.send_write_protection_command(addr)
+    }
+
+    fn send_write_protection_command(&mut self, addr: u8) -> Result<(), Error<E>> {
+        // the command requires a dummy memory address and data byte
+        let result = self.i2c.write(addr, &[0, 0]);
+        self.finish_access(result, true)
+    }
+}
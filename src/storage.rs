@@ -1,8 +1,8 @@
 use crate::{
-    eeprom24x::{MultiSizeAddr, PageWrite},
+    eeprom24x::{MultiSizeAddr, PageWrite, WriteCycle},
+    slice::split,
     Eeprom24x, Error, Storage,
 };
-use core::cmp::min;
 use embedded_hal::{delay::DelayNs, i2c::I2c};
 use embedded_storage::ReadStorage;
 
@@ -16,9 +16,14 @@ where
 {
     /// Create a new Storage instance wrapping the given Eeprom
     pub fn new(eeprom: Eeprom24x<I2C, PS, AS, SN>, delay: D) -> Self {
-        // When writing to the eeprom, we delay by 5 ms after each page
-        // before writing to the next page.
-        Storage { eeprom, delay }
+        // When writing to the eeprom, we delay by the write cycle time (5 ms) after
+        // each page before writing to the next page. Devices without write cycle
+        // (e.g. FRAMs) are not delayed.
+        Storage {
+            eeprom,
+            delay,
+            max_write_len: usize::MAX,
+        }
     }
 }
 
@@ -28,11 +33,21 @@ impl<I2C, PS, AS, SN, D> Storage<I2C, PS, AS, SN, D> {
     pub fn destroy(self) -> (I2C, D) {
         (self.eeprom.destroy(), self.delay)
     }
+
+    /// Set the maximum number of bytes written at once.
+    ///
+    /// By default, whole pages are written at once. A smaller length allows using I²C
+    /// peripherals which cannot transfer a whole page at once, e.g. due to a small transmit
+    /// FIFO, at the cost of more write cycles. A length of 0 is treated as 1.
+    pub fn set_max_write_len(&mut self, len: usize) {
+        self.max_write_len = len.max(1);
+    }
 }
 
 impl<I2C, E, PS, AS, SN, D> embedded_storage::ReadStorage for Storage<I2C, PS, AS, SN, D>
 where
     I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
     AS: MultiSizeAddr,
     D: DelayNs,
 {
@@ -43,36 +58,89 @@ where
     }
 
     fn capacity(&self) -> usize {
-        1 << self.eeprom.address_bits
+        self.eeprom.capacity()
     }
 }
 
 impl<I2C, E, PS, AS, SN, D> embedded_storage::Storage for Storage<I2C, PS, AS, SN, D>
 where
     I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
     AS: MultiSizeAddr,
+    PS: WriteCycle,
     Eeprom24x<I2C, PS, AS, SN>: PageWrite<E>,
     D: DelayNs,
 {
     fn write(&mut self, mut offset: u32, mut bytes: &[u8]) -> Result<(), Self::Error> {
-        if offset as usize + bytes.len() > self.capacity() {
-            return Err(Error::TooMuchData);
+        let allowed = self.capacity().saturating_sub(offset as usize);
+        if bytes.len() > allowed {
+            return Err(Error::CapacityExceeded {
+                requested: bytes.len(),
+                allowed,
+            });
         }
         let page_size = self.eeprom.page_size();
         while !bytes.is_empty() {
             let this_page_offset = offset as usize % page_size;
             let this_page_remaining = page_size - this_page_offset;
-            let chunk_size = min(bytes.len(), this_page_remaining);
-            self.eeprom.page_write(offset, &bytes[..chunk_size])?;
-            offset += chunk_size as u32;
-            bytes = &bytes[chunk_size..];
+            let (chunk, rest) = split(bytes, this_page_remaining.min(self.max_write_len));
+            self.eeprom.page_write(offset, chunk)?;
+            offset += chunk.len() as u32;
+            bytes = rest;
             // TODO At least ST's eeproms allow polling, i.e. trying the next i2c access which will
             // just be NACKed as long as the device is still busy. This could potentially speed up
             // the write process.
             // A (theoretically needless) delay after the last page write ensures that the user can
             // call Storage::write() again immediately.
-            self.delay.delay_ms(5);
+            // Devices without write cycle (e.g. FRAMs) can be accessed again immediately.
+            if PS::WRITE_CYCLE_MS > 0 {
+                self.delay.delay_ms(PS::WRITE_CYCLE_MS);
+            }
         }
         Ok(())
     }
 }
+
+#[cfg(feature = "zerocopy")]
+impl<I2C, E, PS, AS, SN, D> Storage<I2C, PS, AS, SN, D>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+    D: DelayNs,
+{
+    /// Read a plain-old-data value starting at an offset.
+    ///
+    /// The value is read in its in-memory representation, which `zerocopy` guarantees
+    /// to be valid for any content of the memory.
+    pub fn read_obj<T>(&mut self, offset: u32) -> Result<T, Error<E>>
+    where
+        T: zerocopy::FromBytes + zerocopy::AsBytes,
+    {
+        let mut value = T::new_zeroed();
+        self.read(offset, value.as_bytes_mut())?;
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<I2C, E, PS, AS, SN, D> Storage<I2C, PS, AS, SN, D>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+    AS: MultiSizeAddr,
+    PS: WriteCycle,
+    Eeprom24x<I2C, PS, AS, SN>: PageWrite<E>,
+    D: DelayNs,
+{
+    /// Write a plain-old-data value starting at an offset.
+    ///
+    /// The value is written in its in-memory representation, which `zerocopy` guarantees
+    /// to contain no padding bytes.
+    pub fn write_obj<T>(&mut self, offset: u32, value: &T) -> Result<(), Error<E>>
+    where
+        T: zerocopy::AsBytes,
+    {
+        embedded_storage::Storage::write(self, offset, value.as_bytes())
+    }
+}
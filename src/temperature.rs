@@ -0,0 +1,61 @@
+use crate::{addr_size::OneByte, unique_serial, Ee1004, Eeprom24x, Error};
+use embedded_hal::i2c::I2c;
+
+/// Base device address of JC42.4 temperature sensors.
+const TS_BASE_ADDR: u8 = 0b001_1000;
+/// Ambient temperature register.
+const TEMPERATURE_REGISTER: u8 = 0x05;
+
+/// Read the ambient temperature in °C from the JC42.4 temperature sensor with the same
+/// address pins as the EEPROM.
+fn read_temperature<I2C, E>(i2c: &mut I2C, eeprom_addr: u8) -> Result<f32, Error<E>>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    let mut data = [0; 2];
+    i2c.write_read(
+        TS_BASE_ADDR | (eeprom_addr & 0b111),
+        &[TEMPERATURE_REGISTER],
+        &mut data,
+    )
+    // the sensor is a separate device which is not affected by the EEPROM write cycle
+    .map_err(|e| Error::from_bus(e, false))?;
+    // 13-bit two's complement value with 0.0625 °C resolution, the 3 MSBs are flags
+    let raw = (i16::from_be_bytes(data) << 3) >> 3;
+    Ok(f32::from(raw) * 0.0625)
+}
+
+/// Methods for reading the temperature sensor integrated in some SPD devices.
+/// e.g. 34TS02.
+impl<I2C, PS, E> Eeprom24x<I2C, PS, OneByte, unique_serial::Spd>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Read the ambient temperature in °C from the integrated JC42.4 temperature sensor.
+    ///
+    /// The sensor is accessed at its own address `0b001_1xxx`, which shares the address
+    /// pins with the EEPROM. Devices without a temperature sensor do not acknowledge it
+    /// and `Error::NoDevice` is returned.
+    pub fn read_temperature(&mut self) -> Result<f32, Error<E>> {
+        read_temperature(&mut self.i2c, self.address.addr())
+    }
+}
+
+/// Methods for reading the temperature sensor integrated in some EE1004 devices.
+/// e.g. 34TSE04 or EE1004-T.
+impl<I2C, E> Ee1004<I2C>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Read the ambient temperature in °C from the integrated JC42.4 temperature sensor.
+    ///
+    /// The sensor is accessed at its own address `0b001_1xxx`, which shares the address
+    /// pins with the EEPROM. Devices without a temperature sensor do not acknowledge it
+    /// and `Error::NoDevice` is returned.
+    pub fn read_temperature(&mut self) -> Result<f32, Error<E>> {
+        read_temperature(&mut self.i2c, self.address.addr())
+    }
+}
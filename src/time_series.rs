@@ -0,0 +1,224 @@
+use crate::crc::{crc16, CRC16_INIT};
+use crate::{Samples, TimeSeries, TimeSeriesError};
+use core::ops::Range;
+use embedded_storage::{ReadStorage, Storage};
+
+/// Size of a record without the data: timestamp and checksum.
+const RECORD_OVERHEAD: u32 = 6;
+
+/// Timestamp and data of a record.
+type Sample<const N: usize> = (u32, [u8; N]);
+
+impl<S, const N: usize> TimeSeries<S, N> {
+    /// Size of a record in the storage.
+    const RECORD_LEN: u32 = N as u32 + RECORD_OVERHEAD;
+
+    /// Destroy the store, return the storage.
+    pub fn destroy(self) -> S {
+        self.storage
+    }
+
+    /// Return the number of records.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Return whether the store contains no records.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<S, const N: usize> TimeSeries<S, N>
+where
+    S: ReadStorage,
+{
+    /// Return the maximum number of records.
+    pub fn capacity(&self) -> usize {
+        self.storage.capacity() / Self::RECORD_LEN as usize
+    }
+
+    /// Return the record at an index, counting from the oldest record.
+    pub fn get(&mut self, index: usize) -> Result<Sample<N>, TimeSeriesError<S::Error>> {
+        if index >= self.len() {
+            return Err(TimeSeriesError::OutOfBounds);
+        }
+        let slot = (self.head as usize + index) % self.capacity();
+        self.read_slot(slot as u32)?
+            .ok_or(TimeSeriesError::Corrupted)
+    }
+
+    /// Return the index of the first record whose timestamp is not older than `timestamp`.
+    ///
+    /// The records are found by binary search. Returns the number of records if all the
+    /// records are older.
+    pub fn find(&mut self, timestamp: u32) -> Result<usize, TimeSeriesError<S::Error>> {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.get(middle)?.0 < timestamp {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        Ok(low)
+    }
+
+    /// Return an iterator over the records whose timestamp lies in the range, from the
+    /// oldest to the newest one.
+    pub fn range(
+        &mut self,
+        timestamps: Range<u32>,
+    ) -> Result<Samples<'_, S, N>, TimeSeriesError<S::Error>> {
+        let index = self.find(timestamps.start)?;
+        let end = self.find(timestamps.end)?.max(index);
+        Ok(Samples {
+            series: self,
+            index,
+            end,
+        })
+    }
+
+    /// Read a record slot, returning `None` if it does not match its checksum.
+    fn read_slot(&mut self, slot: u32) -> Result<Option<Sample<N>>, TimeSeriesError<S::Error>> {
+        let offset = slot * Self::RECORD_LEN;
+        let mut timestamp = [0; 4];
+        let mut data = [0; N];
+        let mut stored = [0; 2];
+        self.storage
+            .read(offset, &mut timestamp)
+            .map_err(TimeSeriesError::Storage)?;
+        self.storage
+            .read(offset + 4, &mut data)
+            .map_err(TimeSeriesError::Storage)?;
+        self.storage
+            .read(offset + 4 + N as u32, &mut stored)
+            .map_err(TimeSeriesError::Storage)?;
+        if crc16(crc16(CRC16_INIT, &timestamp), &data) != u16::from_le_bytes(stored) {
+            return Ok(None);
+        }
+        Ok(Some((u32::from_le_bytes(timestamp), data)))
+    }
+}
+
+impl<S, const N: usize> TimeSeries<S, N>
+where
+    S: Storage,
+{
+    /// Create an empty store in the storage.
+    ///
+    /// All the record slots are invalidated. Returns `TimeSeriesError::TooSmall` if the
+    /// storage cannot hold a record.
+    pub fn format(storage: S) -> Result<Self, TimeSeriesError<S::Error>> {
+        let mut series = TimeSeries {
+            storage,
+            head: 0,
+            len: 0,
+            last: 0,
+        };
+        if series.capacity() == 0 {
+            return Err(TimeSeriesError::TooSmall);
+        }
+        let data = [0; N];
+        let crc = !crc16(crc16(CRC16_INIT, &[0; 4]), &data);
+        for slot in 0..series.capacity() as u32 {
+            series.write_slot(slot, 0, &data, crc)?;
+        }
+        Ok(series)
+    }
+
+    /// Open a store previously created with [`format()`](Self::format).
+    ///
+    /// All the record slots are scanned to find the oldest and the newest record. A record
+    /// torn by a power loss is discarded.
+    pub fn mount(storage: S) -> Result<Self, TimeSeriesError<S::Error>> {
+        let mut series = TimeSeries {
+            storage,
+            head: 0,
+            len: 0,
+            last: 0,
+        };
+        let slots = series.capacity() as u32;
+        if slots == 0 {
+            return Err(TimeSeriesError::TooSmall);
+        }
+        let mut newest = None;
+        for slot in 0..slots {
+            if let Some((timestamp, _)) = series.read_slot(slot)? {
+                series.len += 1;
+                if !matches!(newest, Some((_, last)) if timestamp <= last) {
+                    newest = Some((slot, timestamp));
+                }
+            }
+        }
+        if let Some((slot, timestamp)) = newest {
+            // the valid records are contiguous and end with the newest one
+            series.last = timestamp;
+            series.head = (slot + 1 + slots - series.len) % slots;
+        }
+        Ok(series)
+    }
+
+    /// Append a record, overwriting the oldest record if the store is full.
+    ///
+    /// Returns `TimeSeriesError::NotIncreasing` if the timestamp is not newer than the
+    /// timestamp of the newest record.
+    pub fn append(
+        &mut self,
+        timestamp: u32,
+        data: &[u8; N],
+    ) -> Result<(), TimeSeriesError<S::Error>> {
+        if !self.is_empty() && timestamp <= self.last {
+            return Err(TimeSeriesError::NotIncreasing);
+        }
+        let slots = self.capacity() as u32;
+        let slot = (self.head + self.len) % slots;
+        if self.len == slots {
+            // the oldest record is lost as soon as its slot is written
+            self.head = (self.head + 1) % slots;
+            self.len -= 1;
+        }
+        let crc = crc16(crc16(CRC16_INIT, &timestamp.to_le_bytes()), data);
+        self.write_slot(slot, timestamp, data, crc)?;
+        self.len += 1;
+        self.last = timestamp;
+        Ok(())
+    }
+
+    fn write_slot(
+        &mut self,
+        slot: u32,
+        timestamp: u32,
+        data: &[u8; N],
+        crc: u16,
+    ) -> Result<(), TimeSeriesError<S::Error>> {
+        let offset = slot * Self::RECORD_LEN;
+        self.storage
+            .write(offset, &timestamp.to_le_bytes())
+            .map_err(TimeSeriesError::Storage)?;
+        self.storage
+            .write(offset + 4, data)
+            .map_err(TimeSeriesError::Storage)?;
+        self.storage
+            .write(offset + 4 + N as u32, &crc.to_le_bytes())
+            .map_err(TimeSeriesError::Storage)
+    }
+}
+
+impl<S, const N: usize> Iterator for Samples<'_, S, N>
+where
+    S: ReadStorage,
+{
+    type Item = Result<Sample<N>, TimeSeriesError<S::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        let record = self.series.get(self.index);
+        self.index += 1;
+        Some(record)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::slice::{split, split_mut};
+use crate::TransferError;
+use core::ops::Range;
+use embedded_storage::{ReadStorage, Storage};
+
+/// Size of the chunks in which the data is transferred.
+///
+/// Restored chunks are aligned to their size so that a chunk never spans more pages than
+/// necessary for devices with pages of up to 64 bytes.
+const CHUNK_LEN: usize = 64;
+
+/// Read a region of a storage in chunks and pass them to a callback.
+///
+/// Only one chunk of 64 bytes is kept in RAM, so that large regions can be streamed e.g.
+/// to a UART, a USB endpoint or a file. An error returned by the callback stops the
+/// transfer and is returned as `TransferError::Callback`.
+pub fn dump<S, F, C>(
+    storage: &mut S,
+    range: Range<u32>,
+    mut f: F,
+) -> Result<(), TransferError<S::Error, C>>
+where
+    S: ReadStorage,
+    F: FnMut(&[u8]) -> Result<(), C>,
+{
+    if range.end as usize > storage.capacity() {
+        return Err(TransferError::OutOfBounds);
+    }
+    let mut chunk = [0; CHUNK_LEN];
+    let mut offset = range.start;
+    while offset < range.end {
+        let (data, _) = split_mut(&mut chunk, (range.end - offset) as usize);
+        storage.read(offset, data).map_err(TransferError::Storage)?;
+        f(data).map_err(TransferError::Callback)?;
+        offset += data.len() as u32;
+    }
+    Ok(())
+}
+
+/// Write a region of a storage with data pulled in chunks from a callback.
+///
+/// The callback fills the buffer it is given and returns the number of bytes it put
+/// there. Returning 0 ends the transfer early. Each chunk is written to the storage
+/// before the next one is requested, so only one chunk of 64 bytes is kept in RAM and
+/// the write cycles are handled by the storage. Returns the number of bytes written.
+pub fn restore<S, F, C>(
+    storage: &mut S,
+    range: Range<u32>,
+    mut f: F,
+) -> Result<usize, TransferError<S::Error, C>>
+where
+    S: Storage,
+    F: FnMut(&mut [u8]) -> Result<usize, C>,
+{
+    if range.end as usize > storage.capacity() {
+        return Err(TransferError::OutOfBounds);
+    }
+    let mut chunk = [0; CHUNK_LEN];
+    let mut offset = range.start;
+    while offset < range.end {
+        let len = ((range.end - offset) as usize).min(CHUNK_LEN - offset as usize % CHUNK_LEN);
+        let (buffer, _) = split_mut(&mut chunk, len);
+        let filled = f(buffer).map_err(TransferError::Callback)?;
+        let (data, _) = split(buffer, filled);
+        if data.is_empty() {
+            break;
+        }
+        storage
+            .write(offset, data)
+            .map_err(TransferError::Storage)?;
+        offset += data.len() as u32;
+    }
+    Ok(offset.saturating_sub(range.start) as usize)
+}
@@ -0,0 +1,74 @@
+use crate::{addr_size::TwoBytes, unique_serial, Eeprom24x, Error, Irreversible};
+use embedded_hal::i2c::I2c;
+
+/// Memory address of the zone write protection register, beyond the memory array.
+const ZONE_REGISTER: [u8; 2] = [0x80, 0x00];
+/// Memory address of the configuration lock register, beyond the memory array.
+const LOCK_REGISTER: [u8; 2] = [0x80, 0x01];
+/// Lock bit in the configuration lock register.
+const LOCK_BIT: u8 = 0b0000_0001;
+
+/// Methods for interacting with the software write protection of 24CW devices.
+/// e.g. 24CWx16, 24CWx32, 24CWx64 and 24CWx128.
+///
+/// The memory is divided into 8 zones of equal size. Bit `n` of the protection mask
+/// write-protects zone `n`, i.e. the memory from `n * capacity / 8` up to (excluding)
+/// `(n + 1) * capacity / 8`. The configuration registers are accessed through memory
+/// addresses beyond the memory array.
+impl<I2C, PS, E> Eeprom24x<I2C, PS, TwoBytes, unique_serial::Cw>
+where
+    I2C: I2c<Error = E>,
+    E: embedded_hal::i2c::Error,
+{
+    /// Read the write-protected zones mask.
+    pub fn write_protection(&mut self) -> Result<u8, Error<E>> {
+        self.read_config_register(ZONE_REGISTER)
+    }
+
+    /// Set the write-protected zones mask.
+    ///
+    /// Writes to protected zones are ignored by the device. The setting is stored in
+    /// nonvolatile memory, so the EEPROM enters an internally-timed write cycle afterwards.
+    /// The writes are ignored by the device once the configuration has been locked.
+    pub fn set_write_protection(&mut self, zones: u8) -> Result<(), Error<E>> {
+        self.write_config_register(ZONE_REGISTER, zones)
+    }
+
+    /// Remove the write protection from all zones.
+    ///
+    /// This is equivalent to `set_write_protection(0)`.
+    pub fn clear_write_protection(&mut self) -> Result<(), Error<E>> {
+        self.set_write_protection(0)
+    }
+
+    /// Check whether the write protection configuration has been locked.
+    pub fn is_write_protection_locked(&mut self) -> Result<bool, Error<E>> {
+        Ok(self.read_config_register(LOCK_REGISTER)? & LOCK_BIT != 0)
+    }
+
+    /// Permanently lock the write protection configuration.
+    ///
+    /// **This cannot be undone.** Afterwards, the write-protected zones cannot be changed
+    /// anymore. The operation must be confirmed with an [`Irreversible`] token.
+    ///
+    /// After locking, the EEPROM enters an internally-timed write cycle.
+    pub fn lock_write_protection(&mut self, confirmation: Irreversible) -> Result<(), Error<E>> {
+        let Irreversible(()) = confirmation;
+        self.write_config_register(LOCK_REGISTER, LOCK_BIT)
+    }
+
+    fn read_config_register(&mut self, register: [u8; 2]) -> Result<u8, Error<E>> {
+        let mut data = [0];
+        let result = self
+            .i2c
+            .write_read(self.address.addr(), &register, &mut data);
+        self.finish_access(result, false).and(Ok(data[0]))
+    }
+
+    fn write_config_register(&mut self, register: [u8; 2], value: u8) -> Result<(), Error<E>> {
+        let result = self
+            .i2c
+            .write(self.address.addr(), &[register[0], register[1], value]);
+        self.finish_access(result, true)
+    }
+}
@@ -0,0 +1,39 @@
+use eeprom24x::{ArrayError, EepromArray};
+mod common;
+use crate::common::RamStorage;
+
+const PRESETS: EepromArray<u32, 3> = EepromArray::new(4);
+
+#[test]
+fn can_write_and_read_elements() {
+    let mut storage = RamStorage::<16>::new(0);
+    PRESETS.set(&mut storage, 0, &0x0403_0201).unwrap();
+    PRESETS.set(&mut storage, 2, &0x0C0B_0A09).unwrap();
+    assert_eq!(
+        [0, 0, 0, 0, 1, 2, 3, 4, 0, 0, 0, 0, 9, 10, 11, 12],
+        storage.data
+    );
+    assert_eq!(0x0C0B_0A09, PRESETS.get(&mut storage, 2).unwrap());
+    assert_eq!(0, PRESETS.get(&mut storage, 1).unwrap());
+}
+
+#[test]
+fn has_layout() {
+    assert_eq!(3, PRESETS.len());
+    assert_eq!(4, PRESETS.stride());
+    assert_eq!(Some(12), PRESETS.cell(2).map(|cell| cell.address()));
+    assert!(PRESETS.cell(3).is_none());
+}
+
+#[test]
+fn cannot_access_out_of_bounds() {
+    let mut storage = RamStorage::<16>::new(0);
+    match PRESETS.get(&mut storage, 3) {
+        Err(ArrayError::OutOfBounds { index: 3, len: 3 }) => (),
+        _ => panic!("ArrayError::OutOfBounds not returned."),
+    }
+    match PRESETS.set(&mut storage, 3, &0) {
+        Err(ArrayError::OutOfBounds { index: 3, len: 3 }) => (),
+        _ => panic!("ArrayError::OutOfBounds not returned."),
+    }
+}
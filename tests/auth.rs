@@ -0,0 +1,76 @@
+#![cfg(feature = "auth")]
+use eeprom24x::{AuthError, AuthenticatedBlock, Mac};
+mod common;
+use crate::common::RamStorage;
+
+/// Toy keyed checksum standing in for a real MAC
+#[derive(Default)]
+struct KeyedSum {
+    state: [u8; 4],
+    position: usize,
+}
+
+impl Mac for KeyedSum {
+    type Tag = [u8; 4];
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            let index = self.position % 4;
+            self.state[index] = self.state[index].rotate_left(1) ^ byte ^ 0xA5;
+            self.position += 1;
+        }
+    }
+
+    fn finalize(&mut self) -> [u8; 4] {
+        let tag = self.state;
+        *self = KeyedSum::default();
+        tag
+    }
+}
+
+type Block = AuthenticatedBlock<RamStorage<32>, KeyedSum>;
+
+fn new() -> Block {
+    AuthenticatedBlock::new(RamStorage::new(0xFF), KeyedSum::default())
+}
+
+#[test]
+fn can_save_and_load() {
+    let mut block = new();
+    assert_eq!(26, block.max_len());
+    block.save(b"limit=20").unwrap();
+    let mut buffer = [0; 26];
+    let len = block.load(&mut buffer).unwrap();
+    assert_eq!(b"limit=20", &buffer[..len]);
+}
+
+#[test]
+fn detects_modified_data() {
+    let mut block = new();
+    block.save(b"limit=20").unwrap();
+    let (mut storage, mac) = block.destroy();
+    storage.data[12] = b'9';
+    let mut block = AuthenticatedBlock::new(storage, mac);
+    match block.load(&mut [0; 26]) {
+        Err(AuthError::TamperDetected) => (),
+        _ => panic!("AuthError::TamperDetected not returned."),
+    }
+}
+
+#[test]
+fn erased_storage_is_not_authentic() {
+    let mut block = new();
+    match block.load(&mut [0; 26]) {
+        Err(AuthError::TamperDetected) => (),
+        _ => panic!("AuthError::TamperDetected not returned."),
+    }
+}
+
+#[test]
+fn cannot_save_too_large_data() {
+    let mut block = new();
+    match block.save(&[0; 27]) {
+        Err(AuthError::TooLarge) => (),
+        _ => panic!("AuthError::TooLarge not returned."),
+    }
+}
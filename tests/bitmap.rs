@@ -0,0 +1,35 @@
+use eeprom24x::EepromBitmap;
+mod common;
+use crate::common::RamStorage;
+
+#[test]
+fn can_set_and_clear_bits() {
+    let mut bitmap = EepromBitmap::new(RamStorage::<4>::new(0));
+    assert_eq!(32, bitmap.len());
+    bitmap.set(0).unwrap();
+    bitmap.set(9).unwrap();
+    bitmap.set(31).unwrap();
+    assert!(bitmap.get(9).unwrap());
+    assert!(!bitmap.get(8).unwrap());
+    bitmap.clear(0).unwrap();
+    assert!(!bitmap.get(0).unwrap());
+    assert_eq!([0, 0b10, 0, 0x80], bitmap.destroy().data);
+}
+
+#[test]
+fn does_not_write_unchanged_byte() {
+    let mut storage = RamStorage::<4>::new(0x0F);
+    storage.writes_left = Some(0);
+    let mut bitmap = EepromBitmap::new(storage);
+    bitmap.set(3).unwrap();
+    bitmap.clear(4).unwrap();
+}
+
+#[test]
+fn can_count_ones() {
+    let mut storage = RamStorage::<40>::new(0);
+    storage.data[0] = 0xFF;
+    storage.data[39] = 0b101;
+    let mut bitmap = EepromBitmap::new(storage);
+    assert_eq!(10, bitmap.count_ones().unwrap());
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "block-alloc")]
+use eeprom24x::{AllocError, Allocation, BlockAllocator};
+mod common;
+use crate::common::RamStorage;
+
+// 10 blocks of 8 bytes and a 2-byte bitmap
+type Allocator = BlockAllocator<RamStorage<82>>;
+
+fn format() -> Allocator {
+    let mut allocator = Allocator::new(RamStorage::new(0xFF), 8);
+    allocator.format().unwrap();
+    allocator
+}
+
+#[test]
+fn can_alloc_and_free() {
+    let mut allocator = format();
+    assert_eq!(10, allocator.blocks());
+    let a = allocator.alloc(3).unwrap();
+    let b = allocator.alloc(2).unwrap();
+    assert_eq!(Allocation { start: 0, count: 3 }, a);
+    assert_eq!(Allocation { start: 3, count: 2 }, b);
+    allocator.free(a).unwrap();
+    assert_eq!(
+        Allocation { start: 0, count: 2 },
+        allocator.alloc(2).unwrap()
+    );
+    assert_eq!(
+        Allocation { start: 5, count: 5 },
+        allocator.alloc(5).unwrap()
+    );
+    match allocator.alloc(2) {
+        Err(AllocError::Full) => (),
+        _ => panic!("AllocError::Full not returned."),
+    }
+}
+
+#[test]
+fn bitmap_is_persisted() {
+    let mut allocator = format();
+    allocator.alloc(9).unwrap();
+    let mut allocator = Allocator::new(allocator.destroy(), 8);
+    assert_eq!(
+        Allocation { start: 9, count: 1 },
+        allocator.alloc(1).unwrap()
+    );
+}
+
+#[test]
+fn can_read_and_write_allocation() {
+    let mut allocator = format();
+    let a = allocator.alloc(2).unwrap();
+    allocator.write(a, 6, &[1, 2, 3, 4]).unwrap();
+    let mut data = [0; 4];
+    allocator.read(a, 6, &mut data).unwrap();
+    assert_eq!([1, 2, 3, 4], data);
+    match allocator.write(a, 14, &[1, 2, 3]) {
+        Err(AllocError::OutOfBounds) => (),
+        _ => panic!("AllocError::OutOfBounds not returned."),
+    }
+}
+
+#[test]
+fn realloc_grows_in_place() {
+    let mut allocator = format();
+    let a = allocator.alloc(2).unwrap();
+    let a = allocator.realloc(a, 4).unwrap();
+    assert_eq!(Allocation { start: 0, count: 4 }, a);
+    let a = allocator.realloc(a, 1).unwrap();
+    assert_eq!(Allocation { start: 0, count: 1 }, a);
+    assert_eq!(
+        Allocation { start: 1, count: 9 },
+        allocator.alloc(9).unwrap()
+    );
+}
+
+#[test]
+fn realloc_moves_data() {
+    let mut allocator = format();
+    let a = allocator.alloc(1).unwrap();
+    allocator.alloc(1).unwrap();
+    allocator.write(a, 0, b"abcdefgh").unwrap();
+    let a = allocator.realloc(a, 2).unwrap();
+    assert_eq!(Allocation { start: 2, count: 2 }, a);
+    let mut data = [0; 8];
+    allocator.read(a, 0, &mut data).unwrap();
+    assert_eq!(*b"abcdefgh", data);
+    assert_eq!(
+        Allocation { start: 0, count: 1 },
+        allocator.alloc(1).unwrap()
+    );
+}
+
+#[test]
+fn cannot_free_free_blocks() {
+    let mut allocator = format();
+    match allocator.free(Allocation { start: 0, count: 1 }) {
+        Err(AllocError::NotAllocated) => (),
+        _ => panic!("AllocError::NotAllocated not returned."),
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "boot-counter")]
+use eeprom24x::{BootCounter, CounterError};
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+// 4 slots
+type Counter = BootCounter<RamStorage<24>>;
+
+/// Encode a count with its CRC-16/CCITT-FALSE checksum.
+fn slot(count: u32) -> [u8; 6] {
+    let bytes = count.to_le_bytes();
+    let mut crc = 0xFFFF_u16;
+    for byte in bytes {
+        crc ^= u16::from(byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    let [c0, c1] = crc.to_le_bytes();
+    [bytes[0], bytes[1], bytes[2], bytes[3], c0, c1]
+}
+
+#[test]
+fn starts_at_zero() {
+    let mut counter = Counter::new(RamStorage::new(0xFF));
+    assert_eq!(0, counter.boot_count().unwrap());
+}
+
+#[test]
+fn count_is_persisted() {
+    let mut counter = Counter::new(RamStorage::new(0xFF));
+    for expected in 1..=10 {
+        assert_eq!(expected, counter.increment_boot_count().unwrap());
+    }
+    let mut counter = Counter::new(counter.destroy());
+    assert_eq!(10, counter.boot_count().unwrap());
+    assert_eq!(11, counter.increment_boot_count().unwrap());
+}
+
+#[test]
+fn writes_are_spread_over_slots() {
+    let mut counter = Counter::new(RamStorage::new(0xFF));
+    for _ in 0..4 {
+        counter.increment_boot_count().unwrap();
+    }
+    let storage = counter.destroy();
+    for (slot, count) in storage.data.chunks(6).zip(1u8..) {
+        assert_eq!(count, slot[0]);
+    }
+}
+
+#[test]
+fn keeps_previous_count_on_power_loss() {
+    let mut counter = Counter::new(RamStorage::new(0xFF));
+    counter.increment_boot_count().unwrap();
+    let mut storage = counter.destroy();
+    storage.writes_left = Some(0);
+    let mut counter = Counter::new(storage);
+    match counter.increment_boot_count() {
+        Err(CounterError::Storage(PowerLoss)) => (),
+        _ => panic!("CounterError::Storage not returned."),
+    }
+    let mut storage = counter.destroy();
+    storage.writes_left = None;
+    let mut counter = Counter::new(storage);
+    assert_eq!(1, counter.boot_count().unwrap());
+}
+
+#[test]
+fn count_rolls_over() {
+    let mut storage = RamStorage::new(0xFF);
+    storage.data[..6].copy_from_slice(&slot(u32::MAX - 1));
+    storage.data[6..12].copy_from_slice(&slot(u32::MAX));
+    let mut counter = Counter::new(storage);
+    assert_eq!(u32::MAX, counter.boot_count().unwrap());
+    assert_eq!(0, counter.increment_boot_count().unwrap());
+    assert_eq!(1, counter.increment_boot_count().unwrap());
+    let mut counter = Counter::new(counter.destroy());
+    assert_eq!(1, counter.boot_count().unwrap());
+}
+
+#[test]
+fn cannot_count_in_tiny_storage() {
+    let mut counter = BootCounter::new(RamStorage::<4>::new(0xFF));
+    match counter.increment_boot_count() {
+        Err(CounterError::TooSmall) => (),
+        _ => panic!("CounterError::TooSmall not returned."),
+    }
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "alloc")]
+use eeprom24x::{BoxedEeprom24x, Eeprom24x, Eeprom24xTrait, SlaveAddr};
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::DEV_ADDR;
+
+fn read_first_byte(eeprom: &mut impl Eeprom24xTrait<Error = ErrorKind>) -> u8 {
+    eeprom.read_byte(0).unwrap()
+}
+
+#[test]
+fn can_store_heterogeneous_devices() {
+    let mut i2c1 = I2cMock::new(&[I2cTrans::write_read(DEV_ADDR, vec![0], vec![0xAB])]);
+    let mut i2c2 = I2cMock::new(&[I2cTrans::write_read(DEV_ADDR, vec![0, 0], vec![0xCD])]);
+    let mut devices: Vec<BoxedEeprom24x<'_, ErrorKind>> = vec![
+        Eeprom24x::new_24x02(i2c1.clone(), SlaveAddr::default()).into_boxed(),
+        Eeprom24x::new_24x256(i2c2.clone(), SlaveAddr::default()).into_boxed(),
+    ];
+    assert_eq!(8, devices[0].page_size());
+    assert_eq!(64, devices[1].page_size());
+    assert_eq!(0xAB, read_first_byte(&mut devices[0]));
+    assert_eq!(0xCD, read_first_byte(&mut devices[1]));
+    i2c1.done();
+    i2c2.done();
+}
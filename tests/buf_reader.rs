@@ -0,0 +1,56 @@
+use eeprom24x::{addr_size, page_size, unique_serial, BufReader, Eeprom24x, SlaveAddr, Storage};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+mod common;
+use crate::common::{destroy, DEV_ADDR};
+
+type Reader =
+    BufReader<Storage<I2cMock, page_size::B8, addr_size::OneByte, unique_serial::No, NoopDelay>, 4>;
+
+fn new_reader(transactions: &[I2cTrans], offset: u32) -> Reader {
+    let eeprom = Eeprom24x::new_24x02(I2cMock::new(transactions), SlaveAddr::default());
+    BufReader::new(Storage::new(eeprom, NoopDelay), offset)
+}
+
+fn destroy_reader(reader: Reader) {
+    destroy(reader.destroy().eeprom);
+}
+
+#[test]
+fn reads_bytes_sequentially() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![1, 2, 3, 4]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x14], vec![5, 6, 7, 8]),
+    ];
+    let mut reader = new_reader(&trans, 0x10);
+    let data: Vec<u8> = (0..5)
+        .map(|_| reader.read_byte().unwrap().unwrap())
+        .collect();
+    assert_eq!(vec![1, 2, 3, 4, 5], data);
+    assert_eq!(0x15, reader.position());
+    destroy_reader(reader);
+}
+
+#[test]
+fn seeking_within_buffer_does_not_read() {
+    let trans = [I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![1, 2, 3, 4])];
+    let mut reader = new_reader(&trans, 0x10);
+    let mut data = [0; 2];
+    assert_eq!(2, reader.read(&mut data).unwrap());
+    reader.seek(0x11);
+    assert_eq!(2, reader.read(&mut data).unwrap());
+    assert_eq!([2, 3], data);
+    destroy_reader(reader);
+}
+
+#[test]
+fn stops_at_end_of_storage() {
+    let trans = [I2cTrans::write_read(DEV_ADDR, vec![0xFE], vec![1, 2])];
+    let mut reader = new_reader(&trans, 0xFE);
+    let mut data = [0; 3];
+    assert_eq!(2, reader.read(&mut data).unwrap());
+    assert_eq!(None, reader.read_byte().unwrap());
+    destroy_reader(reader);
+}
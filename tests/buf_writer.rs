@@ -0,0 +1,31 @@
+use eeprom24x::BufWriter;
+mod common;
+use crate::common::RamStorage;
+
+#[test]
+fn writes_full_pages() {
+    let mut storage = RamStorage::<64>::new(0xFF);
+    storage.writes_left = Some(2);
+    let mut writer: BufWriter<_, 16> = BufWriter::new(storage, 4);
+    // 4..16 and 16..32
+    for byte in 0..28 {
+        writer.write(&[byte]).unwrap();
+    }
+    assert_eq!(32, writer.position());
+    let storage = writer.destroy();
+    assert_eq!(Some(0), storage.writes_left);
+    assert_eq!([0xFF; 4], storage.data[..4]);
+    assert!((0..28).eq(storage.data[4..32].iter().copied()));
+}
+
+#[test]
+fn flush_writes_tail() {
+    let mut writer: BufWriter<_, 16> = BufWriter::new(RamStorage::<64>::new(0xFF), 0);
+    writer.write(&[1; 20]).unwrap();
+    writer.write(&[2; 3]).unwrap();
+    writer.flush().unwrap();
+    writer.flush().unwrap();
+    let storage = writer.destroy();
+    assert_eq!([1; 20], storage.data[..20]);
+    assert_eq!([2, 2, 2, 0xFF], storage.data[20..24]);
+}
@@ -0,0 +1,105 @@
+use eeprom24x::Error;
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+mod common;
+use crate::common::{destroy, new_24csx64, new_24x256, new_47x16, page_write, DEV_ADDR};
+
+fn nack() -> ErrorKind {
+    ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)
+}
+
+#[test]
+fn reports_missing_device() {
+    let trans = [I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0]).with_error(nack())];
+    let mut eeprom = new_24x256(&trans);
+    match eeprom.read_byte(0x1234) {
+        Err(Error::NoDevice(_)) => (),
+        _ => panic!("Error::NoDevice not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_busy_device_after_write() {
+    let trans = [
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34, 0xAB]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0]).with_error(nack()),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0]).with_error(nack()),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.write_byte(0x1234, 0xAB).unwrap();
+    match eeprom.read_byte(0x1234) {
+        Err(Error::Busy(_)) => (),
+        _ => panic!("Error::Busy not returned."),
+    }
+    assert_eq!(0xAB, eeprom.read_byte(0x1234).unwrap());
+    match eeprom.read_byte(0x1234) {
+        Err(Error::NoDevice(_)) => (),
+        _ => panic!("Error::NoDevice not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_busy_device_after_page_write() {
+    let mut trans = page_write(DEV_ADDR, &[0x12, 0x34], &[0xAB, 0xCD]);
+    trans.push(I2cTrans::write(DEV_ADDR, vec![0x12, 0x36, 0xEF]).with_error(nack()));
+    let mut eeprom = new_24x256(&trans);
+    eeprom.write_page(0x1234, &[0xAB, 0xCD]).unwrap();
+    match eeprom.write_byte(0x1236, 0xEF) {
+        Err(Error::Busy(_)) => (),
+        _ => panic!("Error::Busy not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_other_bus_errors() {
+    let trans = [
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34, 0xAB]).with_error(ErrorKind::ArbitrationLoss)
+    ];
+    let mut eeprom = new_24x256(&trans);
+    match eeprom.write_byte(0x1234, 0xAB) {
+        Err(Error::I2C(ErrorKind::ArbitrationLoss)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_missing_device_when_reading_security_region() {
+    let trans = [I2cTrans::write_read(0b101_1000, vec![0x08, 0x00], vec![0]).with_error(nack())];
+    let mut eeprom = new_24csx64(&trans);
+    match eeprom.read_security_region(0, &mut [0]) {
+        Err(Error::NoDevice(_)) => (),
+        _ => panic!("Error::NoDevice not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_missing_device_id() {
+    let trans = [I2cTrans::write_read(0x7C, vec![DEV_ADDR << 1], vec![0; 3]).with_error(nack())];
+    let mut eeprom = new_24x256(&trans);
+    match eeprom.read_device_id() {
+        Err(Error::NoDevice(_)) => (),
+        _ => panic!("Error::NoDevice not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_busy_eeram_after_store() {
+    let trans = [
+        I2cTrans::write(0b001_1000, vec![0x55, 0x33]),
+        I2cTrans::read(0b001_1000, vec![0]).with_error(nack()),
+    ];
+    let mut eeram = new_47x16(&trans);
+    eeram.store().unwrap();
+    match eeram.read_status() {
+        Err(Error::Busy(_)) => (),
+        _ => panic!("Error::Busy not returned."),
+    }
+    destroy(eeram);
+}
@@ -0,0 +1,109 @@
+use eeprom24x::{CachedEeprom, Eeprom24x, Error, SlaveAddr};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use embedded_storage::{ReadStorage, Storage};
+mod common;
+use crate::common::{destroy, page_write, Eeprom24x02Mock, DEV_ADDR};
+
+type Cache = CachedEeprom<Eeprom24x02Mock, NoopDelay, 2, 8>;
+
+fn new_cache(transactions: &[I2cTrans]) -> Cache {
+    let eeprom = Eeprom24x::new_24x02(I2cMock::new(transactions), SlaveAddr::default());
+    CachedEeprom::new(eeprom, NoopDelay).unwrap()
+}
+
+fn destroy_cache(cache: Cache) {
+    destroy(cache.destroy().0);
+}
+
+#[test]
+fn reads_each_line_once() {
+    let trans = [I2cTrans::write_read(
+        DEV_ADDR,
+        vec![0x10],
+        vec![0, 1, 2, 3, 4, 5, 6, 7],
+    )];
+    let mut cache = new_cache(&trans);
+    let mut data = [0; 2];
+    cache.read(0x12, &mut data).unwrap();
+    assert_eq!([2, 3], data);
+    cache.read(0x16, &mut data).unwrap();
+    assert_eq!([6, 7], data);
+    destroy_cache(cache);
+}
+
+#[test]
+fn coalesces_writes_to_the_same_line() {
+    let mut trans = vec![I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![0; 8])];
+    trans.extend(page_write(
+        DEV_ADDR,
+        &[0x10],
+        &[0, 0xAB, 0xCD, 0, 0, 0, 0, 0],
+    ));
+    let mut cache = new_cache(&trans);
+    cache.write(0x11, &[0xAB]).unwrap();
+    cache.write(0x12, &[0xCD]).unwrap();
+    assert!(cache.is_dirty());
+    let mut data = [0; 2];
+    cache.read(0x11, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD], data);
+    cache.flush().unwrap();
+    assert!(!cache.is_dirty());
+    cache.flush().unwrap();
+    destroy_cache(cache);
+}
+
+#[test]
+fn does_not_read_lines_written_completely() {
+    let trans = page_write(DEV_ADDR, &[0x8], &[0xAB; 8]);
+    let mut cache = new_cache(&trans);
+    cache.write(0x8, &[0xAB; 8]).unwrap();
+    cache.flush().unwrap();
+    destroy_cache(cache);
+}
+
+#[test]
+fn writes_back_evicted_dirty_lines() {
+    let mut trans = page_write(DEV_ADDR, &[0x0], &[0xAB; 8]);
+    trans.push(I2cTrans::write_read(DEV_ADDR, vec![0x20], vec![0x12; 8]));
+    let mut cache = new_cache(&trans);
+    cache.write(0x0, &[0xAB; 8]).unwrap();
+    cache.write(0x8, &[0xCD; 8]).unwrap();
+    // evicts the line at 0x0 first
+    let mut data = [0; 1];
+    cache.read(0x20, &mut data).unwrap();
+    assert_eq!([0x12], data);
+    // drops the line at 0x8
+    cache.invalidate();
+    assert!(!cache.is_dirty());
+    destroy_cache(cache);
+}
+
+#[test]
+fn cannot_read_beyond_capacity() {
+    let mut cache = new_cache(&[]);
+    assert_eq!(256, cache.capacity());
+    match cache.read(0xFF, &mut [0; 2]) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    match cache.write(0xFF, &[0; 2]) {
+        Err(Error::CapacityExceeded { .. }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy_cache(cache);
+}
+
+#[test]
+fn cannot_use_lines_larger_than_pages() {
+    let mut i2c = I2cMock::new(&[]);
+    let eeprom = Eeprom24x::new_24x02(i2c.clone(), SlaveAddr::default());
+    let result: Result<CachedEeprom<_, _, 1, 16>, _> = CachedEeprom::new(eeprom, NoopDelay);
+    match result {
+        Err(Error::InvalidGeometry) => (),
+        _ => panic!("Error::InvalidGeometry not returned."),
+    }
+    i2c.done();
+}
@@ -0,0 +1,53 @@
+use eeprom24x::{EepromCell, Storable};
+mod common;
+use crate::common::RamStorage;
+
+const COUNT: EepromCell<u16> = EepromCell::new(2);
+const ENABLED: EepromCell<bool> = EepromCell::new(4);
+
+struct Point {
+    x: i8,
+    y: i8,
+}
+
+impl Storable for Point {
+    type Bytes = [u8; 2];
+
+    fn to_bytes(&self) -> Self::Bytes {
+        [self.x as u8, self.y as u8]
+    }
+
+    fn from_bytes(bytes: Self::Bytes) -> Self {
+        Point {
+            x: bytes[0] as i8,
+            y: bytes[1] as i8,
+        }
+    }
+}
+
+#[test]
+fn can_get_value() {
+    let mut storage = RamStorage::<8>::new(0);
+    storage.data[2..4].copy_from_slice(&[0x34, 0x12]);
+    storage.data[4] = 1;
+    assert_eq!(0x1234, COUNT.get(&mut storage).unwrap());
+    assert!(ENABLED.get(&mut storage).unwrap());
+}
+
+#[test]
+fn can_set_value() {
+    let mut storage = RamStorage::<8>::new(0);
+    COUNT.set(&mut storage, &0xABCD).unwrap();
+    ENABLED.set(&mut storage, &true).unwrap();
+    assert_eq!([0, 0, 0xCD, 0xAB, 1, 0, 0, 0], storage.data);
+    assert_eq!(2, COUNT.address());
+}
+
+#[test]
+fn can_store_custom_type() {
+    let mut storage = RamStorage::<8>::new(0);
+    let cell = EepromCell::<Point>::new(6);
+    cell.set(&mut storage, &Point { x: -1, y: 2 }).unwrap();
+    let point = cell.get(&mut storage).unwrap();
+    assert_eq!((-1, 2), (point.x, point.y));
+}
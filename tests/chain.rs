@@ -0,0 +1,92 @@
+use eeprom24x::{Eeprom24x, Eeprom24xTrait, EepromChain, Error, SlaveAddr};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use embedded_storage::{ReadStorage, Storage};
+mod common;
+use crate::common::DEV_ADDR;
+
+type Chain = EepromChain<common::Eeprom24x02Mock, NoopDelay, 2>;
+
+fn new_chain(trans0: &[I2cTrans], trans1: &[I2cTrans]) -> Chain {
+    EepromChain::new(
+        [
+            Eeprom24x::new_24x02(I2cMock::new(trans0), SlaveAddr::from_pins(0)),
+            Eeprom24x::new_24x02(I2cMock::new(trans1), SlaveAddr::from_pins(1)),
+        ],
+        NoopDelay,
+    )
+}
+
+fn destroy(chain: Chain) {
+    let (devices, _) = chain.destroy();
+    for device in devices {
+        device.destroy().done();
+    }
+}
+
+#[test]
+fn has_combined_capacity() {
+    let chain = new_chain(&[], &[]);
+    assert_eq!(512, Eeprom24xTrait::capacity(&chain));
+    assert_eq!(512, ReadStorage::capacity(&chain));
+    assert_eq!(8, chain.page_size());
+    destroy(chain);
+}
+
+#[test]
+fn can_access_second_device() {
+    let trans1 = [
+        I2cTrans::write(DEV_ADDR | 1, vec![0x12, 0xAB]),
+        I2cTrans::write_read(DEV_ADDR | 1, vec![0x12], vec![0xAB]),
+        I2cTrans::read(DEV_ADDR | 1, vec![0xCD]),
+    ];
+    let mut chain = new_chain(&[], &trans1);
+    chain.write_byte(0x112, 0xAB).unwrap();
+    assert_eq!(0xAB, chain.read_byte(0x112).unwrap());
+    assert_eq!(0xCD, chain.read_current_address().unwrap());
+    destroy(chain);
+}
+
+#[test]
+fn can_read_over_device_boundary() {
+    let trans0 = [I2cTrans::write_read(DEV_ADDR, vec![0xFE], vec![0xAB, 0xCD])];
+    let trans1 = [I2cTrans::write_read(
+        DEV_ADDR | 1,
+        vec![0],
+        vec![0xEF, 0x12],
+    )];
+    let mut chain = new_chain(&trans0, &trans1);
+    let mut data = [0; 4];
+    chain.read(0xFE, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD, 0xEF, 0x12], data);
+    destroy(chain);
+}
+
+#[test]
+fn can_write_over_device_boundary() {
+    let trans0 = common::page_write(DEV_ADDR, &[0xFE], &[0xAB, 0xCD]);
+    let trans1 = common::page_write(DEV_ADDR | 1, &[0], &[0xEF, 0x12]);
+    let mut chain = new_chain(&trans0, &trans1);
+    chain.write(0xFE, &[0xAB, 0xCD, 0xEF, 0x12]).unwrap();
+    destroy(chain);
+}
+
+#[test]
+fn cannot_access_beyond_chain() {
+    let mut chain = new_chain(&[], &[]);
+    match chain.read_byte(0x200) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    match chain.read(0x1FF, &mut [0; 2]) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    match chain.write(0x1FF, &[0; 2]) {
+        Err(Error::CapacityExceeded { .. }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy(chain);
+}
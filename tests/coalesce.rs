@@ -0,0 +1,76 @@
+use eeprom24x::{Eeprom24x, Error, SlaveAddr, WriteCoalescer};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use embedded_storage::{ReadStorage, Storage};
+mod common;
+use crate::common::{destroy, page_write, Eeprom24x02Mock, DEV_ADDR};
+
+type Coalescer = WriteCoalescer<Eeprom24x02Mock, NoopDelay, 8>;
+
+fn new_coalescer(transactions: &[I2cTrans]) -> Coalescer {
+    let eeprom = Eeprom24x::new_24x02(I2cMock::new(transactions), SlaveAddr::default());
+    WriteCoalescer::new(eeprom, NoopDelay).unwrap()
+}
+
+fn destroy_coalescer(coalescer: Coalescer) {
+    destroy(coalescer.destroy().0);
+}
+
+#[test]
+fn accumulates_adjacent_writes() {
+    let trans = page_write(DEV_ADDR, &[0x11], &[0xAB, 0xCD, 0xEF]);
+    let mut coalescer = new_coalescer(&trans);
+    coalescer.write(0x12, &[0xCD]).unwrap();
+    coalescer.write(0x11, &[0xAB]).unwrap();
+    coalescer.write(0x13, &[0xEF]).unwrap();
+    assert!(coalescer.is_dirty());
+    coalescer.flush().unwrap();
+    assert!(!coalescer.is_dirty());
+    coalescer.flush().unwrap();
+    destroy_coalescer(coalescer);
+}
+
+#[test]
+fn flushes_when_crossing_block_boundary() {
+    let mut trans = page_write(DEV_ADDR, &[0x6], &[0xAB, 0xCD]);
+    trans.extend(page_write(DEV_ADDR, &[0x8], &[0xEF]));
+    let mut coalescer = new_coalescer(&trans);
+    coalescer.write(0x6, &[0xAB, 0xCD, 0xEF]).unwrap();
+    coalescer.flush().unwrap();
+    destroy_coalescer(coalescer);
+}
+
+#[test]
+fn flushes_before_unrelated_write() {
+    let mut trans = page_write(DEV_ADDR, &[0x10], &[0xAB]);
+    trans.extend(page_write(DEV_ADDR, &[0x14], &[0xCD]));
+    let mut coalescer = new_coalescer(&trans);
+    coalescer.write(0x10, &[0xAB]).unwrap();
+    coalescer.write(0x14, &[0xCD]).unwrap();
+    coalescer.flush().unwrap();
+    destroy_coalescer(coalescer);
+}
+
+#[test]
+fn reads_pending_data() {
+    let trans = [I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![1, 2, 3, 4])];
+    let mut coalescer = new_coalescer(&trans);
+    coalescer.write(0x12, &[0xAB, 0xCD]).unwrap();
+    let mut data = [0; 4];
+    coalescer.read(0x10, &mut data).unwrap();
+    assert_eq!([1, 2, 0xAB, 0xCD], data);
+    destroy_coalescer(coalescer);
+}
+
+#[test]
+fn cannot_write_beyond_capacity() {
+    let mut coalescer = new_coalescer(&[]);
+    assert_eq!(256, coalescer.capacity());
+    match coalescer.write(0xFF, &[0; 2]) {
+        Err(Error::CapacityExceeded { .. }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy_coalescer(coalescer);
+}
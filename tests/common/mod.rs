@@ -4,6 +4,9 @@ use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
 #[allow(unused)]
 pub const DEV_ADDR: u8 = 0b101_0000;
 
+#[allow(unused)]
+pub type Eeprom24x02Mock = eeprom24x::Eeprom24x02<I2cMock>;
+
 macro_rules! create {
     ($create:ident, $AS:ident, $PS:ident, $SN:ident) => {
         #[allow(dead_code)]
@@ -15,17 +18,94 @@ macro_rules! create {
     };
 }
 
+#[allow(dead_code)]
 pub fn destroy<T, V, S>(eeprom: Eeprom24x<I2cMock, T, V, S>) {
     eeprom.destroy().done();
 }
 
+/// Transactions of a page write: the memory address followed by the data.
+#[allow(dead_code)]
+pub fn page_write(address: u8, memaddr: &[u8], data: &[u8]) -> Vec<I2cTrans> {
+    vec![
+        I2cTrans::transaction_start(address),
+        I2cTrans::write(address, memaddr.to_vec()),
+        I2cTrans::write(address, data.to_vec()),
+        I2cTrans::transaction_end(address),
+    ]
+}
+
+/// Error of the `RamStorage` after the simulated power loss.
+#[allow(dead_code)]
+#[derive(Debug, PartialEq)]
+pub struct PowerLoss;
+
+/// Storage in RAM which can simulate a power loss after a number of writes.
+#[allow(dead_code)]
+pub struct RamStorage<const N: usize> {
+    pub data: [u8; N],
+    pub writes_left: Option<usize>,
+}
+
+#[allow(dead_code)]
+impl<const N: usize> RamStorage<N> {
+    pub fn new(value: u8) -> Self {
+        RamStorage {
+            data: [value; N],
+            writes_left: None,
+        }
+    }
+}
+
+impl<const N: usize> embedded_storage::ReadStorage for RamStorage<N> {
+    type Error = PowerLoss;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let offset = offset as usize;
+        bytes.copy_from_slice(&self.data[offset..offset + bytes.len()]);
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> embedded_storage::Storage for RamStorage<N> {
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        match &mut self.writes_left {
+            Some(0) => return Err(PowerLoss),
+            Some(left) => *left -= 1,
+            None => (),
+        }
+        let offset = offset as usize;
+        self.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
 create!(new_24x00, OneByte, No, No);
+create!(new_24x01a, OneByte, B4, No);
+create!(new_24x02a, OneByte, B4, No);
 create!(new_24x01, OneByte, B8, No);
 create!(new_24csx01, OneByte, B8, Yes);
 create!(new_m24x01, OneByte, B16, No);
 create!(new_24x02, OneByte, B8, No);
 create!(new_24csx02, OneByte, B8, Yes);
 create!(new_m24x02, OneByte, B16, No);
+create!(new_cat24x01, OneByte, B16, No);
+create!(new_cat24x02, OneByte, B16, No);
+create!(new_34x02, OneByte, B16, Spd);
+create!(new_24mac402, OneByte, B16, Mac402);
+create!(new_24mac602, OneByte, B16, Mac602);
+create!(new_24x02e48, OneByte, B8, E48);
+create!(new_24cswx01, OneByte, B8, Csw);
+create!(new_24cswx02, OneByte, B8, Csw);
+create!(new_24cswx04, OneByte, B16, Csw);
+create!(new_24cswx08, OneByte, B16, Csw);
+create!(new_24cswx16, OneByte, B16, Csw);
+create!(new_24x02e64, OneByte, B8, E64);
+create!(new_24x025e48, OneByte, B16, E48);
+create!(new_24x025e64, OneByte, B16, E64);
 create!(new_24x04, OneByte, B16, No);
 create!(new_24csx04, OneByte, B16, Yes);
 create!(new_24x08, OneByte, B16, No);
@@ -36,11 +116,19 @@ create!(new_24x32, TwoBytes, B32, No);
 create!(new_24csx32, TwoBytes, B32, Yes);
 create!(new_24x64, TwoBytes, B32, No);
 create!(new_24csx64, TwoBytes, B32, Yes);
+create!(new_24cwx16, TwoBytes, B32, Cw);
+create!(new_24cwx32, TwoBytes, B32, Cw);
+create!(new_24cwx64, TwoBytes, B32, Cw);
+create!(new_24cwx128, TwoBytes, B32, Cw);
 create!(new_24x128, TwoBytes, B64, No);
 create!(new_24x256, TwoBytes, B64, No);
 create!(new_24x512, TwoBytes, B128, No);
 create!(new_24xm01, TwoBytes, B256, No);
 create!(new_24xm02, TwoBytes, B256, No);
+create!(new_mb85rc64, TwoBytes, Unlimited, No);
+create!(new_mb85rc256, TwoBytes, Unlimited, No);
+create!(new_fm24w256, TwoBytes, Unlimited, No);
+create!(new_47x16, TwoBytes, Unlimited, Eeram);
 
 #[macro_export]
 macro_rules! for_all_ics {
@@ -48,22 +136,38 @@ macro_rules! for_all_ics {
         mod $name {
             use super::*;
             $name!(for_24x00, new_24x00);
+            $name!(for_24x01a, new_24x01a);
+            $name!(for_24x02a, new_24x02a);
             $name!(for_24x01, new_24x01);
             $name!(for_24csx01, new_24csx01);
+            $name!(for_24cswx01, new_24cswx01);
             $name!(for_m24x01, new_m24x01);
+            $name!(for_cat24x01, new_cat24x01);
             $name!(for_24x02, new_24x02);
             $name!(for_24csx02, new_24csx02);
+            $name!(for_24cswx02, new_24cswx02);
             $name!(for_m24x02, new_m24x02);
+            $name!(for_cat24x02, new_cat24x02);
+            $name!(for_34x02, new_34x02);
+            $name!(for_24mac402, new_24mac402);
+            $name!(for_24mac602, new_24mac602);
             $name!(for_24x04, new_24x04);
             $name!(for_24csx04, new_24csx04);
+            $name!(for_24cswx04, new_24cswx04);
             $name!(for_24x08, new_24x08);
             $name!(for_24csx08, new_24csx08);
+            $name!(for_24cswx08, new_24cswx08);
             $name!(for_24x16, new_24x16);
             $name!(for_24csx16, new_24csx16);
+            $name!(for_24cswx16, new_24cswx16);
             $name!(for_24x32, new_24x32);
             $name!(for_24csx32, new_24csx32);
             $name!(for_24x64, new_24x64);
             $name!(for_24csx64, new_24csx64);
+            $name!(for_24cwx16, new_24cwx16);
+            $name!(for_24cwx32, new_24cwx32);
+            $name!(for_24cwx64, new_24cwx64);
+            $name!(for_24cwx128, new_24cwx128);
             $name!(for_24x128, new_24x128);
             $name!(for_24x256, new_24x256);
             $name!(for_24x512, new_24x512);
@@ -79,18 +183,30 @@ macro_rules! for_all_ics_with_1b_addr {
         mod $name {
             use super::*;
             $name!(for_24x00, new_24x00);
+            $name!(for_24x01a, new_24x01a);
+            $name!(for_24x02a, new_24x02a);
             $name!(for_24x01, new_24x01);
             $name!(for_24csx01, new_24csx01);
+            $name!(for_24cswx01, new_24cswx01);
             $name!(for_m24x01, new_m24x01);
+            $name!(for_cat24x01, new_cat24x01);
             $name!(for_24x02, new_24x02);
             $name!(for_24csx02, new_24csx02);
+            $name!(for_24cswx02, new_24cswx02);
             $name!(for_m24x02, new_m24x02);
+            $name!(for_cat24x02, new_cat24x02);
+            $name!(for_34x02, new_34x02);
+            $name!(for_24mac402, new_24mac402);
+            $name!(for_24mac602, new_24mac602);
             $name!(for_24x04, new_24x04);
             $name!(for_24csx04, new_24csx04);
+            $name!(for_24cswx04, new_24cswx04);
             $name!(for_24x08, new_24x08);
             $name!(for_24csx08, new_24csx08);
+            $name!(for_24cswx08, new_24cswx08);
             $name!(for_24x16, new_24x16);
             $name!(for_24csx16, new_24csx16);
+            $name!(for_24cswx16, new_24cswx16);
         }
     };
 }
@@ -104,6 +220,9 @@ macro_rules! for_all_ics_with_2b_addr {
             $name!(for_24csx32, new_24csx32);
             $name!(for_24x64, new_24x64);
             $name!(for_24csx64, new_24csx64);
+            $name!(for_24cwx32, new_24cwx32);
+            $name!(for_24cwx64, new_24cwx64);
+            $name!(for_24cwx128, new_24cwx128);
             $name!(for_24x128, new_24x128);
             $name!(for_24x256, new_24x256);
             $name!(for_24x512, new_24x512);
@@ -118,18 +237,30 @@ macro_rules! for_all_ics_with_1b_addr_and_page_size {
     ($name:ident) => {
         mod $name {
             use super::*;
+            $name!(for_24x01a, new_24x01a, 4);
+            $name!(for_24x02a, new_24x02a, 4);
             $name!(for_24x01, new_24x01, 8);
             $name!(for_24csx01, new_24csx01, 8);
+            $name!(for_24cswx01, new_24cswx01, 8);
             $name!(for_m24x01, new_m24x01, 16);
+            $name!(for_cat24x01, new_cat24x01, 16);
             $name!(for_24x02, new_24x02, 8);
             $name!(for_24csx02, new_24csx02, 8);
+            $name!(for_24cswx02, new_24cswx02, 8);
             $name!(for_m24x02, new_m24x02, 16);
+            $name!(for_cat24x02, new_cat24x02, 16);
+            $name!(for_34x02, new_34x02, 16);
+            $name!(for_24mac402, new_24mac402, 16);
+            $name!(for_24mac602, new_24mac602, 16);
             $name!(for_24x04, new_24x04, 16);
             $name!(for_24csx04, new_24csx04, 16);
+            $name!(for_24cswx04, new_24cswx04, 16);
             $name!(for_24x08, new_24x08, 16);
             $name!(for_24csx08, new_24csx08, 16);
+            $name!(for_24cswx08, new_24cswx08, 16);
             $name!(for_24x16, new_24x16, 16);
             $name!(for_24csx16, new_24csx16, 16);
+            $name!(for_24cswx16, new_24cswx16, 16);
         }
     };
 }
@@ -143,6 +274,9 @@ macro_rules! for_all_ics_with_2b_addr_and_page_size {
             $name!(for_24csx32, new_24csx32, 32);
             $name!(for_24x64, new_24x64, 32);
             $name!(for_24csx64, new_24csx64, 32);
+            $name!(for_24cwx32, new_24cwx32, 32);
+            $name!(for_24cwx64, new_24cwx64, 32);
+            $name!(for_24cwx128, new_24cwx128, 32);
             $name!(for_24x128, new_24x128, 64);
             $name!(for_24x256, new_24x256, 64);
             $name!(for_24x512, new_24x512, 128);
@@ -157,22 +291,38 @@ macro_rules! for_all_ics_with_page_size {
     ($name:ident) => {
         mod $name {
             use super::*;
+            $name!(for_24x01a, new_24x01a, 4);
+            $name!(for_24x02a, new_24x02a, 4);
             $name!(for_24x01, new_24x01, 8);
             $name!(for_24csx01, new_24csx01, 8);
+            $name!(for_24cswx01, new_24cswx01, 8);
             $name!(for_m24x01, new_m24x01, 16);
+            $name!(for_cat24x01, new_cat24x01, 16);
             $name!(for_24x02, new_24x02, 8);
             $name!(for_24csx02, new_24csx02, 8);
+            $name!(for_24cswx02, new_24cswx02, 8);
             $name!(for_m24x02, new_m24x02, 16);
+            $name!(for_cat24x02, new_cat24x02, 16);
+            $name!(for_34x02, new_34x02, 16);
+            $name!(for_24mac402, new_24mac402, 16);
+            $name!(for_24mac602, new_24mac602, 16);
             $name!(for_24x04, new_24x04, 16);
             $name!(for_24csx04, new_24csx04, 16);
+            $name!(for_24cswx04, new_24cswx04, 16);
             $name!(for_24x08, new_24x08, 16);
             $name!(for_24csx08, new_24csx08, 16);
+            $name!(for_24cswx08, new_24cswx08, 16);
             $name!(for_24x16, new_24x16, 16);
             $name!(for_24csx16, new_24csx16, 16);
+            $name!(for_24cswx16, new_24cswx16, 16);
             $name!(for_24x32, new_24x32, 32);
             $name!(for_24csx32, new_24csx32, 32);
             $name!(for_24x64, new_24x64, 32);
             $name!(for_24csx64, new_24csx64, 32);
+            $name!(for_24cwx16, new_24cwx16, 32);
+            $name!(for_24cwx32, new_24cwx32, 32);
+            $name!(for_24cwx64, new_24cwx64, 32);
+            $name!(for_24cwx128, new_24cwx128, 32);
             $name!(for_24x128, new_24x128, 64);
             $name!(for_24x256, new_24x256, 64);
             $name!(for_24x512, new_24x512, 128);
@@ -188,22 +338,38 @@ macro_rules! for_all_ics_with_capacity {
         mod $name {
             use super::*;
             $name!(for_24x00, new_24x00, 16);
+            $name!(for_24x01a, new_24x01a, 1 << 7);
+            $name!(for_24x02a, new_24x02a, 1 << 8);
             $name!(for_24x01, new_24x01, 1 << 7);
             $name!(for_24csx01, new_24csx01, 1 << 7);
+            $name!(for_24cswx01, new_24cswx01, 1 << 7);
             $name!(for_m24x01, new_m24x01, 1 << 7);
+            $name!(for_cat24x01, new_cat24x01, 1 << 7);
             $name!(for_24x02, new_24x02, 1 << 8);
             $name!(for_24csx02, new_24csx02, 1 << 8);
+            $name!(for_24cswx02, new_24cswx02, 1 << 8);
             $name!(for_m24x02, new_m24x02, 1 << 8);
+            $name!(for_cat24x02, new_cat24x02, 1 << 8);
+            $name!(for_34x02, new_34x02, 1 << 8);
+            $name!(for_24mac402, new_24mac402, 1 << 8);
+            $name!(for_24mac602, new_24mac602, 1 << 8);
             $name!(for_24x04, new_24x04, 1 << 9);
             $name!(for_24csx04, new_24csx04, 1 << 9);
+            $name!(for_24cswx04, new_24cswx04, 1 << 9);
             $name!(for_24x08, new_24x08, 1 << 10);
             $name!(for_24csx08, new_24csx08, 1 << 10);
+            $name!(for_24cswx08, new_24cswx08, 1 << 10);
             $name!(for_24x16, new_24x16, 1 << 11);
             $name!(for_24csx16, new_24csx16, 1 << 11);
+            $name!(for_24cswx16, new_24cswx16, 1 << 11);
             $name!(for_24x32, new_24x32, 1 << 12);
             $name!(for_24csx32, new_24csx32, 1 << 12);
             $name!(for_24x64, new_24x64, 1 << 13);
             $name!(for_24csx64, new_24csx64, 1 << 13);
+            $name!(for_24cwx16, new_24cwx16, 1 << 11);
+            $name!(for_24cwx32, new_24cwx32, 1 << 12);
+            $name!(for_24cwx64, new_24cwx64, 1 << 13);
+            $name!(for_24cwx128, new_24cwx128, 1 << 14);
             $name!(for_24x128, new_24x128, 1 << 14);
             $name!(for_24x256, new_24x256, 1 << 15);
             $name!(for_24x512, new_24x512, 1 << 16);
@@ -218,22 +384,39 @@ macro_rules! for_all_writestorage_ics_with_capacity {
     ($name:ident) => {
         mod $name {
             use super::*;
+            $name!(for_24x00, new_24x00, 1 << 4);
+            $name!(for_24x01a, new_24x01a, 1 << 7);
+            $name!(for_24x02a, new_24x02a, 1 << 8);
             $name!(for_24x01, new_24x01, 1 << 7);
             $name!(for_24csx01, new_24csx01, 1 << 7);
+            $name!(for_24cswx01, new_24cswx01, 1 << 7);
             $name!(for_m24x01, new_m24x01, 1 << 7);
+            $name!(for_cat24x01, new_cat24x01, 1 << 7);
             $name!(for_24x02, new_24x02, 1 << 8);
             $name!(for_24csx02, new_24csx02, 1 << 8);
+            $name!(for_24cswx02, new_24cswx02, 1 << 8);
             $name!(for_m24x02, new_m24x02, 1 << 8);
+            $name!(for_cat24x02, new_cat24x02, 1 << 8);
+            $name!(for_34x02, new_34x02, 1 << 8);
+            $name!(for_24mac402, new_24mac402, 1 << 8);
+            $name!(for_24mac602, new_24mac602, 1 << 8);
             $name!(for_24x04, new_24x04, 1 << 9);
             $name!(for_24csx04, new_24csx04, 1 << 9);
+            $name!(for_24cswx04, new_24cswx04, 1 << 9);
             $name!(for_24x08, new_24x08, 1 << 10);
             $name!(for_24csx08, new_24csx08, 1 << 10);
+            $name!(for_24cswx08, new_24cswx08, 1 << 10);
             $name!(for_24x16, new_24x16, 1 << 11);
             $name!(for_24csx16, new_24csx16, 1 << 11);
+            $name!(for_24cswx16, new_24cswx16, 1 << 11);
             $name!(for_24x32, new_24x32, 1 << 12);
             $name!(for_24csx32, new_24csx32, 1 << 12);
             $name!(for_24x64, new_24x64, 1 << 13);
             $name!(for_24csx64, new_24csx64, 1 << 13);
+            $name!(for_24cwx16, new_24cwx16, 1 << 11);
+            $name!(for_24cwx32, new_24cwx32, 1 << 12);
+            $name!(for_24cwx64, new_24cwx64, 1 << 13);
+            $name!(for_24cwx128, new_24cwx128, 1 << 14);
             $name!(for_24x128, new_24x128, 1 << 14);
             $name!(for_24x256, new_24x256, 1 << 15);
             $name!(for_24x512, new_24x512, 1 << 16);
@@ -253,6 +436,8 @@ macro_rules! for_all_with_serial_with_1b_addr {
             $name!(for_24csx04, new_24csx04);
             $name!(for_24csx08, new_24csx08);
             $name!(for_24csx16, new_24csx16);
+            $name!(for_24mac402, new_24mac402);
+            $name!(for_24mac602, new_24mac602);
         }
     };
 }
@@ -267,3 +452,30 @@ macro_rules! for_all_with_serial_with_2b_addr {
         }
     };
 }
+
+#[macro_export]
+macro_rules! for_all_with_security_register {
+    ($name:ident) => {
+        mod $name {
+            use super::*;
+            $name!(for_24cswx01, new_24cswx01);
+            $name!(for_24cswx02, new_24cswx02);
+            $name!(for_24cswx04, new_24cswx04);
+            $name!(for_24cswx08, new_24cswx08);
+            $name!(for_24cswx16, new_24cswx16);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! for_all_with_write_protection_zones {
+    ($name:ident) => {
+        mod $name {
+            use super::*;
+            $name!(for_24cwx16, new_24cwx16);
+            $name!(for_24cwx32, new_24cwx32);
+            $name!(for_24cwx64, new_24cwx64);
+            $name!(for_24cwx128, new_24cwx128);
+        }
+    };
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "compression")]
+use eeprom24x::{Compressor, ConfigError, DualConfig};
+mod common;
+use crate::common::RamStorage;
+
+/// Run-length encoding of (count, byte) pairs
+struct Rle;
+
+impl Compressor for Rle {
+    fn compress(&mut self, input: &[u8], output: &mut [u8]) -> Option<usize> {
+        let mut len = 0;
+        for run in input.chunk_by(|a, b| a == b) {
+            for part in run.chunks(255) {
+                output
+                    .get_mut(len..len + 2)?
+                    .copy_from_slice(&[part.len() as u8, part[0]]);
+                len += 2;
+            }
+        }
+        Some(len)
+    }
+
+    fn decompress(&mut self, input: &[u8], output: &mut [u8]) -> Option<usize> {
+        let mut len = 0;
+        for pair in input.chunks(2) {
+            let [count, byte] = pair else { return None };
+            output.get_mut(len..len + *count as usize)?.fill(*byte);
+            len += *count as usize;
+        }
+        Some(len)
+    }
+}
+
+type Config = DualConfig<RamStorage<64>>;
+
+#[test]
+fn compressed_data_fits() {
+    let mut config = Config::new(RamStorage::new(0xFF));
+    let data = [[0; 40], [7; 40]].concat();
+    assert!(data.len() > config.max_len());
+    let mut scratch = [0; 100];
+    config
+        .save_compressed(&data, &mut Rle, &mut scratch)
+        .unwrap();
+    let mut config = Config::new(config.destroy());
+    let mut buffer = [0; 100];
+    let len = config
+        .load_compressed(&mut buffer, &mut Rle, &mut scratch)
+        .unwrap();
+    assert_eq!(data, buffer[..len]);
+}
+
+#[test]
+fn incompressible_data_is_stored() {
+    let mut config = Config::new(RamStorage::new(0xFF));
+    let mut scratch = [0; 32];
+    config
+        .save_compressed(b"abcdef", &mut Rle, &mut scratch)
+        .unwrap();
+    let mut buffer = [0; 6];
+    config
+        .load_compressed(&mut buffer, &mut Rle, &mut scratch)
+        .unwrap();
+    assert_eq!(*b"abcdef", buffer);
+    let mut framed = [0; 16];
+    assert_eq!(9, config.load(&mut framed).unwrap());
+}
+
+#[test]
+fn detects_invalid_frame() {
+    let mut config = Config::new(RamStorage::new(0xFF));
+    config.save(&[1, 10, 0, 5]).unwrap();
+    match config.load_compressed(&mut [0; 16], &mut Rle, &mut [0; 32]) {
+        Err(ConfigError::Corrupted) => (),
+        _ => panic!("ConfigError::Corrupted not returned."),
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "config")]
+use eeprom24x::{ConfigBlock, ConfigError};
+mod common;
+use crate::common::RamStorage;
+
+const MAGIC: u32 = 0x4346_4731;
+
+type Config = ConfigBlock<RamStorage<32>>;
+
+fn saved(version: u16, data: &[u8]) -> RamStorage<32> {
+    let mut config = Config::new(RamStorage::new(0xFF), MAGIC, version);
+    config.save(data).unwrap();
+    config.destroy()
+}
+
+fn no_migration(_: u16, _: &mut [u8], _: usize) -> Option<usize> {
+    panic!("unexpected migration")
+}
+
+#[test]
+fn cannot_load_missing_config() {
+    let mut config = Config::new(RamStorage::new(0xFF), MAGIC, 1);
+    let mut buffer = [0; 8];
+    match config.load(&mut buffer, no_migration) {
+        Err(ConfigError::NotFound) => (),
+        _ => panic!("ConfigError::NotFound not returned."),
+    }
+}
+
+#[test]
+fn can_save_and_load() {
+    let mut config = Config::new(saved(1, b"abc"), MAGIC, 1);
+    let mut buffer = [0; 8];
+    assert_eq!(3, config.load(&mut buffer, no_migration).unwrap());
+    assert_eq!(b"abc", &buffer[..3]);
+}
+
+#[test]
+fn detects_corruption() {
+    let mut storage = saved(1, b"abc");
+    storage.data[11] ^= 1;
+    let mut config = Config::new(storage, MAGIC, 1);
+    let mut buffer = [0; 8];
+    match config.load(&mut buffer, no_migration) {
+        Err(ConfigError::Corrupted) => (),
+        _ => panic!("ConfigError::Corrupted not returned."),
+    }
+}
+
+#[test]
+fn migrates_older_version() {
+    let mut config = Config::new(saved(1, b"ab"), MAGIC, 2);
+    let mut buffer = [0; 8];
+    let len = config
+        .load(&mut buffer, |version, data, len| {
+            assert_eq!((1, 2), (version, len));
+            data[len] = b'c';
+            Some(len + 1)
+        })
+        .unwrap();
+    assert_eq!(b"abc", &buffer[..len]);
+    // the migrated configuration was saved
+    let mut config = Config::new(config.destroy(), MAGIC, 2);
+    assert_eq!(3, config.load(&mut buffer, no_migration).unwrap());
+}
+
+#[test]
+fn cannot_load_unmigratable_version() {
+    let mut config = Config::new(saved(1, b"ab"), MAGIC, 3);
+    let mut buffer = [0; 8];
+    match config.load(&mut buffer, |_, _, _| None) {
+        Err(ConfigError::UnsupportedVersion { version: 1 }) => (),
+        _ => panic!("ConfigError::UnsupportedVersion not returned."),
+    }
+}
+
+#[test]
+fn cannot_load_newer_version() {
+    let mut config = Config::new(saved(2, b"ab"), MAGIC, 1);
+    let mut buffer = [0; 8];
+    match config.load(&mut buffer, no_migration) {
+        Err(ConfigError::UnsupportedVersion { version: 2 }) => (),
+        _ => panic!("ConfigError::UnsupportedVersion not returned."),
+    }
+}
+
+#[test]
+fn cannot_save_too_large_config() {
+    let mut config = Config::new(RamStorage::new(0xFF), MAGIC, 1);
+    assert_eq!(22, config.max_len());
+    match config.save(&[0; 23]) {
+        Err(ConfigError::TooLarge) => (),
+        _ => panic!("ConfigError::TooLarge not returned."),
+    }
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "embedded-io")]
+use eeprom24x::{CursorError, EepromCursor};
+use embedded_io::{Read, Seek, SeekFrom, Write};
+mod common;
+use crate::common::RamStorage;
+
+#[test]
+fn can_write_and_read_back() {
+    let mut cursor = EepromCursor::new(RamStorage::<200>::new(0xFF));
+    cursor.write_all(b"hello world").unwrap();
+    assert_eq!(11, cursor.position());
+    cursor.seek(SeekFrom::Start(6)).unwrap();
+    let mut data = [0; 5];
+    cursor.read_exact(&mut data).unwrap();
+    assert_eq!(*b"world", data);
+}
+
+#[test]
+fn writes_are_buffered_until_boundary() {
+    let mut storage = RamStorage::<200>::new(0xFF);
+    storage.writes_left = Some(3);
+    let mut cursor = EepromCursor::new(storage);
+    cursor.seek(SeekFrom::Start(60)).unwrap();
+    // 60..64, 64..128 and 128..140
+    for _ in 0..8 {
+        cursor.write_all(&[0xAB; 10]).unwrap();
+    }
+    cursor.flush().unwrap();
+    let storage = cursor.destroy();
+    assert_eq!(Some(0), storage.writes_left);
+    assert_eq!([0xAB; 80], storage.data[60..140]);
+}
+
+#[test]
+fn seek_flushes_on_next_write() {
+    let mut cursor = EepromCursor::new(RamStorage::<64>::new(0));
+    cursor.write_all(&[1, 2]).unwrap();
+    cursor.seek(SeekFrom::Current(2)).unwrap();
+    cursor.write_all(&[3]).unwrap();
+    cursor.flush().unwrap();
+    assert_eq!([1, 2, 0, 0, 3], cursor.destroy().data[..5]);
+}
+
+#[test]
+fn stops_at_end_of_storage() {
+    let mut cursor = EepromCursor::new(RamStorage::<16>::new(0));
+    assert_eq!(12, cursor.seek(SeekFrom::End(-4)).unwrap());
+    assert_eq!(4, cursor.write(&[1; 8]).unwrap());
+    match cursor.write(&[1]) {
+        Err(CursorError::EndOfStorage) => (),
+        _ => panic!("CursorError::EndOfStorage not returned."),
+    }
+    assert_eq!(0, cursor.read(&mut [0; 4]).unwrap());
+    match cursor.seek(SeekFrom::Current(-17)) {
+        Err(CursorError::InvalidSeek) => (),
+        _ => panic!("CursorError::InvalidSeek not returned."),
+    }
+}
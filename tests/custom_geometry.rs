@@ -0,0 +1,70 @@
+use eeprom24x::{addr_size, page_size, unique_serial, Eeprom24x, Eeprom24xDyn, Error, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::Mock as I2cMock;
+mod common;
+use crate::common::DEV_ADDR;
+
+type Custom<PS, AS> = Eeprom24x<I2cMock, PS, AS, unique_serial::No>;
+
+fn assert_invalid_geometry<T>(result: Result<T, Error<embedded_hal::i2c::ErrorKind>>) {
+    match result {
+        Err(Error::InvalidGeometry) => (),
+        _ => panic!("Error::InvalidGeometry not returned."),
+    }
+}
+
+#[test]
+fn can_create_custom_typed_device() {
+    let trans = common::page_write(DEV_ADDR, &[0x2, 0x34], &[0xAB, 0xCD]);
+    let mut eeprom: Custom<page_size::Bytes<4>, addr_size::TwoBytes> =
+        Eeprom24x::new_custom(I2cMock::new(&trans), SlaveAddr::default(), 10).unwrap();
+    eeprom.write_page(0x234, &[0xAB, 0xCD]).unwrap();
+    match eeprom.write_page(0x236, &[0xAB, 0xCD, 0xEF]) {
+        Err(Error::PageOverflow { .. }) => (),
+        _ => panic!("Error::PageOverflow not returned."),
+    }
+    eeprom.destroy().done();
+}
+
+#[test]
+fn cannot_create_custom_typed_device_with_too_many_address_bits() {
+    let mut i2c = I2cMock::new(&[]);
+    let result: Result<Custom<page_size::B16, addr_size::OneByte>, _> =
+        Eeprom24x::new_custom(i2c.clone(), SlaveAddr::default(), 12);
+    assert_invalid_geometry(result);
+    i2c.done();
+}
+
+#[test]
+fn can_create_custom_dyn_device() {
+    let trans = common::page_write(DEV_ADDR | 1, &[0x34], &[0xAB, 0xCD]);
+    let mut eeprom =
+        Eeprom24xDyn::new_custom(I2cMock::new(&trans), SlaveAddr::default(), 4, 1, 9).unwrap();
+    assert_eq!(4, eeprom.page_size());
+    assert_eq!(512, eeprom.capacity());
+    eeprom.write_page(0x134, &[0xAB, 0xCD]).unwrap();
+    eeprom.destroy().done();
+}
+
+macro_rules! cannot_create_invalid_dyn_device {
+    ($name:ident, $page_size:expr, $address_bytes:expr, $address_bits:expr) => {
+        #[test]
+        fn $name() {
+            let mut i2c = I2cMock::new(&[]);
+            assert_invalid_geometry(Eeprom24xDyn::new_custom(
+                i2c.clone(),
+                SlaveAddr::default(),
+                $page_size,
+                $address_bytes,
+                $address_bits,
+            ));
+            i2c.done();
+        }
+    };
+}
+cannot_create_invalid_dyn_device!(zero_page_size, 0, 1, 8);
+cannot_create_invalid_dyn_device!(page_size_not_power_of_two, 24, 1, 8);
+cannot_create_invalid_dyn_device!(page_size_too_big, 512, 2, 16);
+cannot_create_invalid_dyn_device!(zero_address_bytes, 16, 0, 8);
+cannot_create_invalid_dyn_device!(three_address_bytes, 16, 3, 8);
+cannot_create_invalid_dyn_device!(zero_address_bits, 16, 1, 0);
+cannot_create_invalid_dyn_device!(too_many_address_bits, 16, 2, 20);
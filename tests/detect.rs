@@ -0,0 +1,169 @@
+use eeprom24x::{Eeprom24xDyn, Error, GeometryMismatch, SlaveAddr};
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+mod common;
+use crate::common::{destroy, new_24x02, new_24x256, new_24x32, DEV_ADDR};
+
+fn read(address: u16, value: u8) -> I2cTrans {
+    I2cTrans::write_read(
+        DEV_ADDR,
+        vec![(address >> 8) as u8, address as u8],
+        vec![value],
+    )
+}
+
+fn write(address: u16, value: u8) -> I2cTrans {
+    I2cTrans::write(DEV_ADDR, vec![(address >> 8) as u8, address as u8, value])
+}
+
+/// Check the detection of a device which aliases the memory address 0 at `capacity`
+fn detection(capacity: u16, page_size: usize) {
+    let candidates = [0x1000, 0x2000, 0x4000, 0x8000];
+    let mut trans = vec![read(0, 0xAB)];
+    for c in candidates {
+        trans.push(read(c, if c >= capacity { 0xAB } else { 0x12 }));
+    }
+    trans.push(write(0, 0x54));
+    for c in candidates.iter().take_while(|c| **c <= capacity) {
+        trans.push(read(*c, if *c >= capacity { 0x54 } else { 0x12 }));
+    }
+    trans.push(write(0, 0xAB));
+    let eeprom =
+        Eeprom24xDyn::detect(I2cMock::new(&trans), SlaveAddr::default(), &mut NoopDelay).unwrap();
+    assert_eq!(capacity as usize, eeprom.capacity());
+    assert_eq!(page_size, eeprom.page_size());
+    eeprom.destroy().done();
+}
+
+#[test]
+fn can_detect_24x32() {
+    detection(0x1000, 32);
+}
+
+#[test]
+fn can_detect_24x64() {
+    detection(0x2000, 32);
+}
+
+#[test]
+fn can_detect_24x128() {
+    detection(0x4000, 64);
+}
+
+#[test]
+fn can_detect_24x256() {
+    detection(0x8000, 64);
+}
+
+#[test]
+fn can_detect_24x512() {
+    let mut trans = vec![read(0, 0xAB)];
+    for c in [0x1000, 0x2000, 0x4000, 0x8000] {
+        trans.push(read(c, 0x12));
+    }
+    trans.push(write(0, 0x54));
+    for c in [0x1000, 0x2000, 0x4000, 0x8000] {
+        trans.push(read(c, 0x12));
+    }
+    trans.push(write(0, 0xAB));
+    let eeprom =
+        Eeprom24xDyn::detect(I2cMock::new(&trans), SlaveAddr::default(), &mut NoopDelay).unwrap();
+    assert_eq!(0x10000, eeprom.capacity());
+    assert_eq!(128, eeprom.page_size());
+    eeprom.destroy().done();
+}
+
+#[test]
+fn restores_original_data_on_error() {
+    let trans = [
+        read(0, 0xAB),
+        read(0x1000, 0x12),
+        read(0x2000, 0x12),
+        read(0x4000, 0x12),
+        read(0x8000, 0x12),
+        write(0, 0x54),
+        I2cTrans::write_read(DEV_ADDR, vec![0x10, 0], vec![0]).with_error(ErrorKind::Bus),
+        write(0, 0xAB),
+    ];
+    let mut i2c = I2cMock::new(&trans);
+    match Eeprom24xDyn::detect(i2c.clone(), SlaveAddr::default(), &mut NoopDelay) {
+        Err(Error::I2C(ErrorKind::Bus)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    i2c.done();
+}
+
+fn assert_mismatch<T>(result: Result<T, Error<ErrorKind>>, expected: GeometryMismatch) {
+    match result {
+        Err(Error::GeometryMismatch(mismatch)) if mismatch == expected => (),
+        _ => panic!("Error::GeometryMismatch({:?}) not returned.", expected),
+    }
+}
+
+#[test]
+fn can_validate_matching_geometry() {
+    let trans = [
+        read(0, 0xAB),
+        read(0x4000, 0x12),
+        write(0, 0x54),
+        read(0, 0x54),
+        read(0x4000, 0x12),
+        write(0, 0xAB),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.validate_geometry(&mut NoopDelay).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_detect_smaller_device() {
+    let trans = [
+        read(0, 0xAB),
+        read(0x4000, 0xAB),
+        write(0, 0x54),
+        read(0, 0x54),
+        read(0x4000, 0x54),
+        write(0, 0xAB),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    assert_mismatch(
+        eeprom.validate_geometry(&mut NoopDelay),
+        GeometryMismatch::Capacity,
+    );
+    destroy(eeprom);
+}
+
+#[test]
+fn can_detect_address_size_mismatch() {
+    let trans = [
+        read(0, 0xAB),
+        read(0x800, 0x12),
+        write(0, 0x54),
+        read(0, 0x00),
+        write(0, 0xAB),
+    ];
+    let mut eeprom = new_24x32(&trans);
+    assert_mismatch(
+        eeprom.validate_geometry(&mut NoopDelay),
+        GeometryMismatch::AddressSize,
+    );
+    destroy(eeprom);
+}
+
+#[test]
+fn can_validate_1byte_addr_device() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0], vec![0xAB]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x80], vec![0x12]),
+        I2cTrans::write(DEV_ADDR, vec![0, 0x54]),
+        I2cTrans::write_read(DEV_ADDR, vec![0], vec![0x54]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x80], vec![0x12]),
+        I2cTrans::write(DEV_ADDR, vec![0, 0xAB]),
+    ];
+    let mut eeprom = new_24x02(&trans);
+    eeprom.validate_geometry(&mut NoopDelay).unwrap();
+    destroy(eeprom);
+}
@@ -0,0 +1,58 @@
+use eeprom24x::{Device, DeviceInfo, Eeprom24xDyn, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::DEV_ADDR;
+
+#[test]
+fn can_parse_part_name() {
+    assert_eq!(Ok(Device::AT24C256), "AT24C256".parse());
+    assert_eq!(Ok(Device::AT24CS01), "at24cs01".parse());
+}
+
+#[test]
+fn can_parse_generic_name() {
+    assert_eq!(Ok(Device::AT24C256), "24x256".parse());
+    assert_eq!(Ok(Device::AT24CM01), "24XM01".parse());
+    assert_eq!(Ok(Device::M24C02), "M24x02".parse());
+}
+
+#[test]
+fn cannot_parse_unknown_name() {
+    assert!("24x333".parse::<Device>().is_err());
+    assert!("".parse::<Device>().is_err());
+}
+
+#[test]
+fn all_devices_can_be_parsed_back() {
+    for dev in Device::ALL {
+        assert_eq!(Ok(*dev), dev.part().parse());
+        assert_eq!(Ok(*dev), dev.name().parse());
+    }
+}
+
+#[test]
+fn device_info_is_correct() {
+    assert_eq!(
+        DeviceInfo {
+            address_bits: 15,
+            address_bytes: 2,
+            page_size: 64,
+            unique_serial: false,
+        },
+        Device::AT24C256.info()
+    );
+    assert_eq!(1 << 15, Device::AT24C256.info().capacity());
+    assert!(Device::AT24CS08.info().unique_serial);
+    assert_eq!(1, Device::MC24C00.info().page_size);
+}
+
+#[test]
+fn can_create_from_device() {
+    let trans = [I2cTrans::write(DEV_ADDR | 0x3, vec![0xBC, 0xDE, 0xAB])];
+    let mut eeprom =
+        Eeprom24xDyn::new_from_device(I2cMock::new(&trans), SlaveAddr::default(), Device::AT24CM02);
+    assert_eq!(256, eeprom.page_size());
+    assert_eq!(1 << 18, eeprom.capacity());
+    eeprom.write_byte(0x3BCDE, 0xAB).unwrap();
+    eeprom.destroy().done();
+}
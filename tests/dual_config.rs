@@ -0,0 +1,65 @@
+#![cfg(feature = "config")]
+use eeprom24x::{ConfigError, DualConfig};
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+type Config = DualConfig<RamStorage<32>>;
+
+fn load(config: &mut Config) -> Vec<u8> {
+    let mut buffer = [0; 8];
+    let len = config.load(&mut buffer).unwrap();
+    buffer[..len].to_vec()
+}
+
+#[test]
+fn cannot_load_missing_config() {
+    let mut config = Config::new(RamStorage::new(0xFF));
+    let mut buffer = [0; 8];
+    match config.load(&mut buffer) {
+        Err(ConfigError::NotFound) => (),
+        _ => panic!("ConfigError::NotFound not returned."),
+    }
+}
+
+#[test]
+fn loads_latest_copy() {
+    let mut config = Config::new(RamStorage::new(0xFF));
+    config.save(b"one").unwrap();
+    config.save(b"two").unwrap();
+    config.save(b"three").unwrap();
+    assert_eq!(b"three".to_vec(), load(&mut config));
+    let mut config = Config::new(config.destroy());
+    assert_eq!(b"three".to_vec(), load(&mut config));
+}
+
+#[test]
+fn keeps_previous_copy_on_power_loss() {
+    let mut config = Config::new(RamStorage::new(0xFF));
+    config.save(b"one").unwrap();
+    config.save(b"two").unwrap();
+    let mut storage = config.destroy();
+    // the data is written but not the header
+    storage.writes_left = Some(1);
+    let mut config = Config::new(storage);
+    match config.save(b"three") {
+        Err(ConfigError::Storage(PowerLoss)) => (),
+        _ => panic!("ConfigError::Storage not returned."),
+    }
+    let mut storage = config.destroy();
+    storage.writes_left = None;
+    let mut config = Config::new(storage);
+    assert_eq!(b"two".to_vec(), load(&mut config));
+    config.save(b"four").unwrap();
+    let mut config = Config::new(config.destroy());
+    assert_eq!(b"four".to_vec(), load(&mut config));
+}
+
+#[test]
+fn cannot_save_too_large_config() {
+    let mut config = Config::new(RamStorage::new(0xFF));
+    assert_eq!(8, config.max_len());
+    match config.save(&[0; 9]) {
+        Err(ConfigError::TooLarge) => (),
+        _ => panic!("ConfigError::TooLarge not returned."),
+    }
+}
@@ -0,0 +1,329 @@
+use eeprom24x::{DynStorage, Eeprom24xDyn, Eeprom24xTrait, Error, SlaveAddr};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use embedded_storage::{ReadStorage, Storage};
+mod common;
+use crate::common::DEV_ADDR;
+
+macro_rules! create_dyn {
+    ($($create:ident),*) => {
+        $(
+            fn $create(transactions: &[I2cTrans]) -> Eeprom24xDyn<I2cMock> {
+                Eeprom24xDyn::$create(I2cMock::new(transactions), SlaveAddr::default())
+            }
+        )*
+    };
+}
+
+create_dyn!(
+    new_24x00,
+    new_24x01a,
+    new_24x02a,
+    new_24x01,
+    new_24csx01,
+    new_m24x01,
+    new_24x02,
+    new_24csx02,
+    new_24mac402,
+    new_24mac602,
+    new_m24x02,
+    new_cat24x01,
+    new_cat24x02,
+    new_34x02,
+    new_24x04,
+    new_24csx04,
+    new_24x08,
+    new_24csx08,
+    new_24x16,
+    new_24csx16,
+    new_24cswx01,
+    new_24cswx02,
+    new_24cswx04,
+    new_24cswx08,
+    new_24cswx16,
+    new_24x32,
+    new_24csx32,
+    new_24x64,
+    new_24csx64,
+    new_24cwx16,
+    new_24cwx32,
+    new_24cwx64,
+    new_24cwx128,
+    new_24x128,
+    new_24x256,
+    new_24x512,
+    new_24xm01,
+    new_24xm02
+);
+
+fn destroy(eeprom: Eeprom24xDyn<I2cMock>) {
+    eeprom.destroy().done();
+}
+
+macro_rules! can_read_byte_1byte_addr {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write_read(DEV_ADDR, vec![0xF], vec![0xAB])];
+            let mut eeprom = $create(&trans);
+            let data = eeprom.read_byte(0xF).unwrap();
+            assert_eq!(0xAB, data);
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_1b_addr!(can_read_byte_1byte_addr);
+
+macro_rules! can_read_byte_2byte_addr {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write_read(DEV_ADDR, vec![0xF, 0x34], vec![0xAB])];
+            let mut eeprom = $create(&trans);
+            let data = eeprom.read_byte(0xF34).unwrap();
+            assert_eq!(0xAB, data);
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_2b_addr!(can_read_byte_2byte_addr);
+
+macro_rules! can_write_byte_2byte_addr {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write(DEV_ADDR, vec![0xF, 0x34, 0xAB])];
+            let mut eeprom = $create(&trans);
+            eeprom.write_byte(0xF34, 0xAB).unwrap();
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_2b_addr!(can_write_byte_2byte_addr);
+
+macro_rules! has_page_size {
+    ($name:ident, $create:ident, $size:expr) => {
+        #[test]
+        fn $name() {
+            let eeprom = $create(&[]);
+            assert_eq!($size, eeprom.page_size());
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_page_size!(has_page_size);
+
+macro_rules! has_capacity {
+    ($name:ident, $create:ident, $capacity:expr) => {
+        #[test]
+        fn $name() {
+            let eeprom = $create(&[]);
+            assert_eq!($capacity, eeprom.capacity());
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_capacity!(has_capacity);
+
+macro_rules! can_write_whole_page_2byte_addr {
+    ($name:ident, $create:ident, $size:expr) => {
+        #[test]
+        fn $name() {
+            let trans =
+                common::page_write(DEV_ADDR, &[($size >> 8) as u8, $size as u8], &[0xAB; $size]);
+            let mut eeprom = $create(&trans);
+            eeprom.write_page($size as u32, &[0xAB; $size]).unwrap();
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_2b_addr_and_page_size!(can_write_whole_page_2byte_addr);
+
+macro_rules! cannot_write_so_much_data_that_page_address_would_rollover {
+    ($name:ident, $create:ident, $size:expr) => {
+        #[test]
+        fn $name() {
+            let mut eeprom = $create(&[]);
+            match eeprom.write_page(0x01, &[0xAB; $size]) {
+                Err(Error::PageOverflow { .. }) => (),
+                _ => panic!("Error::PageOverflow not returned."),
+            }
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_page_size!(cannot_write_so_much_data_that_page_address_would_rollover);
+
+#[test]
+fn can_write_single_byte_page_on_24x00() {
+    let trans = common::page_write(DEV_ADDR, &[0xF], &[0xAB]);
+    let mut eeprom = new_24x00(&trans);
+    eeprom.write_page(0xF, &[0xAB]).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_write_invalid_addr() {
+    let mut eeprom = new_24x256(&[]);
+    match eeprom.write_byte(0x8000, 0xAB) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_use_device_address_for_memory_addressing() {
+    let trans = [I2cTrans::write(DEV_ADDR | 0x3, vec![0xBC, 0xDE, 0xAB])];
+    let mut eeprom = new_24xm02(&trans);
+    eeprom.write_byte(0x3BCDE, 0xAB).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_use_through_trait() {
+    let trans = [I2cTrans::write(DEV_ADDR | 0x7, vec![0xBC, 0xAB])];
+    let mut eeprom = new_24x16(&trans);
+    let dev: &mut dyn Eeprom24xTrait<Error = _> = &mut eeprom;
+    dev.write_byte(0x7BC, 0xAB).unwrap();
+    assert_eq!(16, dev.page_size());
+    destroy(eeprom);
+}
+
+macro_rules! can_convert_typed_driver {
+    ($name:ident, $create:ident, $size:expr) => {
+        #[test]
+        fn $name() {
+            let eeprom = common::$create(&[]);
+            let eeprom = eeprom.into_dyn();
+            assert_eq!($size, eeprom.page_size());
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_page_size!(can_convert_typed_driver);
+
+#[test]
+fn can_convert_typed_driver_without_pages() {
+    let trans = [I2cTrans::write(DEV_ADDR, vec![0xF, 0xAB])];
+    let mut eeprom = common::new_24x00(&trans).into_dyn();
+    assert_eq!(16, eeprom.capacity());
+    eeprom.write_byte(0xF, 0xAB).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn converted_driver_keeps_address() {
+    let trans = [I2cTrans::write_read(
+        DEV_ADDR | 0x2,
+        vec![0x34, 0x56],
+        vec![0xAB],
+    )];
+    let eeprom = eeprom24x::Eeprom24x::new_24x256(
+        I2cMock::new(&trans),
+        SlaveAddr::Alternative(false, true, false),
+    );
+    let mut eeprom = eeprom.into_dyn();
+    assert_eq!(0xAB, eeprom.read_byte(0x3456).unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_switch_address() {
+    let trans = [I2cTrans::write(DEV_ADDR | 0x2, vec![0x12, 0x34, 0xCD])];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.set_address(SlaveAddr::Alternative(false, true, false));
+    eeprom.write_byte(0x1234, 0xCD).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_predict_device_address() {
+    let eeprom = new_24xm02(&[]);
+    assert_eq!(Some(DEV_ADDR | 0x3), eeprom.device_address(0x3BCDE));
+    assert_eq!(None, eeprom.device_address(0x40000));
+    destroy(eeprom);
+}
+
+#[test]
+fn converted_driver_keeps_max_transfer_len() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB, 0xCD]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x36], vec![0xEF]),
+    ];
+    let mut eeprom = common::new_24x256(&trans);
+    eeprom.set_max_transfer_len(2);
+    let mut eeprom = eeprom.into_dyn();
+    let mut data = [0; 3];
+    eeprom.read_data(0x1234, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD, 0xEF], data);
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_into_several_buffers_in_groups() {
+    let mut trans = vec![
+        I2cTrans::transaction_start(DEV_ADDR),
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34]),
+    ];
+    trans.extend((0..8).map(|i| I2cTrans::read(DEV_ADDR, vec![i])));
+    trans.extend([
+        I2cTrans::transaction_end(DEV_ADDR),
+        I2cTrans::transaction_start(DEV_ADDR),
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x3C]),
+        I2cTrans::read(DEV_ADDR, vec![8]),
+        I2cTrans::transaction_end(DEV_ADDR),
+    ]);
+    let mut eeprom = new_24x256(&trans);
+    let mut data = [[0; 1]; 9];
+    let mut buffers: Vec<&mut [u8]> = data.iter_mut().map(|b| &mut b[..]).collect();
+    eeprom.read_data_vectored(0x1234, &mut buffers).unwrap();
+    assert_eq!([[0], [1], [2], [3], [4], [5], [6], [7], [8]], data);
+    destroy(eeprom);
+}
+
+#[test]
+fn can_dump_with_sequential_reads() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB, 0xCD]),
+        I2cTrans::read(DEV_ADDR, vec![0xEF]),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.set_max_transfer_len(2);
+    let mut data = [0; 3];
+    eeprom.dump(0x1234, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD, 0xEF], data);
+    destroy(eeprom);
+}
+
+#[test]
+fn unchecked_write_does_not_check_page_boundary() {
+    let trans = common::page_write(DEV_ADDR, &[0x12, 0x3F], &[0xAB, 0xCD]);
+    let mut eeprom = new_24x256(&trans);
+    eeprom.write_page_unchecked(0x123F, &[0xAB, 0xCD]).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn storage_writes_over_page_boundaries() {
+    let mut trans = common::page_write(DEV_ADDR, &[0x12, 0x3F], &[0xAB]);
+    trans.extend(common::page_write(DEV_ADDR, &[0x12, 0x40], &[0xCD, 0xEF]));
+    let mut storage = DynStorage::new(new_24x256(&trans), NoopDelay);
+    storage.write(0x123F, &[0xAB, 0xCD, 0xEF]).unwrap();
+    let (mut i2c, _) = storage.destroy();
+    i2c.done();
+}
+
+#[test]
+fn storage_cannot_write_past_the_end() {
+    let mut storage = DynStorage::new(new_24x256(&[]), NoopDelay);
+    assert_eq!(32768, storage.capacity());
+    match storage.write(0x7FFF, &[0xAB, 0xCD]) {
+        Err(Error::CapacityExceeded { .. }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    let (mut i2c, _) = storage.destroy();
+    i2c.done();
+}
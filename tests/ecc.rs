@@ -0,0 +1,56 @@
+#![cfg(feature = "ecc")]
+use eeprom24x::{EccError, EccEvent, EccStorage};
+use embedded_storage::{ReadStorage, Storage};
+mod common;
+use crate::common::RamStorage;
+
+#[test]
+fn can_write_and_read() {
+    let mut storage = EccStorage::new(RamStorage::<40>::new(0xFF), |_| panic!());
+    assert_eq!(32, storage.capacity());
+    storage.write(3, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).unwrap();
+    let mut data = [0; 12];
+    storage.read(2, &mut data).unwrap();
+    assert_eq!([0xFF, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0xFF], data);
+}
+
+#[test]
+fn corrects_single_bit_error() {
+    let mut events = Vec::new();
+    let mut storage = EccStorage::new(RamStorage::<40>::new(0), |_| ());
+    storage.write(0, &[0x55; 16]).unwrap();
+    let mut raw = storage.destroy();
+    raw.data[10] ^= 0x10;
+    let mut storage = EccStorage::new(raw, |event| events.push(event));
+    let mut data = [0; 16];
+    storage.read(0, &mut data).unwrap();
+    assert_eq!([0x55; 16], data);
+    storage.destroy();
+    assert_eq!(vec![EccEvent::Corrected { offset: 8 }], events);
+}
+
+#[test]
+fn detects_double_bit_error() {
+    let mut events = Vec::new();
+    let mut storage = EccStorage::new(RamStorage::<40>::new(0), |_| ());
+    storage.write(0, &[0x55; 16]).unwrap();
+    let mut raw = storage.destroy();
+    raw.data[1] ^= 0x11;
+    let mut storage = EccStorage::new(raw, |event| events.push(event));
+    let mut data = [0; 16];
+    match storage.read(0, &mut data) {
+        Err(EccError::Uncorrectable { offset: 0 }) => (),
+        _ => panic!("EccError::Uncorrectable not returned."),
+    }
+    storage.destroy();
+    assert_eq!(vec![EccEvent::Uncorrectable { offset: 0 }], events);
+}
+
+#[test]
+fn cannot_access_beyond_capacity() {
+    let mut storage = EccStorage::new(RamStorage::<40>::new(0), |_| ());
+    match storage.write(30, &[0; 3]) {
+        Err(EccError::OutOfBounds) => (),
+        _ => panic!("EccError::OutOfBounds not returned."),
+    }
+}
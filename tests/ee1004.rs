@@ -0,0 +1,139 @@
+use eeprom24x::{Ee1004, Eeprom24xTrait, Error, SlaveAddr};
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::DEV_ADDR;
+
+const SPA0: u8 = 0x36;
+const SPA1: u8 = 0x37;
+
+fn new(transactions: &[I2cTrans]) -> Ee1004<I2cMock> {
+    Ee1004::new(I2cMock::new(transactions), SlaveAddr::default())
+}
+
+fn destroy(eeprom: Ee1004<I2cMock>) {
+    eeprom.destroy().done();
+}
+
+#[test]
+fn has_geometry() {
+    let eeprom = new(&[]);
+    assert_eq!(512, eeprom.capacity());
+    assert_eq!(16, eeprom.page_size());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_byte_from_each_page() {
+    let trans = [
+        I2cTrans::write(SPA0, vec![0]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12], vec![0xAB]),
+        I2cTrans::write(SPA1, vec![0]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12], vec![0xCD]),
+    ];
+    let mut eeprom = new(&trans);
+    assert_eq!(0xAB, eeprom.read_byte(0x12).unwrap());
+    assert_eq!(0xCD, eeprom.read_byte(0x112).unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_whole_memory() {
+    let trans = [
+        I2cTrans::write(SPA0, vec![0]),
+        I2cTrans::write_read(DEV_ADDR, vec![0], vec![0xAB; 256]),
+        I2cTrans::write(SPA1, vec![0]),
+        I2cTrans::write_read(DEV_ADDR, vec![0], vec![0xCD; 256]),
+    ];
+    let mut eeprom = new(&trans);
+    let mut data = [0; 512];
+    eeprom.read_data(0, &mut data).unwrap();
+    assert_eq!([0xAB; 256], data[..256]);
+    assert_eq!([0xCD; 256], data[256..]);
+    destroy(eeprom);
+}
+
+#[test]
+fn can_write_page_in_upper_page() {
+    let trans = [
+        I2cTrans::write(SPA1, vec![0]),
+        I2cTrans::write(DEV_ADDR, vec![0xF0, 0xAB, 0xCD]),
+    ];
+    let mut eeprom = new(&trans);
+    eeprom.write_page(0x1F0, &[0xAB, 0xCD]).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_busy_device_after_page_write() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let trans = [
+        I2cTrans::write(SPA0, vec![0]),
+        I2cTrans::write(DEV_ADDR, vec![0x10, 0xAB]),
+        I2cTrans::write(SPA0, vec![0]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![0]).with_error(nack),
+    ];
+    let mut eeprom = new(&trans);
+    eeprom.write_page(0x10, &[0xAB]).unwrap();
+    match eeprom.read_byte(0x10) {
+        Err(Error::Busy(_)) => (),
+        _ => panic!("Error::Busy not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_write_byte_through_trait() {
+    let trans = [
+        I2cTrans::write(SPA0, vec![0]),
+        I2cTrans::write(DEV_ADDR, vec![0xFF, 0xAB]),
+    ];
+    let mut eeprom = new(&trans);
+    let dev: &mut dyn Eeprom24xTrait<Error = _> = &mut eeprom;
+    dev.write_byte(0xFF, 0xAB).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_write_over_page_boundary() {
+    let mut eeprom = new(&[]);
+    match eeprom.write_page(0x0F, &[0xAB, 0xCD]) {
+        Err(Error::PageOverflow { .. }) => (),
+        _ => panic!("Error::PageOverflow not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_access_invalid_addr() {
+    let mut eeprom = new(&[]);
+    match eeprom.read_data(0x1FF, &mut [0; 2]) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    match eeprom.write_byte(0x200, 0xAB) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_temperature() {
+    let trans = [I2cTrans::write_read(0x1B, vec![0x05], vec![0x01, 0x94])];
+    let mut eeprom = Ee1004::new(I2cMock::new(&trans), SlaveAddr::from_pins(0b011));
+    assert_eq!(25.25, eeprom.read_temperature().unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_missing_temperature_sensor() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let trans = [I2cTrans::write_read(0x18, vec![0x05], vec![0, 0]).with_error(nack)];
+    let mut eeprom = new(&trans);
+    match eeprom.read_temperature() {
+        Err(Error::NoDevice(_)) => (),
+        _ => panic!("Error::NoDevice not returned."),
+    }
+    destroy(eeprom);
+}
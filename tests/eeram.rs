@@ -0,0 +1,81 @@
+use eeprom24x::{Eeprom24x, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::{destroy, new_47x16, DEV_ADDR};
+
+const CONTROL_ADDR: u8 = 0b001_1000;
+
+#[test]
+fn can_store() {
+    let trans = [I2cTrans::write(CONTROL_ADDR, vec![0x55, 0x33])];
+    let mut eeprom = new_47x16(&trans);
+    eeprom.store().unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_recall() {
+    let trans = [I2cTrans::write(CONTROL_ADDR, vec![0x55, 0xDD])];
+    let mut eeprom = new_47x16(&trans);
+    eeprom.recall().unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_status() {
+    let trans = [
+        I2cTrans::read(CONTROL_ADDR, vec![0b1000_0010]),
+        I2cTrans::read(CONTROL_ADDR, vec![0b1000_0010]),
+    ];
+    let mut eeprom = new_47x16(&trans);
+    assert!(eeprom.is_array_modified().unwrap());
+    assert!(eeprom.is_auto_store_enabled().unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_enable_auto_store_keeping_block_protection() {
+    let trans = [
+        I2cTrans::read(CONTROL_ADDR, vec![0b1001_0100]),
+        I2cTrans::write(CONTROL_ADDR, vec![0x00, 0b0001_0110]),
+    ];
+    let mut eeprom = new_47x16(&trans);
+    eeprom.set_auto_store(true).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_disable_auto_store() {
+    let trans = [
+        I2cTrans::read(CONTROL_ADDR, vec![0b0000_0010]),
+        I2cTrans::write(CONTROL_ADDR, vec![0x00, 0]),
+    ];
+    let mut eeprom = new_47x16(&trans);
+    eeprom.set_auto_store(false).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_write_array_without_page_limit() {
+    let trans = [
+        I2cTrans::transaction_start(DEV_ADDR),
+        I2cTrans::write(DEV_ADDR, vec![0x07, 0xF0]),
+        I2cTrans::write(DEV_ADDR, vec![0xAB; 16]),
+        I2cTrans::transaction_end(DEV_ADDR),
+    ];
+    let mut eeprom = new_47x16(&trans);
+    assert_eq!(2048, eeprom.capacity());
+    eeprom.write_page(0x7F0, &[0xAB; 16]).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn uses_address_pins_for_control_register() {
+    let trans = [I2cTrans::write(CONTROL_ADDR | 0b110, vec![0x55, 0x33])];
+    let mut eeprom = Eeprom24x::new_47x16(
+        I2cMock::new(&trans),
+        SlaveAddr::Alternative(true, true, false),
+    );
+    eeprom.store().unwrap();
+    destroy(eeprom);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "encryption")]
+use eeprom24x::{EncryptedStorage, EncryptionError, Keystream};
+use embedded_storage::{ReadStorage, Storage};
+mod common;
+use crate::common::RamStorage;
+
+/// Toy cipher deriving the keystream from the nonce and the position
+struct XorCipher;
+
+impl Keystream for XorCipher {
+    fn apply_keystream(&mut self, nonce: &[u8; 8], data: &mut [u8]) {
+        for (position, byte) in data.iter_mut().enumerate() {
+            *byte ^= nonce
+                .iter()
+                .fold(position as u8 ^ 0x5A, |acc, n| acc.rotate_left(3) ^ n);
+        }
+    }
+}
+
+// 3 regions of 32 bytes
+fn format() -> EncryptedStorage<RamStorage<108>, XorCipher> {
+    let mut storage = EncryptedStorage::new(RamStorage::new(0xFF), XorCipher);
+    storage.format().unwrap();
+    storage
+}
+
+#[test]
+fn can_read_written_data() {
+    let mut storage = format();
+    assert_eq!(96, storage.capacity());
+    let mut data = [0xFF; 40];
+    storage.read(20, &mut data).unwrap();
+    assert_eq!([0; 40], data);
+    storage.write(30, b"secret").unwrap();
+    let mut data = [0; 6];
+    storage.read(30, &mut data).unwrap();
+    assert_eq!(*b"secret", data);
+}
+
+#[test]
+fn data_is_encrypted() {
+    let mut storage = format();
+    storage.write(0, b"secret").unwrap();
+    let (raw, _) = storage.destroy();
+    assert!(!raw.data.windows(6).any(|window| window == b"secret"));
+}
+
+#[test]
+fn nonce_changes_on_each_write() {
+    let mut storage = format();
+    storage.write(0, b"secret").unwrap();
+    let (raw, cipher) = storage.destroy();
+    let first = raw.data;
+    let mut storage = EncryptedStorage::new(raw, cipher);
+    storage.write(0, b"secret").unwrap();
+    let (raw, _) = storage.destroy();
+    assert_ne!(first[..32], raw.data[..32]);
+}
+
+#[test]
+fn cannot_write_out_of_bounds() {
+    let mut storage = format();
+    match storage.write(95, &[0; 2]) {
+        Err(EncryptionError::OutOfBounds) => (),
+        _ => panic!("EncryptionError::OutOfBounds not returned."),
+    }
+}
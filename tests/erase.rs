@@ -0,0 +1,54 @@
+use eeprom24x::{secure_erase, EraseError};
+use embedded_storage::{ReadStorage, Storage};
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+/// Storage whose byte at offset 5 is stuck at 0x55
+struct StuckStorage(RamStorage<16>);
+
+impl ReadStorage for StuckStorage {
+    type Error = PowerLoss;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.read(offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+}
+
+impl Storage for StuckStorage {
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write(offset, bytes)?;
+        self.0.data[5] = 0x55;
+        Ok(())
+    }
+}
+
+#[test]
+fn overwrites_region() {
+    let mut storage = RamStorage::<64>::new(0x12);
+    secure_erase(&mut storage, 8..40, 3).unwrap();
+    assert_eq!([0x12; 8], storage.data[..8]);
+    assert_eq!([0xFF; 32], storage.data[8..40]);
+    assert_eq!([0x12; 24], storage.data[40..]);
+}
+
+#[test]
+fn detects_stuck_byte() {
+    let mut storage = StuckStorage(RamStorage::new(0));
+    match secure_erase(&mut storage, 0..16, 2) {
+        Err(EraseError::VerifyFailed { offset: 5 }) => (),
+        _ => panic!("EraseError::VerifyFailed not returned."),
+    }
+}
+
+#[test]
+fn cannot_erase_out_of_bounds() {
+    let mut storage = RamStorage::<16>::new(0);
+    match secure_erase(&mut storage, 8..17, 1) {
+        Err(EraseError::OutOfBounds) => (),
+        _ => panic!("EraseError::OutOfBounds not returned."),
+    }
+}
@@ -0,0 +1,58 @@
+use eeprom24x::{Error, GeometryMismatch};
+
+#[test]
+fn can_display_i2c_error() {
+    let error: Error<&str> = Error::I2C("nack");
+    assert_eq!("I2C bus error: \"nack\"", error.to_string());
+}
+
+#[test]
+fn can_display_page_overflow() {
+    let error: Error<()> = Error::PageOverflow {
+        requested: 8,
+        allowed: 4,
+    };
+    assert_eq!(
+        "write of 8 bytes crosses the page boundary, 4 bytes available",
+        error.to_string()
+    );
+}
+
+#[test]
+fn can_display_capacity_exceeded() {
+    let error: Error<()> = Error::CapacityExceeded {
+        requested: 3,
+        allowed: 1,
+    };
+    assert_eq!(
+        "write of 3 bytes exceeds the memory capacity, 1 bytes available",
+        error.to_string()
+    );
+}
+
+#[test]
+fn can_display_invalid_address() {
+    let error: Error<()> = Error::InvalidAddr {
+        address: 0x8000,
+        capacity: 32768,
+    };
+    assert_eq!(
+        "memory address 0x8000 is out of range for a capacity of 32768 bytes",
+        error.to_string()
+    );
+}
+
+#[test]
+fn can_display_geometry_mismatch() {
+    let error: Error<()> = Error::GeometryMismatch(GeometryMismatch::Capacity);
+    assert_eq!(
+        "device geometry mismatch: device is smaller than configured",
+        error.to_string()
+    );
+}
+
+#[test]
+fn can_be_used_as_std_error() {
+    let error: Box<dyn std::error::Error> = Box::new(Error::<()>::InvalidGeometry);
+    assert_eq!("invalid device geometry", error.to_string());
+}
@@ -0,0 +1,67 @@
+use eeprom24x::{Eui48, Eui64};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+mod common;
+use crate::common::{
+    destroy, new_24mac402, new_24mac602, new_24x025e48, new_24x025e64, new_24x02e48, new_24x02e64,
+    DEV_ADDR,
+};
+
+const EUI48: [u8; 6] = [0xFC, 0xC2, 0x3D, 0x01, 0x02, 0x03];
+
+#[test]
+fn can_read_eui48_24mac402() {
+    let trans = [I2cTrans::write_read(0b101_1000, vec![0x9A], EUI48.to_vec())];
+    let mut eeprom = new_24mac402(&trans);
+    assert_eq!(EUI48, eeprom.read_eui48().unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_eui48_24mac402_through_trait() {
+    let trans = [I2cTrans::write_read(0b101_1011, vec![0x9A], EUI48.to_vec())];
+    let mut eeprom = eeprom24x::Eeprom24x::new_24mac402(
+        embedded_hal_mock::eh1::i2c::Mock::new(&trans),
+        eeprom24x::SlaveAddr::from_pins(0b011),
+    );
+    let dev: &mut dyn Eui48<Error = _> = &mut eeprom;
+    assert_eq!(EUI48, dev.read_eui48().unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_eui64_24mac602() {
+    let eui64 = [0xFC, 0xC2, 0x3D, 0xFF, 0xFE, 0x01, 0x02, 0x03];
+    let trans = [I2cTrans::write_read(0b101_1000, vec![0x98], eui64.to_vec())];
+    let mut eeprom = new_24mac602(&trans);
+    assert_eq!(eui64, Eui64::read_eui64(&mut eeprom).unwrap());
+    destroy(eeprom);
+}
+
+macro_rules! can_read_microchip_eui48 {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write_read(DEV_ADDR, vec![0xFA], EUI48.to_vec())];
+            let mut eeprom = $create(&trans);
+            assert_eq!(EUI48, eeprom.read_eui48().unwrap());
+            destroy(eeprom);
+        }
+    };
+}
+can_read_microchip_eui48!(can_read_eui48_24x02e48, new_24x02e48);
+can_read_microchip_eui48!(can_read_eui48_24x025e48, new_24x025e48);
+
+macro_rules! can_read_microchip_eui64 {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let eui64 = [0xFC, 0xC2, 0x3D, 0xFF, 0xFE, 0x01, 0x02, 0x03];
+            let trans = [I2cTrans::write_read(DEV_ADDR, vec![0xF8], eui64.to_vec())];
+            let mut eeprom = $create(&trans);
+            assert_eq!(eui64, eeprom.read_eui64().unwrap());
+            destroy(eeprom);
+        }
+    };
+}
+can_read_microchip_eui64!(can_read_eui64_24x02e64, new_24x02e64);
+can_read_microchip_eui64!(can_read_eui64_24x025e64, new_24x025e64);
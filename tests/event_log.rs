@@ -0,0 +1,110 @@
+#![cfg(feature = "event-log")]
+use eeprom24x::{EventLog, LogError};
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+type Log = EventLog<RamStorage<64>>;
+
+fn records(log: &mut Log) -> Vec<Vec<u8>> {
+    let mut buffer = [0; 255];
+    let mut records = log.records();
+    let mut result = Vec::new();
+    while let Some(record) = records.next() {
+        let len = records.read(&record.unwrap(), &mut buffer).unwrap();
+        result.push(buffer[..len].to_vec());
+    }
+    result
+}
+
+#[test]
+fn cannot_mount_unformatted_storage() {
+    match Log::mount(RamStorage::new(0xFF)) {
+        Err(LogError::NotFormatted) => (),
+        _ => panic!("LogError::NotFormatted not returned."),
+    }
+}
+
+#[test]
+fn can_append_and_iterate() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    assert!(log.is_empty());
+    log.append(b"abc").unwrap();
+    log.append(b"").unwrap();
+    log.append(b"de").unwrap();
+    assert!(!log.is_empty());
+    assert_eq!(
+        vec![b"abc".to_vec(), vec![], b"de".to_vec()],
+        records(&mut log)
+    );
+    let mut log = Log::mount(log.destroy()).unwrap();
+    assert_eq!(
+        vec![b"abc".to_vec(), vec![], b"de".to_vec()],
+        records(&mut log)
+    );
+}
+
+#[test]
+fn returns_full_when_out_of_space() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    log.append(&[1; 40]).unwrap();
+    assert_eq!(13, log.remaining());
+    match log.append(&[2; 11]) {
+        Err(LogError::Full) => (),
+        _ => panic!("LogError::Full not returned."),
+    }
+    log.append(&[3; 10]).unwrap();
+    assert_eq!(0, log.remaining());
+}
+
+#[test]
+fn discards_torn_record() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    log.append(b"abc").unwrap();
+    let mut storage = log.destroy();
+    // the length and data are written but not the checksum
+    storage.writes_left = Some(2);
+    let mut log = Log::mount(storage).unwrap();
+    match log.append(b"de") {
+        Err(LogError::Storage(PowerLoss)) => (),
+        _ => panic!("LogError::Storage not returned."),
+    }
+    let mut storage = log.destroy();
+    storage.writes_left = None;
+    let mut log = Log::mount(storage).unwrap();
+    assert_eq!(vec![b"abc".to_vec()], records(&mut log));
+    log.append(b"f").unwrap();
+    let mut log = Log::mount(log.destroy()).unwrap();
+    assert_eq!(vec![b"abc".to_vec(), b"f".to_vec()], records(&mut log));
+}
+
+#[test]
+fn format_discards_records() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    log.append(b"abc").unwrap();
+    let log = Log::format(log.destroy()).unwrap();
+    let mut log = Log::mount(log.destroy()).unwrap();
+    assert!(log.is_empty());
+    assert!(records(&mut log).is_empty());
+}
+
+#[test]
+fn scan_stops_at_corrupted_record() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    log.append(b"abc").unwrap();
+    log.append(b"de").unwrap();
+    log.append(b"f").unwrap();
+    let mut storage = log.destroy();
+    // data of the second record
+    storage.data[15] ^= 1;
+    let mut log = Log::mount(storage).unwrap();
+    assert_eq!(vec![b"abc".to_vec()], records(&mut log));
+}
+
+#[test]
+fn cannot_append_too_long_record() {
+    let mut log = EventLog::format(RamStorage::<512>::new(0xFF)).unwrap();
+    match log.append(&[0; 256]) {
+        Err(LogError::RecordTooLong) => (),
+        _ => panic!("LogError::RecordTooLong not returned."),
+    }
+}
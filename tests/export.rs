@@ -0,0 +1,59 @@
+use eeprom24x::{hexdump, write_srec, ExportError};
+mod common;
+use crate::common::RamStorage;
+
+#[test]
+fn exports_srec() {
+    let mut storage = RamStorage::<64>::new(0);
+    for (i, byte) in storage.data.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    let mut out = String::new();
+    write_srec(&mut storage, 0x10..0x24, &mut out).unwrap();
+    assert_eq!(
+        "S0030000FC\n\
+         S1130010101112131415161718191A1B1C1D1E1F64\n\
+         S10700202021222352\n\
+         S5030002FA\n\
+         S9030000FC\n",
+        out
+    );
+}
+
+#[test]
+fn exports_srec_with_24_bit_addresses() {
+    let mut storage = RamStorage::<0x10004>::new(0xAA);
+    let mut out = String::new();
+    write_srec(&mut storage, 0x10000..0x10004, &mut out).unwrap();
+    assert_eq!(
+        "S0030000FC\nS208010000AAAAAAAA4E\nS5030001FB\nS804000000FB\n",
+        out
+    );
+}
+
+#[test]
+fn cannot_export_out_of_bounds() {
+    let mut storage = RamStorage::<16>::new(0);
+    match write_srec(&mut storage, 0..17, &mut String::new()) {
+        Err(ExportError::OutOfBounds) => (),
+        _ => panic!("ExportError::OutOfBounds not returned."),
+    }
+}
+
+#[test]
+fn dumps_hex() {
+    let mut storage = RamStorage::<32>::new(0xFF);
+    storage.data[16..22].copy_from_slice(b"Hello ");
+    let mut out = String::new();
+    hexdump(&mut storage, 16..32, &mut out).unwrap();
+    assert_eq!(
+        "00000010  48 65 6c 6c 6f 20 ff ff  ff ff ff ff ff ff ff ff  |Hello ..........|\n",
+        out
+    );
+    let mut out = String::new();
+    hexdump(&mut storage, 18..21, &mut out).unwrap();
+    assert_eq!(
+        "00000012  6c 6c 6f                                          |llo|\n",
+        out
+    );
+}
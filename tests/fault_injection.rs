@@ -0,0 +1,96 @@
+#![cfg(feature = "fault-injection")]
+use eeprom24x::{Eeprom24xTrait, Error, FaultError, Faults, FaultyEeprom};
+use embedded_hal::i2c::{Error as _, ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+mod common;
+use crate::common::{destroy, new_24x256, DEV_ADDR};
+
+#[test]
+fn passes_accesses_through_without_faults() {
+    let trans = [
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34, 0xAB]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB]),
+    ];
+    let mut eeprom = FaultyEeprom::new(new_24x256(&trans), Faults::default());
+    eeprom.write_byte(0x1234, 0xAB).unwrap();
+    assert_eq!(0xAB, eeprom.read_byte(0x1234).unwrap());
+    assert_eq!(2, eeprom.accesses());
+    assert_eq!(64, eeprom.page_size());
+    destroy(eeprom.destroy());
+}
+
+#[test]
+fn can_inject_nack_every_nth_access() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB]),
+    ];
+    let faults = Faults {
+        nack_every: 2,
+        ..Faults::default()
+    };
+    let mut eeprom = FaultyEeprom::new(new_24x256(&trans), faults);
+    assert_eq!(0xAB, eeprom.read_byte(0x1234).unwrap());
+    match eeprom.read_byte(0x1234) {
+        Err(Error::Busy(FaultError::Nack)) => (),
+        _ => panic!("Error::Busy not returned."),
+    }
+    assert_eq!(0xAB, eeprom.read_byte(0x1234).unwrap());
+    destroy(eeprom.destroy());
+}
+
+#[test]
+fn can_inject_timeout() {
+    let mut eeprom = FaultyEeprom::new(
+        new_24x256(&[]),
+        Faults {
+            timeout_every: 1,
+            ..Faults::default()
+        },
+    );
+    match eeprom.write_page(0x1234, &[0xAB, 0xCD]) {
+        Err(Error::I2C(FaultError::Timeout)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    destroy(eeprom.destroy());
+}
+
+#[test]
+fn can_corrupt_read_data() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB, 0xCD]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB, 0xCD]),
+    ];
+    let faults = Faults {
+        corrupt_every: 2,
+        corrupt_mask: 0x01,
+        ..Faults::default()
+    };
+    let mut eeprom = FaultyEeprom::new(new_24x256(&trans), faults);
+    let mut data = [0; 2];
+    eeprom.read_data(0x1234, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD], data);
+    eeprom.read_data(0x1234, &mut data).unwrap();
+    assert_eq!([0xAA, 0xCC], data);
+    destroy(eeprom.destroy());
+}
+
+#[test]
+fn wraps_device_errors() {
+    let trans = [I2cTrans::write(DEV_ADDR, vec![0x12, 0x34, 0xAB]).with_error(ErrorKind::Bus)];
+    let mut eeprom = FaultyEeprom::new(new_24x256(&trans), Faults::default());
+    match eeprom.write_byte(0x1234, 0xAB) {
+        Err(Error::I2C(FaultError::Device(ErrorKind::Bus))) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    destroy(eeprom.destroy());
+}
+
+#[test]
+fn reports_error_kinds() {
+    assert_eq!(
+        ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address),
+        FaultError::<ErrorKind>::Nack.kind()
+    );
+    assert_eq!(ErrorKind::Bus, FaultError::Device(ErrorKind::Bus).kind());
+}
@@ -0,0 +1,109 @@
+use eeprom24x::{CachedEeprom, Eeprom24xTrait, EepromChain, Error, Storage};
+use embedded_hal::delay::DelayNs;
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use embedded_storage::Storage as _;
+mod common;
+use crate::common::{destroy, new_fm24w256, new_mb85rc256, new_mb85rc64, page_write, DEV_ADDR};
+
+struct PanicDelay;
+
+impl DelayNs for PanicDelay {
+    fn delay_ns(&mut self, _ns: u32) {
+        panic!("unexpected delay");
+    }
+}
+
+fn write_trans(address: u16, data: &[u8]) -> Vec<I2cTrans> {
+    page_write(DEV_ADDR, &address.to_be_bytes(), data)
+}
+
+#[test]
+fn has_capacity() {
+    let eeprom = new_mb85rc64(&[]);
+    assert_eq!(8192, eeprom.capacity());
+    destroy(eeprom);
+    let eeprom = new_mb85rc256(&[]);
+    assert_eq!(32768, eeprom.capacity());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_write_data_across_page_boundaries() {
+    let data = [0xAB; 300];
+    let trans = write_trans(0x1F0, &data);
+    let mut eeprom = new_mb85rc256(&trans);
+    eeprom.write_page(0x1F0, &data).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_write_until_the_end() {
+    let trans = write_trans(0x7FFE, &[0xAB, 0xCD]);
+    let mut eeprom = new_fm24w256(&trans);
+    eeprom.write_page(0x7FFE, &[0xAB, 0xCD]).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_write_past_the_end() {
+    let mut eeprom = new_mb85rc64(&[]);
+    match eeprom.write_page(0x1FFF, &[0xAB, 0xCD]) {
+        Err(Error::CapacityExceeded { .. }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn reports_capacity_as_page_size() {
+    let eeprom = new_mb85rc64(&[]);
+    let dev: &dyn Eeprom24xTrait<Error = _> = &eeprom;
+    assert_eq!(8192, dev.page_size());
+    destroy(eeprom);
+}
+
+#[test]
+fn storage_writes_in_one_transfer_without_delay() {
+    let data = [0xAB; 100];
+    let trans = write_trans(0x30, &data);
+    let mut storage = Storage::new(new_mb85rc256(&trans), PanicDelay);
+    storage.write(0x30, &data).unwrap();
+    destroy(storage.eeprom);
+}
+
+#[test]
+fn validates_geometry_without_delay() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0, 0], vec![0xAB]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x40, 0], vec![0x12]),
+        I2cTrans::write(DEV_ADDR, vec![0, 0, 0x54]),
+        I2cTrans::write_read(DEV_ADDR, vec![0, 0], vec![0x54]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x40, 0], vec![0x12]),
+        I2cTrans::write(DEV_ADDR, vec![0, 0, 0xAB]),
+    ];
+    let mut eeprom = new_mb85rc256(&trans);
+    eeprom.validate_geometry(&mut PanicDelay).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn chain_writes_without_delay() {
+    let data = [0xAB; 100];
+    let trans = write_trans(0x30, &data);
+    let mut chain = EepromChain::new([new_mb85rc256(&trans)], PanicDelay);
+    chain.write(0x30, &data).unwrap();
+    let ([eeprom], _) = chain.destroy();
+    destroy(eeprom);
+}
+
+#[test]
+fn cache_writes_back_without_delay() {
+    let data = [0xAB; 16];
+    let trans = write_trans(0x40, &data);
+    let mut cache: CachedEeprom<_, _, 1, 16> =
+        CachedEeprom::new(new_mb85rc256(&trans), PanicDelay).unwrap();
+    cache.write(0x40, &data).unwrap();
+    cache.flush().unwrap();
+    let (eeprom, _) = cache.destroy();
+    destroy(eeprom);
+}
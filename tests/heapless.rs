@@ -0,0 +1,37 @@
+#![cfg(feature = "heapless")]
+use eeprom24x::{Eeprom24x, Error, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::{destroy, DEV_ADDR};
+
+#[test]
+fn can_read_heapless() {
+    let trans = [I2cTrans::write_read(
+        DEV_ADDR,
+        vec![0x12, 0x34],
+        vec![0xAB, 0xCD, 0xEF],
+    )];
+    let mut eeprom = Eeprom24x::new_24x256(I2cMock::new(&trans), SlaveAddr::default());
+    let data = eeprom.read_heapless::<3>(0x1234).unwrap();
+    assert_eq!(&[0xAB, 0xCD, 0xEF], &data[..]);
+    destroy(eeprom);
+}
+
+#[test]
+fn read_heapless_stops_at_end_of_memory() {
+    let trans = [I2cTrans::write_read(DEV_ADDR, vec![0xE], vec![0xAB, 0xCD])];
+    let mut eeprom = Eeprom24x::new_24x00(I2cMock::new(&trans), SlaveAddr::default());
+    let data = eeprom.read_heapless::<8>(0xE).unwrap();
+    assert_eq!(&[0xAB, 0xCD], &data[..]);
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_read_heapless_out_of_bounds() {
+    let mut eeprom = Eeprom24x::new_24x00(I2cMock::new(&[]), SlaveAddr::default());
+    match eeprom.read_heapless::<8>(0x10) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    destroy(eeprom);
+}
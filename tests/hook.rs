@@ -0,0 +1,45 @@
+use eeprom24x::{Eeprom24x, Error, HookedI2c, SharedBus, SlaveAddr};
+use embedded_hal::i2c::{ErrorKind, I2c};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::DEV_ADDR;
+
+const MUX_ADDR: u8 = 0x70;
+
+#[test]
+fn calls_hook_before_each_access() {
+    let trans = [
+        I2cTrans::write(MUX_ADDR, vec![0b01]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB]),
+        I2cTrans::write(MUX_ADDR, vec![0b10]),
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34, 0xAB]),
+    ];
+    let bus = SharedBus::new(I2cMock::new(&trans));
+    let i2c0 = HookedI2c::new(bus.acquire(), |i2c: &mut _| {
+        I2c::write(i2c, MUX_ADDR, &[0b01])
+    });
+    let i2c1 = HookedI2c::new(bus.acquire(), |i2c: &mut _| {
+        I2c::write(i2c, MUX_ADDR, &[0b10])
+    });
+    let mut eeprom0 = Eeprom24x::new_24x256(i2c0, SlaveAddr::default());
+    let mut eeprom1 = Eeprom24x::new_24x256(i2c1, SlaveAddr::default());
+    let data = eeprom0.read_byte(0x1234).unwrap();
+    eeprom1.write_byte(0x1234, data).unwrap();
+    eeprom0.destroy();
+    eeprom1.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn returns_hook_errors() {
+    let trans = [I2cTrans::write(MUX_ADDR, vec![0b01]).with_error(ErrorKind::Bus)];
+    let i2c = HookedI2c::new(I2cMock::new(&trans), |i2c: &mut I2cMock| {
+        i2c.write(MUX_ADDR, &[0b01])
+    });
+    let mut eeprom = Eeprom24x::new_24x256(i2c, SlaveAddr::default());
+    match eeprom.read_byte(0x1234) {
+        Err(Error::I2C(ErrorKind::Bus)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    eeprom.destroy().destroy().0.done();
+}
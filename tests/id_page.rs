@@ -0,0 +1,77 @@
+use eeprom24x::{Error, SlaveAddr};
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+mod common;
+use crate::common::{
+    destroy, new_24csx32, new_24csx64, new_24cwx128, new_24cwx32, new_24cwx64, new_24x128,
+    new_24x256, new_24x32, new_24x512, new_24x64, new_24xm01, new_24xm02, DEV_ADDR,
+};
+
+const ID_PAGE_ADDR: u8 = 0b101_1000;
+
+macro_rules! can_read_id_page_lock_status {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [
+                I2cTrans::write(ID_PAGE_ADDR, vec![0x04, 0x00, 0x00]),
+                I2cTrans::write(ID_PAGE_ADDR, vec![0x04, 0x00, 0x00])
+                    .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data)),
+            ];
+            let mut eeprom = $create(&trans);
+            assert!(!eeprom.is_id_page_locked().unwrap());
+            assert!(eeprom.is_id_page_locked().unwrap());
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics_with_2b_addr!(can_read_id_page_lock_status);
+
+#[test]
+fn id_page_lock_status_returns_address_nack() {
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let trans = [I2cTrans::write(ID_PAGE_ADDR, vec![0x04, 0x00, 0x00]).with_error(nack)];
+    let mut eeprom = new_24x256(&trans);
+    match eeprom.is_id_page_locked() {
+        Err(Error::NoDevice(e)) if e == nack => (),
+        _ => panic!("Error::NoDevice not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_cda() {
+    let trans = [I2cTrans::write_read(
+        ID_PAGE_ADDR,
+        vec![0xC0, 0x00],
+        vec![0b1010],
+    )];
+    let mut eeprom = new_24x256(&trans);
+    assert_eq!(0b1010, eeprom.read_cda().unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn writing_cda_changes_device_address() {
+    let trans = [
+        I2cTrans::write(ID_PAGE_ADDR, vec![0xC0, 0x00, 0b1010]),
+        I2cTrans::write_read(DEV_ADDR | 0b101, vec![0x12, 0x34], vec![0xAB]),
+        I2cTrans::write_read(ID_PAGE_ADDR | 0b101, vec![0xC0, 0x00], vec![0b1010]),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.write_cda(0b101).unwrap();
+    assert_eq!(SlaveAddr::from_pins(0b101), eeprom.address());
+    assert_eq!(0xAB, eeprom.read_byte(0x1234).unwrap());
+    assert_eq!(0b1010, eeprom.read_cda().unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn failed_cda_write_keeps_device_address() {
+    let trans =
+        [I2cTrans::write(ID_PAGE_ADDR, vec![0xC0, 0x00, 0b0010]).with_error(ErrorKind::Bus)];
+    let mut eeprom = new_24x256(&trans);
+    assert!(eeprom.write_cda(0b001).is_err());
+    assert_eq!(SlaveAddr::default(), eeprom.address());
+    destroy(eeprom);
+}
@@ -1,13 +1,18 @@
 use std::fmt::Debug;
 
-use eeprom24x::{Eeprom24xTrait, Error};
-use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use eeprom24x::{
+    page_size, DeviceId, Eeprom24x, Eeprom24x00, Eeprom24x16, Eeprom24x256, Eeprom24xTrait, Error,
+    SlaveAddr,
+};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
 mod common;
 use crate::common::{
-    destroy, new_24csx01, new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32,
-    new_24csx64, new_24x00, new_24x01, new_24x02, new_24x04, new_24x08, new_24x128, new_24x16,
-    new_24x256, new_24x32, new_24x512, new_24x64, new_24xm01, new_24xm02, new_m24x01, new_m24x02,
-    DEV_ADDR,
+    destroy, new_24cswx01, new_24cswx02, new_24cswx04, new_24cswx08, new_24cswx16, new_24csx01,
+    new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32, new_24csx64, new_24cwx128,
+    new_24cwx16, new_24cwx32, new_24cwx64, new_24mac402, new_24mac602, new_24x00, new_24x01,
+    new_24x01a, new_24x02, new_24x02a, new_24x04, new_24x08, new_24x128, new_24x16, new_24x256,
+    new_24x32, new_24x512, new_24x64, new_24xm01, new_24xm02, new_34x02, new_cat24x01,
+    new_cat24x02, new_m24x01, new_m24x02, page_write, DEV_ADDR,
 };
 
 macro_rules! construction_test {
@@ -143,7 +148,7 @@ macro_rules! can_write_array_1byte_addr {
     ($name:ident, $create:ident, $page_size:expr) => {
         #[test]
         fn $name() {
-            let trans = [I2cTrans::write(DEV_ADDR, vec![0x34, 0xAB, 0xCD, 0xEF])];
+            let trans = page_write(DEV_ADDR, &[0x34], &[0xAB, 0xCD, 0xEF]);
             let mut eeprom = $create(&trans);
             eeprom.write_page(0x34, &[0xAB, 0xCD, 0xEF]).unwrap();
             destroy(eeprom);
@@ -156,7 +161,7 @@ macro_rules! can_write_array_2byte_addr {
     ($name:ident, $create:ident, $page_size:expr) => {
         #[test]
         fn $name() {
-            let trans = [I2cTrans::write(DEV_ADDR, vec![0xF, 0x34, 0xAB, 0xCD, 0xEF])];
+            let trans = page_write(DEV_ADDR, &[0xF, 0x34], &[0xAB, 0xCD, 0xEF]);
             let mut eeprom = $create(&trans);
             eeprom.write_page(0xF34, &[0xAB, 0xCD, 0xEF]).unwrap();
             destroy(eeprom);
@@ -166,21 +171,37 @@ macro_rules! can_write_array_2byte_addr {
 for_all_ics_with_2b_addr_and_page_size!(can_write_array_2byte_addr);
 
 // only available since Rust 1.31: #[allow(clippy::needless_pass_by_value)]
-fn assert_too_much_data<T, E>(result: Result<T, Error<E>>) {
+fn assert_page_overflow<T, E>(result: Result<T, Error<E>>) {
     match result {
-        Err(Error::TooMuchData) => (),
-        _ => panic!("Error::TooMuchData not returned."),
+        Err(Error::PageOverflow { .. }) => (),
+        _ => panic!("Error::PageOverflow not returned."),
     }
 }
 #[test]
 fn check_data_assert_matches() {
-    assert_too_much_data::<(), ()>(Err(Error::TooMuchData));
+    assert_page_overflow::<(), ()>(Err(Error::PageOverflow {
+        requested: 0,
+        allowed: 0,
+    }));
 }
 
 #[test]
 #[should_panic]
 fn check_data_assert_fails() {
-    assert_too_much_data::<(), ()>(Ok(()));
+    assert_page_overflow::<(), ()>(Ok(()));
+}
+
+#[test]
+fn reports_requested_and_allowed_length() {
+    let mut eeprom = new_24x04(&[]);
+    match eeprom.write_page(0x1C, &[0xAB; 8]) {
+        Err(Error::PageOverflow {
+            requested: 8,
+            allowed: 4,
+        }) => (),
+        _ => panic!("Error::PageOverflow with payload not returned."),
+    }
+    destroy(eeprom);
 }
 
 macro_rules! cannot_write_too_big_page {
@@ -188,7 +209,7 @@ macro_rules! cannot_write_too_big_page {
         #[test]
         fn $name() {
             let mut eeprom = $create(&[]);
-            assert_too_much_data(eeprom.write_page(0x34, &[0xAB; 1 + $size]));
+            assert_page_overflow(eeprom.write_page(0x34, &[0xAB; 1 + $size]));
             destroy(eeprom);
         }
     };
@@ -200,7 +221,7 @@ macro_rules! cannot_write_so_much_data_that_page_address_would_rollover {
         #[test]
         fn $name() {
             let mut eeprom = $create(&[]);
-            assert_too_much_data(eeprom.write_page(0x01, &[0xAB; $size]));
+            assert_page_overflow(eeprom.write_page(0x01, &[0xAB; $size]));
             destroy(eeprom);
         }
     };
@@ -211,9 +232,7 @@ macro_rules! can_write_whole_page_1byte_addr {
     ($name:ident, $create:ident, $size:expr) => {
         #[test]
         fn $name() {
-            let mut data = vec![$size];
-            data.extend_from_slice(&[0xAB; $size]);
-            let trans = [I2cTrans::write(DEV_ADDR, data)];
+            let trans = page_write(DEV_ADDR, &[$size], &[0xAB; $size]);
             let mut eeprom = $create(&trans);
             eeprom.write_page($size, &[0xAB; $size]).unwrap();
             destroy(eeprom);
@@ -226,9 +245,7 @@ macro_rules! can_write_whole_page_2byte_addr {
     ($name:ident, $create:ident, $size:expr) => {
         #[test]
         fn $name() {
-            let mut data = vec![($size >> 8) as u8, $size as u8];
-            data.extend_from_slice(&[0xAB; $size]);
-            let trans = [I2cTrans::write(DEV_ADDR, data)];
+            let trans = page_write(DEV_ADDR, &[($size >> 8) as u8, $size as u8], &[0xAB; $size]);
             let mut eeprom = $create(&trans);
             eeprom.write_page($size as u32, &[0xAB; $size]).unwrap();
             destroy(eeprom);
@@ -264,3 +281,253 @@ fn can_pass_device_to_function() {
     write_byte(&mut eeprom);
     destroy(eeprom);
 }
+
+#[test]
+fn page_size_markers_are_const_generic() {
+    let eeprom: Eeprom24x<_, page_size::Bytes<64>, _, _> = new_24x256(&[]);
+    assert_eq!(64, Eeprom24xTrait::page_size(&eeprom));
+    destroy(eeprom);
+}
+
+#[test]
+fn device_type_aliases_match_constructors() {
+    let eeprom: Eeprom24x00<_> = new_24x00(&[]);
+    destroy(eeprom);
+    let eeprom: Eeprom24x16<_> = new_24x16(&[]);
+    destroy(eeprom);
+    let eeprom: Eeprom24x256<_> = new_24x256(&[]);
+    destroy(eeprom);
+}
+
+#[test]
+fn can_use_custom_slave_address() {
+    let trans = [I2cTrans::write(0b101_1101, vec![0xBC, 0xAB])];
+    let mut eeprom = Eeprom24x::new_24x16(I2cMock::new(&trans), SlaveAddr::Custom(0b101_1000));
+    eeprom.write_byte(0x5BC, 0xAB).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_use_custom_slave_address_with_memory_bits() {
+    let mut eeprom = Eeprom24x::new_24x16(I2cMock::new(&[]), SlaveAddr::Custom(0b101_1001));
+    match eeprom.write_byte(0x5BC, 0xAB) {
+        Err(Error::InvalidSlaveAddr) => (),
+        _ => panic!("Error::InvalidSlaveAddr not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_device_id() {
+    let trans = [I2cTrans::write_read(
+        0x7C,
+        vec![DEV_ADDR << 1],
+        vec![0x00, 0xD0, 0xD3],
+    )];
+    let mut eeprom = new_24x256(&trans);
+    let id = eeprom.read_device_id().unwrap();
+    assert_eq!(
+        DeviceId {
+            manufacturer: 0x00D,
+            part: 0x01A,
+            revision: 3
+        },
+        id
+    );
+    destroy(eeprom);
+}
+
+#[test]
+fn can_switch_address() {
+    let trans = [
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34, 0xAB]),
+        I2cTrans::write(DEV_ADDR | 0x5, vec![0x12, 0x34, 0xCD]),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.write_byte(0x1234, 0xAB).unwrap();
+    eeprom.set_address(SlaveAddr::from_pins(0b101));
+    assert_eq!(SlaveAddr::from_pins(0b101), eeprom.address());
+    eeprom.write_byte(0x1234, 0xCD).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_predict_device_address() {
+    let eeprom = new_24x16(&[]);
+    assert_eq!(Some(DEV_ADDR), eeprom.device_address(0xFF));
+    assert_eq!(Some(DEV_ADDR | 0x7), eeprom.device_address(0x7FF));
+    assert_eq!(None, eeprom.device_address(0x800));
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_predict_device_address_for_invalid_slave_address() {
+    let eeprom = Eeprom24x::new_24x16(I2cMock::new(&[]), SlaveAddr::Custom(0x51));
+    assert_eq!(None, eeprom.device_address(0));
+    destroy(eeprom);
+}
+
+fn new_24x1025(transactions: &[I2cTrans], address: SlaveAddr) -> eeprom24x::Eeprom24x1025<I2cMock> {
+    Eeprom24x::new_24x1025(I2cMock::new(transactions), address)
+}
+
+#[test]
+fn uses_block_select_bit_on_24x1025() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR | 0b011, vec![0x12, 0x34], vec![0xAB]),
+        I2cTrans::write(DEV_ADDR | 0b111, vec![0x12, 0x34, 0xCD]),
+    ];
+    let mut eeprom = new_24x1025(&trans, SlaveAddr::from_pins(0b011));
+    assert_eq!(1 << 17, eeprom.capacity());
+    assert_eq!(128, eeprom.page_size());
+    assert_eq!(0xAB, eeprom.read_byte(0x0_1234).unwrap());
+    eeprom.write_byte(0x1_1234, 0xCD).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_across_block_boundary_on_24x1025() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR | 0b011, vec![0xFF, 0xFF], vec![0xAB]),
+        I2cTrans::write_read(DEV_ADDR | 0b111, vec![0x00, 0x00], vec![0xCD, 0xEF]),
+        I2cTrans::write_read(DEV_ADDR | 0b011, vec![0xFF, 0xFF], vec![0x12]),
+        I2cTrans::write_read(DEV_ADDR | 0b111, vec![0x00, 0x00], vec![0x34]),
+    ];
+    let mut eeprom = new_24x1025(&trans, SlaveAddr::from_pins(0b011));
+    let mut data = [0; 3];
+    eeprom.read_data(0xFFFF, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD, 0xEF], data);
+    let (mut header, mut payload) = ([0; 1], [0; 1]);
+    eeprom
+        .read_data_vectored(0xFFFF, &mut [&mut header, &mut payload])
+        .unwrap();
+    assert_eq!([0x12], header);
+    assert_eq!([0x34], payload);
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_use_a2_on_24x1025() {
+    let mut eeprom = new_24x1025(&[], SlaveAddr::from_pins(0b100));
+    match eeprom.read_byte(0) {
+        Err(Error::InvalidSlaveAddr) => (),
+        _ => panic!("Error::InvalidSlaveAddr not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_split_reads_into_transfers() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0xF, 0xFE], vec![0xAB, 0xCD]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x10, 0x00], vec![0xEF, 0x12]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x10, 0x02], vec![0x34]),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.set_max_transfer_len(2);
+    let mut data = [0; 5];
+    eeprom.read_data(0xFFE, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD, 0xEF, 0x12, 0x34], data);
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_across_device_address_boundary_in_transfers() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0xFF], vec![0xAB]),
+        I2cTrans::write_read(DEV_ADDR | 1, vec![0x00], vec![0xCD]),
+    ];
+    let mut eeprom = new_24x16(&trans);
+    eeprom.set_max_transfer_len(1);
+    let mut data = [0; 2];
+    eeprom.read_data(0xFF, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD], data);
+    destroy(eeprom);
+}
+
+#[test]
+fn unchecked_read_wraps_around_at_the_end() {
+    let trans = [I2cTrans::write_read(DEV_ADDR | 1, vec![0x23], vec![0xAB])];
+    let mut eeprom = new_24x16(&trans);
+    let mut data = [0; 1];
+    eeprom.read_data_unchecked(0x923, &mut data).unwrap();
+    assert_eq!([0xAB], data);
+    destroy(eeprom);
+}
+
+#[test]
+fn unchecked_write_does_not_check_page_boundary() {
+    let trans = page_write(DEV_ADDR, &[0x12, 0x3F], &[0xAB, 0xCD]);
+    let mut eeprom = new_24x256(&trans);
+    eeprom.write_page_unchecked(0x123F, &[0xAB, 0xCD]).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_dump_with_sequential_reads() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x0F, 0xFE], vec![0xAB, 0xCD]),
+        I2cTrans::read(DEV_ADDR, vec![0xEF, 0x12]),
+        I2cTrans::read(DEV_ADDR, vec![0x34]),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.set_max_transfer_len(2);
+    let mut data = [0; 5];
+    eeprom.dump(0xFFE, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD, 0xEF, 0x12, 0x34], data);
+    destroy(eeprom);
+}
+
+#[test]
+fn dump_sends_address_again_for_other_device_address() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0xFE], vec![0xAB]),
+        I2cTrans::read(DEV_ADDR, vec![0xCD]),
+        I2cTrans::write_read(DEV_ADDR | 1, vec![0x00], vec![0xEF]),
+    ];
+    let mut eeprom = new_24x16(&trans);
+    eeprom.set_max_transfer_len(1);
+    let mut data = [0; 3];
+    eeprom.dump(0xFE, &mut data).unwrap();
+    assert_eq!([0xAB, 0xCD, 0xEF], data);
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_into_several_buffers_in_one_transaction() {
+    let trans = [
+        I2cTrans::transaction_start(DEV_ADDR),
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34]),
+        I2cTrans::read(DEV_ADDR, vec![0xAB, 0xCD]),
+        I2cTrans::read(DEV_ADDR, vec![0xEF]),
+        I2cTrans::transaction_end(DEV_ADDR),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    let mut header = [0; 2];
+    let mut empty = [0; 0];
+    let mut payload = [0; 1];
+    eeprom
+        .read_data_vectored(0x1234, &mut [&mut header, &mut empty, &mut payload])
+        .unwrap();
+    assert_eq!([0xAB, 0xCD], header);
+    assert_eq!([0xEF], payload);
+    destroy(eeprom);
+}
+
+#[test]
+fn reads_several_buffers_separately_with_max_transfer_len() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB, 0xCD]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x36], vec![0xEF]),
+    ];
+    let mut eeprom = new_24x256(&trans);
+    eeprom.set_max_transfer_len(2);
+    let mut header = [0; 2];
+    let mut payload = [0; 1];
+    eeprom
+        .read_data_vectored(0x1234, &mut [&mut header, &mut payload])
+        .unwrap();
+    assert_eq!([0xAB, 0xCD], header);
+    assert_eq!([0xEF], payload);
+    destroy(eeprom);
+}
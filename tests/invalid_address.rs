@@ -1,21 +1,27 @@
 use eeprom24x::Error;
 mod common;
 use crate::common::{
-    destroy, new_24csx01, new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32,
-    new_24csx64, new_24x00, new_24x01, new_24x02, new_24x04, new_24x08, new_24x128, new_24x16,
-    new_24x256, new_24x32, new_24x512, new_24x64, new_24xm01, new_24xm02, new_m24x01, new_m24x02,
+    destroy, new_24cswx01, new_24cswx02, new_24cswx04, new_24cswx08, new_24cswx16, new_24csx01,
+    new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32, new_24csx64, new_24cwx128,
+    new_24cwx32, new_24cwx64, new_24mac402, new_24mac602, new_24x00, new_24x01, new_24x01a,
+    new_24x02, new_24x02a, new_24x04, new_24x08, new_24x128, new_24x16, new_24x256, new_24x32,
+    new_24x512, new_24x64, new_24xm01, new_24xm02, new_34x02, new_cat24x01, new_cat24x02,
+    new_m24x01, new_m24x02,
 };
 
 // only available since Rust 1.31: #[allow(clippy::needless_pass_by_value)]
 fn assert_invalid_address<T, E>(result: Result<T, Error<E>>) {
     match result {
-        Err(Error::InvalidAddr) => (),
+        Err(Error::InvalidAddr { .. }) => (),
         _ => panic!("Error::InvalidAddr not returned."),
     }
 }
 #[test]
 fn check_addr_assert_matches() {
-    assert_invalid_address::<(), ()>(Err(Error::InvalidAddr));
+    assert_invalid_address::<(), ()>(Err(Error::InvalidAddr {
+        address: 0,
+        capacity: 0,
+    }));
 }
 
 #[test]
@@ -61,3 +67,16 @@ fn cannot_write_to_position_over_capacity_2bytes() {
     assert_invalid_address(eeprom.write_byte(0xFFFF, 0xAB));
     destroy(eeprom);
 }
+
+#[test]
+fn reports_offending_address_and_capacity() {
+    let mut eeprom = new_24x256(&[]);
+    match eeprom.read_byte(0x8000) {
+        Err(Error::InvalidAddr {
+            address: 0x8000,
+            capacity: 0x8000,
+        }) => (),
+        _ => panic!("Error::InvalidAddr with payload not returned."),
+    }
+    destroy(eeprom);
+}
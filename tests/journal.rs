@@ -0,0 +1,85 @@
+#![cfg(feature = "journal")]
+use eeprom24x::{Journal, JournalError};
+use embedded_storage::ReadStorage;
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+type Store = Journal<RamStorage<64>>;
+
+fn read(journal: &mut Store) -> [u8; 32] {
+    let mut data = [0; 32];
+    journal.read(0, &mut data).unwrap();
+    data
+}
+
+#[test]
+fn applies_writes_on_commit() {
+    let mut journal = Store::new(RamStorage::new(0), 32).unwrap();
+    assert_eq!(32, journal.capacity());
+    journal.write(0, &[1, 2]).unwrap();
+    journal.write(30, &[3, 4]).unwrap();
+    assert_eq!([0; 32], read(&mut journal));
+    journal.commit().unwrap();
+    let data = read(&mut journal);
+    assert_eq!([1, 2, 0], data[..3]);
+    assert_eq!([3, 4], data[30..]);
+}
+
+#[test]
+fn can_abort() {
+    let mut journal = Store::new(RamStorage::new(0), 32).unwrap();
+    journal.write(0, &[1, 2]).unwrap();
+    journal.abort();
+    journal.commit().unwrap();
+    assert_eq!([0; 32], read(&mut journal));
+}
+
+#[test]
+fn discards_uncommitted_writes_after_power_loss() {
+    let mut journal = Store::new(RamStorage::new(0), 32).unwrap();
+    journal.write(0, &[1, 2]).unwrap();
+    let mut journal = Store::new(journal.destroy(), 32).unwrap();
+    journal.commit().unwrap();
+    assert_eq!([0; 32], read(&mut journal));
+}
+
+#[test]
+fn completes_committed_transaction_after_power_loss() {
+    let mut storage = RamStorage::new(0);
+    // the entries, the commit header and the first write are done
+    storage.writes_left = Some(6);
+    let mut journal = Store::new(storage, 32).unwrap();
+    journal.write(0, &[1, 2]).unwrap();
+    journal.write(30, &[3, 4]).unwrap();
+    match journal.commit() {
+        Err(JournalError::Storage(PowerLoss)) => (),
+        _ => panic!("JournalError::Storage not returned."),
+    }
+    let mut storage = journal.destroy();
+    assert_eq!([1, 2], storage.data[32..34]);
+    assert_eq!([0, 0], storage.data[62..]);
+    storage.writes_left = None;
+    let mut journal = Store::new(storage, 32).unwrap();
+    let data = read(&mut journal);
+    assert_eq!([1, 2], data[..2]);
+    assert_eq!([3, 4], data[30..]);
+}
+
+#[test]
+fn cannot_write_beyond_data_area() {
+    let mut journal = Store::new(RamStorage::new(0), 32).unwrap();
+    match journal.write(31, &[1, 2]) {
+        Err(JournalError::OutOfBounds) => (),
+        _ => panic!("JournalError::OutOfBounds not returned."),
+    }
+}
+
+#[test]
+fn cannot_write_more_than_journal_holds() {
+    let mut journal = Store::new(RamStorage::new(0), 32).unwrap();
+    journal.write(0, &[1; 10]).unwrap();
+    match journal.write(10, &[1; 10]) {
+        Err(JournalError::JournalFull) => (),
+        _ => panic!("JournalError::JournalFull not returned."),
+    }
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "kv-store")]
+use eeprom24x::{KvError, KvStore};
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+type Store = KvStore<RamStorage<128>, 4>;
+
+fn format() -> Store {
+    KvStore::format(RamStorage::new(0xAA)).unwrap()
+}
+
+fn get(store: &mut Store, key: &[u8; 4]) -> Option<Vec<u8>> {
+    let mut value = [0; 255];
+    let len = store.get(key, &mut value).unwrap()?;
+    Some(value[..len].to_vec())
+}
+
+#[test]
+fn cannot_mount_unformatted_storage() {
+    match Store::mount(RamStorage::new(0xFF)) {
+        Err(KvError::NotFormatted) => (),
+        _ => panic!("KvError::NotFormatted not returned."),
+    }
+}
+
+#[test]
+fn cannot_mount_with_other_key_length() {
+    let storage = format().destroy();
+    match KvStore::<_, 2>::mount(storage) {
+        Err(KvError::NotFormatted) => (),
+        _ => panic!("KvError::NotFormatted not returned."),
+    }
+}
+
+#[test]
+fn can_set_and_get() {
+    let mut store = format();
+    assert_eq!(None, get(&mut store, b"baud"));
+    store.set(b"baud", &[1, 2, 3]).unwrap();
+    store.set(b"mode", &[4]).unwrap();
+    store.set(b"baud", &[5, 6]).unwrap();
+    assert_eq!(Some(vec![5, 6]), get(&mut store, b"baud"));
+    assert_eq!(Some(vec![4]), get(&mut store, b"mode"));
+}
+
+#[test]
+fn can_remove() {
+    let mut store = format();
+    store.set(b"baud", &[1]).unwrap();
+    store.remove(b"baud").unwrap();
+    assert_eq!(None, get(&mut store, b"baud"));
+    store.remove(b"mode").unwrap();
+}
+
+#[test]
+fn keeps_values_after_mounting() {
+    let mut store = format();
+    store.set(b"baud", &[1, 2]).unwrap();
+    store.set(b"mode", &[]).unwrap();
+    let mut store = Store::mount(store.destroy()).unwrap();
+    assert_eq!(Some(vec![1, 2]), get(&mut store, b"baud"));
+    assert_eq!(Some(vec![]), get(&mut store, b"mode"));
+    store.set(b"baud", &[3]).unwrap();
+    assert_eq!(Some(vec![3]), get(&mut store, b"baud"));
+}
+
+#[test]
+fn compacts_when_full() {
+    let mut store = format();
+    store.set(b"mode", &[7]).unwrap();
+    store.set(b"gone", &[8]).unwrap();
+    store.remove(b"gone").unwrap();
+    for i in 0..20 {
+        store.set(b"baud", &[i; 4]).unwrap();
+    }
+    assert_eq!(Some(vec![19; 4]), get(&mut store, b"baud"));
+    assert_eq!(Some(vec![7]), get(&mut store, b"mode"));
+    assert_eq!(None, get(&mut store, b"gone"));
+    let mut store = Store::mount(store.destroy()).unwrap();
+    assert_eq!(Some(vec![19; 4]), get(&mut store, b"baud"));
+    assert_eq!(Some(vec![7]), get(&mut store, b"mode"));
+}
+
+#[test]
+fn discards_interrupted_update() {
+    let mut store = format();
+    store.set(b"baud", &[1]).unwrap();
+    let mut storage = store.destroy();
+    storage.writes_left = Some(3);
+    let mut store = Store::mount(storage).unwrap();
+    assert_eq!(Err(KvError::Storage(PowerLoss)), store.set(b"baud", &[2]));
+    let mut storage = store.destroy();
+    storage.writes_left = None;
+    let mut store = Store::mount(storage).unwrap();
+    assert_eq!(Some(vec![1]), get(&mut store, b"baud"));
+    store.set(b"mode", &[3]).unwrap();
+    assert_eq!(Some(vec![3]), get(&mut store, b"mode"));
+}
+
+#[test]
+fn keeps_values_after_interrupted_compaction() {
+    let mut store = format();
+    store.set(b"baud", &[1; 8]).unwrap();
+    store.set(b"mode", &[2; 8]).unwrap();
+    let mut storage = store.destroy();
+    storage.writes_left = Some(1);
+    let mut store = Store::mount(storage).unwrap();
+    assert!(store.compact().is_err());
+    let mut storage = store.destroy();
+    storage.writes_left = None;
+    let mut store = Store::mount(storage).unwrap();
+    assert_eq!(Some(vec![1; 8]), get(&mut store, b"baud"));
+    assert_eq!(Some(vec![2; 8]), get(&mut store, b"mode"));
+}
+
+#[test]
+fn cannot_read_into_small_buffer() {
+    let mut store = format();
+    store.set(b"baud", &[1, 2]).unwrap();
+    let mut value = [0; 1];
+    assert_eq!(
+        Err(KvError::BufferTooSmall { required: 2 }),
+        store.get(b"baud", &mut value)
+    );
+}
+
+#[test]
+fn cannot_store_too_long_value() {
+    let mut store = format();
+    assert_eq!(Err(KvError::ValueTooLong), store.set(b"baud", &[0; 256]));
+}
+
+#[test]
+fn reports_full_store() {
+    let mut store = format();
+    store.set(b"baud", &[1; 40]).unwrap();
+    assert_eq!(Err(KvError::Full), store.set(b"mode", &[2; 20]));
+    assert_eq!(Some(vec![1; 40]), get(&mut store, b"baud"));
+}
@@ -0,0 +1,115 @@
+use eeprom24x::{addr_size, page_size, unique_serial, Eeprom24x, Error, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::{destroy, page_write};
+
+const USER_ADDR: u8 = 0x53;
+const SYSTEM_ADDR: u8 = 0x57;
+
+fn new_m24lr64e(
+    transactions: &[I2cTrans],
+) -> Eeprom24x<I2cMock, page_size::B4, addr_size::TwoBytes, unique_serial::M24lr> {
+    Eeprom24x::new_m24lr64e(
+        I2cMock::new(transactions),
+        SlaveAddr::Alternative(false, true, true),
+    )
+}
+
+#[test]
+fn can_write_user_memory() {
+    let trans = page_write(USER_ADDR, &[0x1F, 0xFC], &[1, 2, 3, 4]);
+    let mut eeprom = new_m24lr64e(&trans);
+    assert_eq!(8192, eeprom.capacity());
+    eeprom.write_page(0x1FFC, &[1, 2, 3, 4]).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_present_password() {
+    let trans = [I2cTrans::write(
+        SYSTEM_ADDR,
+        vec![
+            0x09, 0x00, 0x12, 0x34, 0x56, 0x78, 0x09, 0x12, 0x34, 0x56, 0x78,
+        ],
+    )];
+    let mut eeprom = new_m24lr64e(&trans);
+    eeprom.present_password(0x1234_5678).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_write_password() {
+    let trans = [I2cTrans::write(
+        SYSTEM_ADDR,
+        vec![
+            0x09, 0x00, 0xAB, 0xCD, 0xEF, 0x01, 0x07, 0xAB, 0xCD, 0xEF, 0x01,
+        ],
+    )];
+    let mut eeprom = new_m24lr64e(&trans);
+    eeprom.write_password(0xABCD_EF01).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_sector_security() {
+    let trans = [I2cTrans::write_read(SYSTEM_ADDR, vec![0, 63], vec![0b1011])];
+    let mut eeprom = new_m24lr64e(&trans);
+    assert_eq!(0b1011, eeprom.read_sector_security(63).unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_write_sector_security() {
+    let trans = [I2cTrans::write(SYSTEM_ADDR, vec![0, 2, 0b1011])];
+    let mut eeprom = new_m24lr64e(&trans);
+    eeprom.write_sector_security(2, 0b1011).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_access_invalid_sector() {
+    let mut eeprom =
+        Eeprom24x::new_m24lr04e(I2cMock::new(&[]), SlaveAddr::Alternative(false, true, true));
+    match eeprom.read_sector_security(4) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    match eeprom.write_sector_security(4, 0) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_configuration() {
+    let trans = [I2cTrans::write_read(
+        SYSTEM_ADDR,
+        vec![0x09, 0x10],
+        vec![0xF4],
+    )];
+    let mut eeprom = new_m24lr64e(&trans);
+    assert_eq!(0xF4, eeprom.read_configuration().unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn can_write_configuration() {
+    let trans = [I2cTrans::write(SYSTEM_ADDR, vec![0x09, 0x10, 0xF8])];
+    let mut eeprom = new_m24lr64e(&trans);
+    eeprom.write_configuration(0xF8).unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_uid() {
+    let uid = [1, 2, 3, 4, 5, 6, 7, 8];
+    let trans = [I2cTrans::write_read(
+        SYSTEM_ADDR,
+        vec![0x09, 0x14],
+        uid.to_vec(),
+    )];
+    let mut eeprom = new_m24lr64e(&trans);
+    assert_eq!(uid, eeprom.read_uid().unwrap());
+    destroy(eeprom);
+}
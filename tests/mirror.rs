@@ -0,0 +1,62 @@
+#![cfg(feature = "mirror")]
+use eeprom24x::{Mirror, MirrorError};
+use embedded_storage::{ReadStorage, Storage};
+mod common;
+use crate::common::RamStorage;
+
+fn formatted() -> Mirror<RamStorage<64>> {
+    let mut mirror = Mirror::new(RamStorage::new(0xFF));
+    mirror.format().unwrap();
+    mirror
+}
+
+#[test]
+fn can_write_and_read() {
+    let mut mirror = formatted();
+    assert_eq!(28, mirror.capacity());
+    mirror.write(10, b"abcdefgh").unwrap();
+    let mut data = [0; 10];
+    mirror.read(9, &mut data).unwrap();
+    assert_eq!(*b"\0abcdefgh\0", data);
+    let storage = mirror.destroy();
+    assert_eq!(storage.data[..32], storage.data[32..]);
+}
+
+#[test]
+fn repairs_bad_copy_on_read() {
+    let mut mirror = formatted();
+    mirror.write(0, b"abc").unwrap();
+    let mut storage = mirror.destroy();
+    storage.data[33] ^= 0x01;
+    let mut mirror = Mirror::new(storage);
+    let mut data = [0; 3];
+    mirror.read(0, &mut data).unwrap();
+    assert_eq!(*b"abc", data);
+    let storage = mirror.destroy();
+    assert_eq!(storage.data[..32], storage.data[32..]);
+}
+
+#[test]
+fn scrub_repairs_all_blocks() {
+    let mut mirror = formatted();
+    let mut storage = mirror.destroy();
+    storage.data[1] ^= 0x01;
+    storage.data[48] ^= 0x80;
+    mirror = Mirror::new(storage);
+    assert_eq!(2, mirror.scrub().unwrap());
+    assert_eq!(0, mirror.scrub().unwrap());
+}
+
+#[test]
+fn reports_corrupted_block() {
+    let mirror = formatted();
+    let mut storage = mirror.destroy();
+    storage.data[20] ^= 0x01;
+    storage.data[52] ^= 0x01;
+    let mut mirror = Mirror::new(storage);
+    let mut data = [0; 1];
+    match mirror.read(14, &mut data) {
+        Err(MirrorError::Corrupted { offset: 14 }) => (),
+        _ => panic!("MirrorError::Corrupted not returned."),
+    }
+}
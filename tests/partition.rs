@@ -0,0 +1,67 @@
+use eeprom24x::{
+    addr_size, page_size, unique_serial, Eeprom24x, Error, Partition, SlaveAddr, Storage,
+};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use embedded_storage::{ReadStorage, Storage as _};
+mod common;
+use crate::common::{destroy, page_write, DEV_ADDR};
+
+type Eeprom = Storage<I2cMock, page_size::B8, addr_size::OneByte, unique_serial::No, NoopDelay>;
+
+fn new_storage(transactions: &[I2cTrans]) -> Eeprom {
+    let eeprom = Eeprom24x::new_24x02(I2cMock::new(transactions), SlaveAddr::default());
+    Storage::new(eeprom, NoopDelay)
+}
+
+#[test]
+fn accesses_are_offset() {
+    let mut trans = page_write(DEV_ADDR, &[0x42], &[0xAB, 0xCD]);
+    trans.push(I2cTrans::write_read(DEV_ADDR, vec![0x43], vec![0xCD]));
+    let mut storage = new_storage(&trans);
+    let mut partition = Partition::new(&mut storage, 0x40, 0x10).unwrap();
+    assert_eq!(0x10, partition.capacity());
+    assert_eq!(0x40, partition.offset());
+    partition.write(2, &[0xAB, 0xCD]).unwrap();
+    let mut data = [0];
+    partition.read(3, &mut data).unwrap();
+    assert_eq!([0xCD], data);
+    destroy(storage.eeprom);
+}
+
+#[test]
+fn cannot_create_partition_beyond_storage() {
+    let mut storage = new_storage(&[]);
+    match Partition::new(&mut storage, 0xF8, 0x10) {
+        Err(Error::CapacityExceeded {
+            requested: 0x10,
+            allowed: 8,
+        }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy(storage.eeprom);
+}
+
+#[test]
+fn cannot_access_beyond_partition() {
+    let mut storage = new_storage(&[]);
+    let mut partition = Partition::new(&mut storage, 0x40, 0x10).unwrap();
+    let mut data = [0; 2];
+    match partition.read(0xF, &mut data) {
+        Err(Error::InvalidAddr {
+            address: 0xF,
+            capacity: 0x10,
+        }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    match partition.write(0xF, &[0xAB, 0xCD]) {
+        Err(Error::CapacityExceeded {
+            requested: 2,
+            allowed: 1,
+        }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy(storage.eeprom);
+}
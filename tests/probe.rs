@@ -0,0 +1,121 @@
+use eeprom24x::{scan, Error};
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::{
+    destroy, new_24cswx01, new_24cswx02, new_24cswx04, new_24cswx08, new_24cswx16, new_24csx01,
+    new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32, new_24csx64, new_24cwx128,
+    new_24cwx16, new_24cwx32, new_24cwx64, new_24mac402, new_24mac602, new_24x00, new_24x01,
+    new_24x01a, new_24x02, new_24x02a, new_24x04, new_24x08, new_24x128, new_24x16, new_24x256,
+    new_24x32, new_24x512, new_24x64, new_24xm01, new_24xm02, new_34x02, new_cat24x01,
+    new_cat24x02, new_m24x01, new_m24x02, DEV_ADDR,
+};
+
+fn nack() -> ErrorKind {
+    ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)
+}
+
+#[test]
+fn can_scan_bus() {
+    let trans: Vec<_> = (0x50..=0x57)
+        .map(|addr| {
+            let t = I2cTrans::write(addr, vec![]);
+            if addr == 0x50 || addr == 0x53 {
+                t
+            } else {
+                t.with_error(nack())
+            }
+        })
+        .collect();
+    let mut i2c = I2cMock::new(&trans);
+    assert_eq!(0b0000_1001, scan(&mut i2c).unwrap());
+    i2c.done();
+}
+
+#[test]
+fn scan_returns_other_bus_errors() {
+    let trans = [
+        I2cTrans::write(0x50, vec![]),
+        I2cTrans::write(0x51, vec![]).with_error(ErrorKind::ArbitrationLoss),
+    ];
+    let mut i2c = I2cMock::new(&trans);
+    match scan(&mut i2c) {
+        Err(Error::I2C(ErrorKind::ArbitrationLoss)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    i2c.done();
+}
+
+macro_rules! can_check_presence {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [
+                I2cTrans::write(DEV_ADDR, vec![]),
+                I2cTrans::write(DEV_ADDR, vec![]).with_error(nack()),
+            ];
+            let mut eeprom = $create(&trans);
+            assert!(eeprom.is_present().unwrap());
+            assert!(!eeprom.is_present().unwrap());
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics!(can_check_presence);
+
+#[test]
+fn presence_check_returns_other_bus_errors() {
+    let trans = [I2cTrans::write(DEV_ADDR, vec![]).with_error(ErrorKind::Bus)];
+    let mut eeprom = new_24x256(&trans);
+    match eeprom.is_present() {
+        Err(Error::I2C(ErrorKind::Bus)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_check_presence_of_dyn_device() {
+    let trans = [I2cTrans::write(DEV_ADDR, vec![]).with_error(nack())];
+    let mut eeprom = new_24x256(&trans).into_dyn();
+    assert!(!eeprom.is_present().unwrap());
+    eeprom.destroy().done();
+}
+
+macro_rules! can_recover_bus {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [
+                I2cTrans::read(0x7F, vec![]).with_error(ErrorKind::ArbitrationLoss),
+                I2cTrans::write(DEV_ADDR, vec![]).with_error(nack()),
+            ];
+            let mut eeprom = $create(&trans);
+            eeprom.bus_recover().unwrap();
+            destroy(eeprom);
+        }
+    };
+}
+for_all_ics!(can_recover_bus);
+
+#[test]
+fn bus_recovery_returns_other_bus_errors() {
+    let trans = [I2cTrans::read(0x7F, vec![]).with_error(ErrorKind::Bus)];
+    let mut eeprom = new_24x256(&trans);
+    match eeprom.bus_recover() {
+        Err(Error::I2C(ErrorKind::Bus)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_recover_bus_of_dyn_device() {
+    let trans = [
+        I2cTrans::read(0x7F, vec![]).with_error(nack()),
+        I2cTrans::write(DEV_ADDR, vec![]),
+    ];
+    let mut eeprom = new_24x256(&trans).into_dyn();
+    eeprom.bus_recover().unwrap();
+    eeprom.destroy().done();
+}
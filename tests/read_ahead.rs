@@ -0,0 +1,78 @@
+use eeprom24x::{addr_size, page_size, unique_serial, Eeprom24x, ReadAhead, SlaveAddr, Storage};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use embedded_storage::{ReadStorage, Storage as _};
+mod common;
+use crate::common::{destroy, page_write, DEV_ADDR};
+
+type Reader =
+    ReadAhead<Storage<I2cMock, page_size::B8, addr_size::OneByte, unique_serial::No, NoopDelay>, 4>;
+
+fn new_reader(transactions: &[I2cTrans]) -> Reader {
+    let eeprom = Eeprom24x::new_24x02(I2cMock::new(transactions), SlaveAddr::default());
+    ReadAhead::new(Storage::new(eeprom, NoopDelay))
+}
+
+fn destroy_reader(reader: Reader) {
+    destroy(reader.destroy().eeprom);
+}
+
+#[test]
+fn reads_bytes_in_one_transfer() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![1, 2, 3, 4]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x14], vec![5, 6, 7, 8]),
+    ];
+    let mut reader = new_reader(&trans);
+    let data: Vec<u8> = (0x10..0x15).map(|a| reader.read_byte(a).unwrap()).collect();
+    assert_eq!(vec![1, 2, 3, 4, 5], data);
+    destroy_reader(reader);
+}
+
+#[test]
+fn reads_large_data_directly() {
+    let trans = [I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![1, 2, 3, 4])];
+    let mut reader = new_reader(&trans);
+    let mut data = [0; 4];
+    reader.read(0x10, &mut data).unwrap();
+    assert_eq!([1, 2, 3, 4], data);
+    destroy_reader(reader);
+}
+
+#[test]
+fn does_not_read_past_the_end() {
+    let trans = [I2cTrans::write_read(DEV_ADDR, vec![0xFE], vec![1, 2])];
+    let mut reader = new_reader(&trans);
+    let mut data = [0; 2];
+    reader.read(0xFE, &mut data).unwrap();
+    assert_eq!([1, 2], data);
+    destroy_reader(reader);
+}
+
+#[test]
+fn updates_buffer_on_write() {
+    let mut trans = vec![I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![1, 2, 3, 4])];
+    trans.extend(page_write(DEV_ADDR, &[0x12], &[0xAB, 0xCD, 0xEF]));
+    let mut reader = new_reader(&trans);
+    assert_eq!(1, reader.read_byte(0x10).unwrap());
+    reader.write(0x12, &[0xAB, 0xCD, 0xEF]).unwrap();
+    let mut data = [0; 3];
+    reader.read(0x11, &mut data).unwrap();
+    assert_eq!([2, 0xAB, 0xCD], data);
+    destroy_reader(reader);
+}
+
+#[test]
+fn can_invalidate_buffer() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![1, 2, 3, 4]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x10], vec![5, 6, 7, 8]),
+    ];
+    let mut reader = new_reader(&trans);
+    assert_eq!(1, reader.read_byte(0x10).unwrap());
+    reader.invalidate();
+    assert_eq!(5, reader.read_byte(0x10).unwrap());
+    destroy_reader(reader);
+}
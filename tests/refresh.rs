@@ -0,0 +1,26 @@
+use eeprom24x::{refresh, refresh_if_older};
+mod common;
+use crate::common::RamStorage;
+
+#[test]
+fn rewrites_region() {
+    let mut storage = RamStorage::<200>::new(0);
+    for (i, byte) in storage.data.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    storage.writes_left = Some(3);
+    // 10..150 spans chunks 0..64, 64..128 and 128..150
+    refresh(&mut storage, 10..150).unwrap();
+    assert_eq!(Some(0), storage.writes_left);
+    assert!(storage.data.iter().enumerate().all(|(i, b)| *b == i as u8));
+}
+
+#[test]
+fn refreshes_only_old_regions() {
+    let mut storage = RamStorage::<64>::new(0xFF);
+    assert!(refresh_if_older(&mut storage, 0..60, 60, 100, 365).unwrap());
+    assert_eq!(100u32.to_le_bytes(), storage.data[60..]);
+    assert!(!refresh_if_older(&mut storage, 0..60, 60, 464, 365).unwrap());
+    assert!(refresh_if_older(&mut storage, 0..60, 60, 465, 365).unwrap());
+    assert_eq!(465u32.to_le_bytes(), storage.data[60..]);
+}
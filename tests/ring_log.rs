@@ -0,0 +1,145 @@
+#![cfg(feature = "ring-log")]
+use eeprom24x::{LogError, RingLog};
+mod common;
+use crate::common::RamStorage;
+
+type Log = RingLog<RamStorage<80>>;
+
+fn records(log: &mut Log) -> Vec<Vec<u8>> {
+    let mut buffer = [0; 255];
+    let mut records = Vec::new();
+    log.for_each(&mut buffer, |record| records.push(record.to_vec()))
+        .unwrap();
+    records
+}
+
+#[test]
+fn cannot_mount_unformatted_storage() {
+    match Log::mount(RamStorage::new(0xFF)) {
+        Err(LogError::NotFormatted) => (),
+        _ => panic!("LogError::NotFormatted not returned."),
+    }
+}
+
+#[test]
+fn can_push_and_pop() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    assert!(log.is_empty());
+    log.push(b"abc").unwrap();
+    log.push(b"de").unwrap();
+    let mut buffer = [0; 8];
+    assert_eq!(Some(3), log.peek(&mut buffer).unwrap());
+    assert_eq!(Some(3), log.pop(&mut buffer).unwrap());
+    assert_eq!(b"abc", &buffer[..3]);
+    assert_eq!(Some(2), log.pop(&mut buffer).unwrap());
+    assert_eq!(b"de", &buffer[..2]);
+    assert_eq!(None, log.pop(&mut buffer).unwrap());
+    assert!(log.is_empty());
+}
+
+#[test]
+fn overwrites_oldest_records() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    for i in 0..10u8 {
+        log.push(&[i; 4]).unwrap();
+    }
+    // 40 data bytes hold three records of 11 bytes
+    assert_eq!(vec![vec![7; 4], vec![8; 4], vec![9; 4]], records(&mut log));
+}
+
+#[test]
+fn keeps_records_when_mounted() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    for i in 0..5u8 {
+        log.push(&[i; 3]).unwrap();
+    }
+    let mut buffer = [0; 3];
+    log.pop(&mut buffer).unwrap();
+    let mut log = Log::mount(log.destroy()).unwrap();
+    assert_eq!(vec![vec![3; 3], vec![4; 3]], records(&mut log));
+}
+
+#[test]
+fn recovers_records_written_after_pointer_update() {
+    let log = Log::format(RamStorage::new(0xFF)).unwrap();
+    let mut storage = log.destroy();
+    // only the header, data and checksum of each record are written
+    storage.writes_left = Some(6);
+    let mut log = Log::mount(storage).unwrap();
+    log.push(b"a").unwrap();
+    log.push(b"b").unwrap();
+    let mut storage = log.destroy();
+    storage.writes_left = None;
+    let mut log = Log::mount(storage).unwrap();
+    assert_eq!(vec![b"a".to_vec(), b"b".to_vec()], records(&mut log));
+}
+
+#[test]
+fn ignores_partially_written_record() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    log.push(b"a").unwrap();
+    let mut storage = log.destroy();
+    storage.writes_left = Some(2);
+    let mut log = Log::mount(storage).unwrap();
+    assert!(log.push(b"b").is_err());
+    let mut storage = log.destroy();
+    storage.writes_left = None;
+    let mut log = Log::mount(storage).unwrap();
+    assert_eq!(vec![b"a".to_vec()], records(&mut log));
+    log.push(b"c").unwrap();
+    assert_eq!(vec![b"a".to_vec(), b"c".to_vec()], records(&mut log));
+}
+
+#[test]
+fn format_discards_records() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    log.push(b"a").unwrap();
+    let log = Log::format(log.destroy()).unwrap();
+    assert!(log.is_empty());
+    let mut log = Log::mount(log.destroy()).unwrap();
+    assert!(records(&mut log).is_empty());
+}
+
+#[test]
+fn cannot_push_too_long_record() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    match log.push(&[0; 40]) {
+        Err(LogError::RecordTooLong) => (),
+        _ => panic!("LogError::RecordTooLong not returned."),
+    }
+}
+
+#[test]
+fn cannot_read_into_small_buffer() {
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    log.push(b"abc").unwrap();
+    let mut buffer = [0; 2];
+    match log.peek(&mut buffer) {
+        Err(LogError::BufferTooSmall { required: 3 }) => (),
+        _ => panic!("LogError::BufferTooSmall not returned."),
+    }
+}
+
+#[test]
+fn writer_appends_lines() {
+    use core::fmt::Write;
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    let mut writer = log.writer();
+    write!(writer, "t={}", 42).unwrap();
+    writeln!(writer, " ok").unwrap();
+    writeln!(writer).unwrap();
+    write!(writer, "tail").unwrap();
+    writer.flush().unwrap();
+    writer.flush().unwrap();
+    assert_eq!(
+        vec![b"t=42 ok".to_vec(), vec![], b"tail".to_vec()],
+        records(&mut log)
+    );
+}
+
+#[test]
+fn writer_reports_errors() {
+    use core::fmt::Write;
+    let mut log = Log::format(RamStorage::new(0xFF)).unwrap();
+    assert!(writeln!(log.writer(), "{}", "x".repeat(40)).is_err());
+}
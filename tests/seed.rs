@@ -0,0 +1,55 @@
+#![cfg(feature = "seed")]
+use eeprom24x::{SeedError, SeedStore};
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+type Seeds = SeedStore<RamStorage<128>>;
+
+#[test]
+fn cannot_get_seed_before_rotate() {
+    let mut seeds = Seeds::new(RamStorage::new(0xFF));
+    match seeds.next_seed() {
+        Err(SeedError::NotInitialized) => (),
+        _ => panic!("SeedError::NotInitialized not returned."),
+    }
+}
+
+#[test]
+fn seeds_differ_across_reboots() {
+    let mut seeds = Seeds::new(RamStorage::new(0xFF));
+    seeds.rotate(&[0xAB; 32]).unwrap();
+    let first = seeds.next_seed().unwrap();
+    let mut seeds = Seeds::new(seeds.destroy());
+    let second = seeds.next_seed().unwrap();
+    assert_ne!(first, second);
+    assert_eq!(first[..28], [0xAB; 28]);
+    assert_eq!(second[..28], [0xAB; 28]);
+}
+
+#[test]
+fn can_rotate_seed() {
+    let mut seeds = Seeds::new(RamStorage::new(0xFF));
+    seeds.rotate(&[1; 32]).unwrap();
+    seeds.next_seed().unwrap();
+    seeds.rotate(&[2; 32]).unwrap();
+    assert_eq!(seeds.next_seed().unwrap()[..28], [2; 28]);
+}
+
+#[test]
+fn seed_is_not_reused_after_power_loss() {
+    let mut seeds = Seeds::new(RamStorage::new(0xFF));
+    seeds.rotate(&[0; 32]).unwrap();
+    let first = seeds.next_seed().unwrap();
+    let mut storage = seeds.destroy();
+    storage.writes_left = Some(0);
+    let mut seeds = Seeds::new(storage);
+    match seeds.next_seed() {
+        Err(SeedError::Storage(PowerLoss)) => (),
+        _ => panic!("SeedError::Storage not returned."),
+    }
+    let mut storage = seeds.destroy();
+    storage.writes_left = None;
+    let mut seeds = Seeds::new(storage);
+    let second = seeds.next_seed().unwrap();
+    assert_ne!(first, second);
+}
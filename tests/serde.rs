@@ -0,0 +1,23 @@
+#![cfg(feature = "serde")]
+
+use eeprom24x::{Device, DeviceInfo, SlaveAddr};
+
+#[test]
+fn can_round_trip_slave_address() {
+    let address = SlaveAddr::Alternative(true, false, true);
+    let json = serde_json::to_string(&address).unwrap();
+    assert_eq!(address, serde_json::from_str::<SlaveAddr>(&json).unwrap());
+}
+
+#[test]
+fn can_deserialize_device() {
+    let device: Device = serde_json::from_str("\"AT24C256\"").unwrap();
+    assert_eq!(Device::AT24C256, device);
+}
+
+#[test]
+fn can_round_trip_device_info() {
+    let info = Device::AT24C256.info();
+    let json = serde_json::to_string(&info).unwrap();
+    assert_eq!(info, serde_json::from_str::<DeviceInfo>(&json).unwrap());
+}
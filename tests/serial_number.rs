@@ -1,8 +1,11 @@
-use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+use eeprom24x::{Eeprom24x, Error, Irreversible, SlaveAddr};
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
 mod common;
 use crate::common::{
-    destroy, new_24csx01, new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32,
-    new_24csx64,
+    destroy, new_24cswx01, new_24cswx02, new_24cswx04, new_24cswx08, new_24cswx16, new_24csx01,
+    new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32, new_24csx64, new_24mac402,
+    new_24mac602,
 };
 
 #[allow(unused)]
@@ -47,3 +50,201 @@ macro_rules! can_read_serial_number_2byte_addr {
 }
 
 for_all_with_serial_with_2b_addr!(can_read_serial_number_2byte_addr);
+
+macro_rules! can_read_serial_number_csw {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write_read(
+                0b101_1000,
+                vec![0x00],
+                DEV_SERIAL.to_vec(),
+            )];
+            let mut eeprom = $create(&trans);
+            let serial_number = eeprom.read_unique_serial().unwrap();
+            assert_eq!(DEV_SERIAL, serial_number);
+            destroy(eeprom);
+        }
+    };
+}
+for_all_with_security_register!(can_read_serial_number_csw);
+
+macro_rules! can_write_security_register {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write(0b101_1000, vec![0x1E, 0xAB, 0xCD])];
+            let mut eeprom = $create(&trans);
+            eeprom.write_security_register(14, &[0xAB, 0xCD]).unwrap();
+            destroy(eeprom);
+        }
+    };
+}
+for_all_with_security_register!(can_write_security_register);
+
+#[test]
+fn cannot_write_security_register_out_of_range() {
+    let mut eeprom = new_24cswx02(&[]);
+    match eeprom.write_security_register(16, &[0xAB]) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    match eeprom.write_security_register(15, &[0xAB, 0xCD]) {
+        Err(Error::CapacityExceeded { .. }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy(eeprom);
+}
+
+macro_rules! can_lock_security_register {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write(0b011_0000, vec![0x60, 0x00])];
+            let mut eeprom = $create(&trans);
+            eeprom
+                .lock_security_register(Irreversible::confirm())
+                .unwrap();
+            destroy(eeprom);
+        }
+    };
+}
+for_all_with_security_register!(can_lock_security_register);
+
+#[test]
+fn lock_command_uses_pin_address_bits() {
+    let trans = [I2cTrans::write(0b011_0100, vec![0x60, 0x00])];
+    let mut eeprom = Eeprom24x::new_24cswx08(I2cMock::new(&trans), SlaveAddr::from_pins(0b111));
+    eeprom
+        .lock_security_register(Irreversible::confirm())
+        .unwrap();
+    destroy(eeprom);
+}
+
+macro_rules! can_check_security_register_lock {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [
+                I2cTrans::write(0b011_0000, vec![0x60]),
+                I2cTrans::write(0b011_0000, vec![0x60])
+                    .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data)),
+            ];
+            let mut eeprom = $create(&trans);
+            assert!(!eeprom.is_security_register_locked().unwrap());
+            assert!(eeprom.is_security_register_locked().unwrap());
+            destroy(eeprom);
+        }
+    };
+}
+for_all_with_security_register!(can_check_security_register_lock);
+
+#[test]
+fn security_register_lock_check_returns_other_errors() {
+    let trans = [I2cTrans::write(0b011_0000, vec![0x60]).with_error(ErrorKind::Bus)];
+    let mut eeprom = new_24cswx01(&trans);
+    match eeprom.is_security_register_locked() {
+        Err(Error::I2C(ErrorKind::Bus)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    destroy(eeprom);
+}
+
+macro_rules! can_read_security_region_1byte_addr {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write_read(
+                0b101_1000,
+                vec![0x84],
+                vec![0xAB, 0xCD],
+            )];
+            let mut eeprom = $create(&trans);
+            let mut data = [0; 2];
+            eeprom.read_security_region(4, &mut data).unwrap();
+            assert_eq!([0xAB, 0xCD], data);
+            destroy(eeprom);
+        }
+    };
+}
+can_read_security_region_1byte_addr!(can_read_security_region_24csx01, new_24csx01);
+can_read_security_region_1byte_addr!(can_read_security_region_24csx16, new_24csx16);
+
+#[test]
+fn can_read_security_region_2byte_addr() {
+    let trans = [I2cTrans::write_read(
+        0b101_1000,
+        vec![0x08, 0x0F],
+        vec![0xAB],
+    )];
+    let mut eeprom = new_24csx64(&trans);
+    let mut data = [0];
+    eeprom.read_security_region(15, &mut data).unwrap();
+    assert_eq!([0xAB], data);
+    destroy(eeprom);
+}
+
+macro_rules! can_read_whole_security_register {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write_read(0b101_1000, vec![0x00], vec![0xAB; 32])];
+            let mut eeprom = $create(&trans);
+            let mut data = [0; 32];
+            eeprom.read_security_region(0, &mut data).unwrap();
+            assert_eq!([0xAB; 32], data);
+            destroy(eeprom);
+        }
+    };
+}
+for_all_with_security_register!(can_read_whole_security_register);
+
+#[test]
+fn cannot_read_beyond_security_region() {
+    let mut eeprom = new_24csx32(&[]);
+    match eeprom.read_security_region(16, &mut [0]) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    match eeprom.read_security_region(8, &mut [0; 9]) {
+        Err(Error::CapacityExceeded { .. }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy(eeprom);
+
+    let mut eeprom = new_24cswx04(&[]);
+    match eeprom.read_security_region(16, &mut [0; 17]) {
+        Err(Error::CapacityExceeded { .. }) => (),
+        _ => panic!("Error::CapacityExceeded not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_use_custom_secure_region_address() {
+    let trans = [
+        I2cTrans::write_read(0x5C, vec![0x08, 0x00], DEV_SERIAL.to_vec()),
+        I2cTrans::write_read(0b101_1000, vec![0x08, 0x00], DEV_SERIAL.to_vec()),
+    ];
+    let mut eeprom = new_24csx32(&trans);
+    eeprom.set_secure_region_address(Some(0x5C));
+    assert_eq!(DEV_SERIAL, eeprom.read_unique_serial().unwrap());
+    eeprom.set_secure_region_address(None);
+    assert_eq!(DEV_SERIAL, eeprom.read_unique_serial().unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn lock_command_follows_custom_secure_region_address() {
+    let trans = [
+        I2cTrans::write_read(0x5A, vec![0x00], DEV_SERIAL.to_vec()),
+        I2cTrans::write(0b011_0010, vec![0x60, 0x00]),
+    ];
+    let mut eeprom = new_24cswx02(&trans);
+    eeprom.set_secure_region_address(Some(0x5A));
+    assert_eq!(DEV_SERIAL, eeprom.read_unique_serial().unwrap());
+    eeprom
+        .lock_security_register(Irreversible::confirm())
+        .unwrap();
+    destroy(eeprom);
+}
@@ -0,0 +1,34 @@
+use eeprom24x::{Eeprom24x, SharedBus, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::DEV_ADDR;
+
+#[test]
+fn can_use_several_devices_on_shared_bus() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB]),
+        I2cTrans::write(DEV_ADDR | 1, vec![0x12, 0x34, 0xAB]),
+    ];
+    let bus = SharedBus::new(I2cMock::new(&trans));
+    let mut eeprom1 = Eeprom24x::new_24x256(bus.acquire(), SlaveAddr::from_pins(0));
+    let mut eeprom2 = Eeprom24x::new_24x256(bus.acquire(), SlaveAddr::from_pins(1));
+    let data = eeprom1.read_byte(0x1234).unwrap();
+    eeprom2.write_byte(0x1234, data).unwrap();
+    eeprom1.destroy();
+    eeprom2.destroy();
+    bus.into_inner().done();
+}
+
+#[test]
+fn can_use_borrowed_bus() {
+    let trans = [
+        I2cTrans::write_read(DEV_ADDR, vec![0x12], vec![0xAB]),
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0xCD]),
+    ];
+    let mut i2c = I2cMock::new(&trans);
+    let mut eeprom = Eeprom24x::new_24x02(&mut i2c, SlaveAddr::default());
+    assert_eq!(0xAB, eeprom.read_byte(0x12).unwrap());
+    let mut eeprom = Eeprom24x::new_24x02(&mut i2c, SlaveAddr::default());
+    eeprom.write_byte(0x12, 0xCD).unwrap();
+    i2c.done();
+}
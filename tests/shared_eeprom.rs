@@ -0,0 +1,45 @@
+#![cfg(feature = "critical-section")]
+use eeprom24x::{Eeprom24x, SharedEeprom, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::{destroy, DEV_ADDR};
+
+#[test]
+fn can_access_device() {
+    let trans = [
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34, 0xAB]),
+        I2cTrans::write_read(DEV_ADDR, vec![0x12, 0x34], vec![0xAB]),
+    ];
+    let eeprom = SharedEeprom::new(Eeprom24x::new_24x256(
+        I2cMock::new(&trans),
+        SlaveAddr::default(),
+    ));
+    eeprom
+        .lock(|eeprom| eeprom.write_byte(0x1234, 0xAB))
+        .unwrap();
+    let data = eeprom.lock(|eeprom| eeprom.read_byte(0x1234)).unwrap();
+    assert_eq!(0xAB, data);
+    destroy(eeprom.into_inner());
+}
+
+#[test]
+fn can_share_between_threads() {
+    let trans = [
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x34, 0xAB]),
+        I2cTrans::write(DEV_ADDR, vec![0x12, 0x35, 0xCD]),
+    ];
+    let eeprom = SharedEeprom::new(Eeprom24x::new_24x256(
+        I2cMock::new(&trans),
+        SlaveAddr::default(),
+    ));
+    std::thread::scope(|s| {
+        s.spawn(|| eeprom.lock(|eeprom| eeprom.write_byte(0x1234, 0xAB)))
+            .join()
+            .unwrap()
+            .unwrap();
+    });
+    eeprom
+        .lock(|eeprom| eeprom.write_byte(0x1235, 0xCD))
+        .unwrap();
+    destroy(eeprom.into_inner());
+}
@@ -0,0 +1,15 @@
+use eeprom24x::SlaveAddr;
+
+#[test]
+fn can_display_resolved_address() {
+    assert_eq!("0x50", SlaveAddr::default().to_string());
+    assert_eq!("0x55", SlaveAddr::from_pins(0b101).to_string());
+    assert_eq!("0x08", SlaveAddr::Custom(0x8).to_string());
+}
+
+#[test]
+fn can_get_resolved_address() {
+    assert_eq!(0x50, SlaveAddr::default().addr());
+    assert_eq!(0x56, SlaveAddr::from_pins(0b110).addr());
+    assert_eq!(0x08, SlaveAddr::Custom(0x8).addr());
+}
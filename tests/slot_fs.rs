@@ -0,0 +1,115 @@
+#![cfg(feature = "slot-fs")]
+use eeprom24x::{FsError, SlotFs};
+mod common;
+use crate::common::RamStorage;
+
+// 4 byte header and 4 entries of 18 bytes, 52 bytes of data
+type Fs = SlotFs<RamStorage<128>, 4>;
+
+fn format() -> Fs {
+    Fs::format(RamStorage::new(0xFF)).unwrap()
+}
+
+fn read(fs: &mut Fs, name: &[u8]) -> Vec<u8> {
+    let slot = fs.open(name).unwrap();
+    let mut buffer = [0; 64];
+    let len = fs.read(&slot, &mut buffer).unwrap();
+    buffer[..len].to_vec()
+}
+
+#[test]
+fn cannot_mount_unformatted_storage() {
+    match Fs::mount(RamStorage::new(0xFF)) {
+        Err(FsError::NotFormatted) => (),
+        _ => panic!("FsError::NotFormatted not returned."),
+    }
+}
+
+#[test]
+fn can_create_write_and_read_slots() {
+    let mut fs = format();
+    let wifi = fs.create(b"wifi", 16).unwrap();
+    let cal = fs.create(b"cal", 8).unwrap();
+    assert_eq!(16, wifi.capacity());
+    fs.write(&wifi, b"ssid=home").unwrap();
+    fs.write(&cal, &[1, 2, 3]).unwrap();
+    let mut fs = Fs::mount(fs.destroy()).unwrap();
+    assert_eq!(b"ssid=home".to_vec(), read(&mut fs, b"wifi"));
+    assert_eq!(vec![1, 2, 3], read(&mut fs, b"cal"));
+    assert_eq!(0, fs.check().unwrap());
+}
+
+#[test]
+fn new_slot_is_empty() {
+    let mut fs = format();
+    fs.create(b"log", 8).unwrap();
+    assert!(read(&mut fs, b"log").is_empty());
+}
+
+#[test]
+fn reuses_space_of_deleted_slots() {
+    let mut fs = format();
+    fs.create(b"a", 20).unwrap();
+    fs.create(b"b", 20).unwrap();
+    match fs.create(b"c", 20) {
+        Err(FsError::NoSpace) => (),
+        _ => panic!("FsError::NoSpace not returned."),
+    }
+    fs.delete(b"a").unwrap();
+    let c = fs.create(b"c", 20).unwrap();
+    fs.write(&c, b"c").unwrap();
+    let b = fs.open(b"b").unwrap();
+    fs.write(&b, b"b").unwrap();
+    assert_eq!(b"c".to_vec(), read(&mut fs, b"c"));
+    match fs.open(b"a") {
+        Err(FsError::NotFound) => (),
+        _ => panic!("FsError::NotFound not returned."),
+    }
+}
+
+#[test]
+fn detects_corrupted_data() {
+    let mut fs = format();
+    let slot = fs.create(b"cal", 8).unwrap();
+    fs.write(&slot, &[1, 2, 3]).unwrap();
+    let mut storage = fs.destroy();
+    storage.data[77] ^= 1;
+    let mut fs = Fs::mount(storage).unwrap();
+    assert_eq!(1, fs.check().unwrap());
+    let mut buffer = [0; 8];
+    match fs.read(&slot, &mut buffer) {
+        Err(FsError::Corrupted) => (),
+        _ => panic!("FsError::Corrupted not returned."),
+    }
+}
+
+#[test]
+fn cannot_create_invalid_slots() {
+    let mut fs = format();
+    fs.create(b"a", 1).unwrap();
+    match fs.create(b"a", 1) {
+        Err(FsError::AlreadyExists) => (),
+        _ => panic!("FsError::AlreadyExists not returned."),
+    }
+    match fs.create(b"too long!", 1) {
+        Err(FsError::InvalidName) => (),
+        _ => panic!("FsError::InvalidName not returned."),
+    }
+    fs.create(b"b", 1).unwrap();
+    fs.create(b"c", 1).unwrap();
+    fs.create(b"d", 1).unwrap();
+    match fs.create(b"e", 1) {
+        Err(FsError::DirectoryFull) => (),
+        _ => panic!("FsError::DirectoryFull not returned."),
+    }
+}
+
+#[test]
+fn cannot_write_more_than_capacity() {
+    let mut fs = format();
+    let slot = fs.create(b"a", 2).unwrap();
+    match fs.write(&slot, &[0; 3]) {
+        Err(FsError::TooLarge) => (),
+        _ => panic!("FsError::TooLarge not returned."),
+    }
+}
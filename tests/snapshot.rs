@@ -0,0 +1,90 @@
+#![cfg(feature = "snapshot")]
+use eeprom24x::{restore_snapshot, snapshot, SnapshotError};
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+fn take(storage: &mut RamStorage<256>, page_size: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    snapshot(storage, page_size, |chunk| {
+        out.extend_from_slice(chunk);
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    out
+}
+
+fn give<const N: usize>(
+    storage: &mut RamStorage<N>,
+    page_size: usize,
+    source: &[u8],
+) -> Result<(), SnapshotError<PowerLoss, ()>> {
+    let mut position = 0;
+    restore_snapshot(storage, page_size, |buffer| {
+        let len = buffer.len().min(source.len() - position);
+        buffer[..len].copy_from_slice(&source[position..position + len]);
+        position += len;
+        Ok(len)
+    })
+}
+
+#[test]
+fn restores_snapshot() {
+    let mut original = RamStorage::<256>::new(0);
+    for (i, byte) in original.data.iter_mut().enumerate() {
+        *byte = (i * 7) as u8;
+    }
+    let taken = take(&mut original, 16);
+    assert_eq!(13 + 256 + 2, taken.len());
+    assert_eq!(b"E24S", &taken[..4]);
+
+    let mut target = RamStorage::<256>::new(0xFF);
+    give(&mut target, 16, &taken).unwrap();
+    assert_eq!(original.data, target.data);
+}
+
+#[test]
+fn rejects_other_data() {
+    let mut target = RamStorage::<256>::new(0xFF);
+    match give(&mut target, 16, &[0; 300]) {
+        Err(SnapshotError::InvalidHeader) => (),
+        _ => panic!("SnapshotError::InvalidHeader not returned."),
+    }
+    assert!(target.data.iter().all(|byte| *byte == 0xFF));
+}
+
+#[test]
+fn rejects_other_geometry() {
+    let taken = take(&mut RamStorage::<256>::new(0), 16);
+    let mut other_page_size = RamStorage::<256>::new(0xFF);
+    match give(&mut other_page_size, 32, &taken) {
+        Err(SnapshotError::GeometryMismatch) => (),
+        _ => panic!("SnapshotError::GeometryMismatch not returned."),
+    }
+    let mut other_capacity = RamStorage::<128>::new(0xFF);
+    match give(&mut other_capacity, 16, &taken) {
+        Err(SnapshotError::GeometryMismatch) => (),
+        _ => panic!("SnapshotError::GeometryMismatch not returned."),
+    }
+    assert!(other_page_size.data.iter().all(|byte| *byte == 0xFF));
+}
+
+#[test]
+fn detects_corruption() {
+    let mut taken = take(&mut RamStorage::<256>::new(0x12), 16);
+    taken[100] ^= 1;
+    match give(&mut RamStorage::<256>::new(0xFF), 16, &taken) {
+        Err(SnapshotError::Corrupted) => (),
+        _ => panic!("SnapshotError::Corrupted not returned."),
+    }
+}
+
+#[test]
+fn detects_truncation() {
+    let taken = take(&mut RamStorage::<256>::new(0x12), 16);
+    for len in [5, 100, taken.len() - 1] {
+        match give(&mut RamStorage::<256>::new(0xFF), 16, &taken[..len]) {
+            Err(SnapshotError::Truncated) => (),
+            _ => panic!("SnapshotError::Truncated not returned."),
+        }
+    }
+}
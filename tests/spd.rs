@@ -0,0 +1,42 @@
+use eeprom24x::{Eeprom24x, Irreversible, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::{destroy, new_34x02};
+
+#[test]
+fn can_set_reversible_write_protection() {
+    let trans = [I2cTrans::write(0x31, vec![0, 0])];
+    let mut eeprom = new_34x02(&trans);
+    eeprom.set_reversible_write_protection().unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_clear_reversible_write_protection() {
+    let trans = [I2cTrans::write(0x33, vec![0, 0])];
+    let mut eeprom = new_34x02(&trans);
+    eeprom.clear_reversible_write_protection().unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_set_permanent_write_protection() {
+    let trans = [I2cTrans::write(0x35, vec![0, 0])];
+    let mut eeprom = Eeprom24x::new_34x02(I2cMock::new(&trans), SlaveAddr::from_pins(0b101));
+    eeprom
+        .set_permanent_write_protection(Irreversible::confirm())
+        .unwrap();
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_temperature() {
+    let trans = [
+        I2cTrans::write_read(0x18, vec![0x05], vec![0xC1, 0x94]),
+        I2cTrans::write_read(0x18, vec![0x05], vec![0x1F, 0xF0]),
+    ];
+    let mut eeprom = new_34x02(&trans);
+    assert_eq!(25.25, eeprom.read_temperature().unwrap());
+    assert_eq!(-1.0, eeprom.read_temperature().unwrap());
+    destroy(eeprom);
+}
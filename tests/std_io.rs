@@ -0,0 +1,25 @@
+#![cfg(feature = "std")]
+use eeprom24x::EepromCursor;
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+mod common;
+use crate::common::RamStorage;
+
+#[test]
+fn can_copy_image() {
+    let mut cursor = EepromCursor::new(RamStorage::<16>::new(0));
+    std::io::copy(&mut &b"disk image"[..], &mut cursor).unwrap();
+    cursor.flush().unwrap();
+    cursor.rewind().unwrap();
+    let mut image = Vec::new();
+    cursor.read_to_end(&mut image).unwrap();
+    assert_eq!(b"disk image\0\0\0\0\0\0", &image[..]);
+}
+
+#[test]
+fn reports_end_of_storage() {
+    let mut cursor = EepromCursor::new(RamStorage::<16>::new(0));
+    cursor.seek(SeekFrom::End(0)).unwrap();
+    assert_eq!(ErrorKind::WriteZero, cursor.write(&[1]).unwrap_err().kind());
+    let error = cursor.seek(SeekFrom::Current(-17)).unwrap_err();
+    assert_eq!(ErrorKind::InvalidInput, error.kind());
+}
@@ -1,4 +1,4 @@
-use eeprom24x::{Eeprom24x, Error, Storage};
+use eeprom24x::{Eeprom24x, Error, Storage, WriteCycle};
 use embedded_hal_mock::eh1::{
     delay::NoopDelay,
     i2c::{Mock as I2cMock, Transaction as I2cTrans},
@@ -6,10 +6,12 @@ use embedded_hal_mock::eh1::{
 use embedded_storage::{ReadStorage, Storage as _};
 mod common;
 use crate::common::{
-    destroy, new_24csx01, new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32,
-    new_24csx64, new_24x00, new_24x01, new_24x02, new_24x04, new_24x08, new_24x128, new_24x16,
-    new_24x256, new_24x32, new_24x512, new_24x64, new_24xm01, new_24xm02, new_m24x01, new_m24x02,
-    DEV_ADDR,
+    destroy, new_24cswx01, new_24cswx02, new_24cswx04, new_24cswx08, new_24cswx16, new_24csx01,
+    new_24csx02, new_24csx04, new_24csx08, new_24csx16, new_24csx32, new_24csx64, new_24cwx128,
+    new_24cwx16, new_24cwx32, new_24cwx64, new_24mac402, new_24mac602, new_24x00, new_24x01,
+    new_24x01a, new_24x02, new_24x02a, new_24x04, new_24x08, new_24x128, new_24x16, new_24x256,
+    new_24x32, new_24x512, new_24x64, new_24xm01, new_24xm02, new_34x02, new_cat24x01,
+    new_cat24x02, new_m24x01, new_m24x02, page_write, DEV_ADDR,
 };
 
 fn storage_new<PS, AS, SN>(
@@ -65,7 +67,7 @@ macro_rules! can_write_array_1byte_addr {
     ($name:ident, $create:ident, $_page_size:expr) => {
         #[test]
         fn $name() {
-            let trans = [I2cTrans::write(DEV_ADDR, vec![0x34, 0xAB, 0xCD, 0xEF])];
+            let trans = page_write(DEV_ADDR, &[0x34], &[0xAB, 0xCD, 0xEF]);
             let mut storage = storage_new($create(&trans));
             storage.write(0x34, &[0xAB, 0xCD, 0xEF]).unwrap();
             destroy(storage.eeprom);
@@ -78,7 +80,7 @@ macro_rules! can_write_array_2byte_addr {
     ($name:ident, $create:ident, $_page_size:expr) => {
         #[test]
         fn $name() {
-            let trans = [I2cTrans::write(DEV_ADDR, vec![0xF, 0x34, 0xAB, 0xCD, 0xEF])];
+            let trans = page_write(DEV_ADDR, &[0xF, 0x34], &[0xAB, 0xCD, 0xEF]);
             let mut storage = storage_new($create(&trans));
             storage.write(0xF34, &[0xAB, 0xCD, 0xEF]).unwrap();
             destroy(storage.eeprom);
@@ -93,11 +95,70 @@ macro_rules! cannot_write_too_much_data {
         fn $name() {
             let mut storage = storage_new($create(&[]));
             match storage.write(0x34, &[0xAB; 1 + $capacity]) {
-                Err(Error::TooMuchData) => (),
-                _ => panic!("Error::TooMuchData not returned."),
+                Err(Error::CapacityExceeded { .. }) => (),
+                _ => panic!("Error::CapacityExceeded not returned."),
             }
             destroy(storage.eeprom);
         }
     };
 }
 for_all_writestorage_ics_with_capacity!(cannot_write_too_much_data);
+
+#[derive(Default)]
+struct RecordingDelay {
+    total_ms: u32,
+}
+
+impl embedded_hal::delay::DelayNs for RecordingDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.total_ms += ns / 1_000_000;
+    }
+}
+
+#[test]
+fn waits_for_write_cycle_after_each_page() {
+    let mut trans = page_write(DEV_ADDR, &[0x0F], &[0xAB]);
+    trans.extend(page_write(DEV_ADDR, &[0x10], &[0xCD]));
+    let mut storage = Storage::new(new_24x04(&trans), RecordingDelay::default());
+    storage.write(0x0F, &[0xAB, 0xCD]).unwrap();
+    let (mut i2c, delay) = storage.destroy();
+    assert_eq!(
+        2 * <eeprom24x::page_size::B16 as WriteCycle>::WRITE_CYCLE_MS,
+        delay.total_ms
+    );
+    i2c.done();
+}
+
+#[test]
+fn can_write_array_byte_by_byte_on_24x00() {
+    let trans = [
+        I2cTrans::write(DEV_ADDR, vec![0xE, 0xAB]),
+        I2cTrans::write(DEV_ADDR, vec![0xF, 0xCD]),
+    ];
+    let mut storage = Storage::new(new_24x00(&trans), RecordingDelay::default());
+    storage.write(0xE, &[0xAB, 0xCD]).unwrap();
+    let (mut i2c, delay) = storage.destroy();
+    assert_eq!(
+        2 * <eeprom24x::page_size::No as WriteCycle>::WRITE_CYCLE_MS,
+        delay.total_ms
+    );
+    i2c.done();
+}
+
+#[test]
+fn can_write_in_chunks_below_page_size() {
+    let mut trans = page_write(DEV_ADDR, &[0x0, 0x3E], &[0xAB, 0xCD]);
+    trans.extend(page_write(DEV_ADDR, &[0x0, 0x40], &[0xEF, 0x12]));
+    trans.extend(page_write(DEV_ADDR, &[0x0, 0x42], &[0x34]));
+    let mut storage = Storage::new(new_24x256(&trans), RecordingDelay::default());
+    storage.set_max_write_len(2);
+    storage
+        .write(0x3E, &[0xAB, 0xCD, 0xEF, 0x12, 0x34])
+        .unwrap();
+    let (mut i2c, delay) = storage.destroy();
+    assert_eq!(
+        3 * <eeprom24x::page_size::B64 as WriteCycle>::WRITE_CYCLE_MS,
+        delay.total_ms
+    );
+    i2c.done();
+}
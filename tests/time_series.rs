@@ -0,0 +1,90 @@
+#![cfg(feature = "time-series")]
+use eeprom24x::{TimeSeries, TimeSeriesError};
+mod common;
+use crate::common::{PowerLoss, RamStorage};
+
+// 5 records of 8 bytes
+type Series = TimeSeries<RamStorage<40>, 2>;
+
+fn timestamps(series: &mut Series, start: u32, end: u32) -> Vec<u32> {
+    series
+        .range(start..end)
+        .unwrap()
+        .map(|record| record.unwrap().0)
+        .collect()
+}
+
+#[test]
+fn can_append_and_get() {
+    let mut series = Series::format(RamStorage::new(0xFF)).unwrap();
+    assert!(series.is_empty());
+    assert_eq!(5, series.capacity());
+    series.append(10, &[1, 2]).unwrap();
+    series.append(20, &[3, 4]).unwrap();
+    assert_eq!(2, series.len());
+    assert_eq!((10, [1, 2]), series.get(0).unwrap());
+    assert_eq!((20, [3, 4]), series.get(1).unwrap());
+    match series.get(2) {
+        Err(TimeSeriesError::OutOfBounds) => (),
+        _ => panic!("TimeSeriesError::OutOfBounds not returned."),
+    }
+}
+
+#[test]
+fn timestamps_must_increase() {
+    let mut series = Series::format(RamStorage::new(0xFF)).unwrap();
+    series.append(10, &[0; 2]).unwrap();
+    match series.append(10, &[0; 2]) {
+        Err(TimeSeriesError::NotIncreasing) => (),
+        _ => panic!("TimeSeriesError::NotIncreasing not returned."),
+    }
+}
+
+#[test]
+fn can_iterate_range() {
+    let mut series = Series::format(RamStorage::new(0xFF)).unwrap();
+    for timestamp in [10, 20, 30, 40] {
+        series.append(timestamp, &[0; 2]).unwrap();
+    }
+    assert_eq!(2, series.find(25).unwrap());
+    assert_eq!(4, series.find(45).unwrap());
+    assert_eq!(vec![20, 30], timestamps(&mut series, 15, 40));
+    assert_eq!(vec![10, 20, 30, 40], timestamps(&mut series, 0, 100));
+    assert!(timestamps(&mut series, 41, 100).is_empty());
+    assert!(timestamps(&mut series, 30, 20).is_empty());
+}
+
+#[test]
+fn overwrites_oldest_records_when_full() {
+    let mut series = Series::format(RamStorage::new(0xFF)).unwrap();
+    for timestamp in 1..=7 {
+        series.append(timestamp, &[timestamp as u8; 2]).unwrap();
+    }
+    assert_eq!(5, series.len());
+    assert_eq!(vec![3, 4, 5, 6, 7], timestamps(&mut series, 0, 10));
+    let mut series = Series::mount(series.destroy()).unwrap();
+    assert_eq!(vec![3, 4, 5, 6, 7], timestamps(&mut series, 0, 10));
+    series.append(8, &[8; 2]).unwrap();
+    assert_eq!((4, [4; 2]), series.get(0).unwrap());
+}
+
+#[test]
+fn mount_discards_torn_record() {
+    let mut series = Series::format(RamStorage::new(0xFF)).unwrap();
+    for timestamp in 1..=5 {
+        series.append(timestamp, &[0; 2]).unwrap();
+    }
+    let mut storage = series.destroy();
+    storage.writes_left = Some(1);
+    let mut series = Series::mount(storage).unwrap();
+    match series.append(6, &[0; 2]) {
+        Err(TimeSeriesError::Storage(PowerLoss)) => (),
+        _ => panic!("TimeSeriesError::Storage not returned."),
+    }
+    let mut storage = series.destroy();
+    storage.writes_left = None;
+    let mut series = Series::mount(storage).unwrap();
+    assert_eq!(vec![2, 3, 4, 5], timestamps(&mut series, 0, 10));
+    series.append(6, &[0; 2]).unwrap();
+    assert_eq!(vec![2, 3, 4, 5, 6], timestamps(&mut series, 0, 10));
+}
@@ -0,0 +1,94 @@
+use eeprom24x::{dump, restore, TransferError};
+mod common;
+use crate::common::RamStorage;
+
+#[test]
+fn dumps_in_chunks() {
+    let mut storage = RamStorage::<256>::new(0);
+    for (i, byte) in storage.data.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    let mut out = Vec::new();
+    let mut chunks = 0;
+    dump(&mut storage, 10..150, |chunk| {
+        chunks += 1;
+        out.extend_from_slice(chunk);
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    assert_eq!(3, chunks);
+    assert_eq!(&storage.data[10..150], &out[..]);
+}
+
+#[test]
+fn stops_on_callback_error() {
+    let mut storage = RamStorage::<256>::new(0);
+    let mut chunks = 0;
+    let result = dump(&mut storage, 0..256, |_| {
+        chunks += 1;
+        Err("sink full")
+    });
+    assert_eq!(Err(TransferError::Callback("sink full")), result);
+    assert_eq!(1, chunks);
+}
+
+#[test]
+fn cannot_dump_out_of_bounds() {
+    let mut storage = RamStorage::<16>::new(0);
+    match dump(&mut storage, 0..17, |_| Ok::<(), ()>(())) {
+        Err(TransferError::OutOfBounds) => (),
+        _ => panic!("TransferError::OutOfBounds not returned."),
+    }
+}
+
+#[test]
+fn restores_in_aligned_chunks() {
+    let mut storage = RamStorage::<256>::new(0xFF);
+    let source: Vec<u8> = (0..140).collect();
+    let mut position = 0;
+    let mut lengths = Vec::new();
+    let written = restore(&mut storage, 10..150, |buffer| {
+        lengths.push(buffer.len());
+        buffer.copy_from_slice(&source[position..position + buffer.len()]);
+        position += buffer.len();
+        Ok::<usize, ()>(buffer.len())
+    })
+    .unwrap();
+    assert_eq!(140, written);
+    assert_eq!(vec![54, 64, 22], lengths);
+    assert_eq!(&source[..], &storage.data[10..150]);
+    assert_eq!(0xFF, storage.data[9]);
+    assert_eq!(0xFF, storage.data[150]);
+}
+
+#[test]
+fn restore_stops_when_source_is_exhausted() {
+    let mut storage = RamStorage::<256>::new(0xFF);
+    let mut left = 70;
+    let written = restore(&mut storage, 0..256, |buffer| {
+        let len = buffer.len().min(left);
+        buffer[..len].fill(0x5A);
+        left -= len;
+        Ok::<usize, ()>(len)
+    })
+    .unwrap();
+    assert_eq!(70, written);
+    assert!(storage.data[..70].iter().all(|byte| *byte == 0x5A));
+    assert!(storage.data[70..].iter().all(|byte| *byte == 0xFF));
+}
+
+#[test]
+fn restore_stops_on_callback_error() {
+    let mut storage = RamStorage::<256>::new(0xFF);
+    let result = restore(&mut storage, 0..256, |_| Err("source closed"));
+    assert_eq!(Err(TransferError::Callback("source closed")), result);
+}
+
+#[test]
+fn cannot_restore_out_of_bounds() {
+    let mut storage = RamStorage::<16>::new(0);
+    match restore(&mut storage, 0..17, |buffer| Ok::<usize, ()>(buffer.len())) {
+        Err(TransferError::OutOfBounds) => (),
+        _ => panic!("TransferError::OutOfBounds not returned."),
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "alloc")]
+use eeprom24x::{Eeprom24x, Error, SlaveAddr};
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+mod common;
+use crate::common::{destroy, DEV_ADDR};
+
+#[test]
+fn can_read_vec() {
+    let trans = [I2cTrans::write_read(
+        DEV_ADDR,
+        vec![0x12, 0x34],
+        vec![0xAB, 0xCD, 0xEF],
+    )];
+    let mut eeprom = Eeprom24x::new_24x256(I2cMock::new(&trans), SlaveAddr::default());
+    assert_eq!(vec![0xAB, 0xCD, 0xEF], eeprom.read_vec(0x1234, 3).unwrap());
+    destroy(eeprom);
+}
+
+#[test]
+fn cannot_read_vec_out_of_bounds() {
+    let mut eeprom = Eeprom24x::new_24x00(I2cMock::new(&[]), SlaveAddr::default());
+    match eeprom.read_vec(0x10, 2) {
+        Err(Error::InvalidAddr { .. }) => (),
+        _ => panic!("Error::InvalidAddr not returned."),
+    }
+    destroy(eeprom);
+}
+
+#[test]
+fn can_read_all_vec() {
+    let data: Vec<u8> = (0..16).collect();
+    let trans = [I2cTrans::write_read(DEV_ADDR, vec![0], data.clone())];
+    let mut eeprom = Eeprom24x::new_24x00(I2cMock::new(&trans), SlaveAddr::default());
+    assert_eq!(data, eeprom.read_all_vec().unwrap());
+    destroy(eeprom);
+}
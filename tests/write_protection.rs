@@ -0,0 +1,71 @@
+use eeprom24x::{Error, Irreversible};
+use embedded_hal::i2c::ErrorKind;
+use embedded_hal_mock::eh1::i2c::Transaction as I2cTrans;
+mod common;
+use crate::common::{destroy, new_24cwx128, new_24cwx16, new_24cwx32, new_24cwx64, DEV_ADDR};
+
+macro_rules! can_read_write_protection {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [I2cTrans::write_read(
+                DEV_ADDR,
+                vec![0x80, 0x00],
+                vec![0b1100_0000],
+            )];
+            let mut eeprom = $create(&trans);
+            assert_eq!(0b1100_0000, eeprom.write_protection().unwrap());
+            destroy(eeprom);
+        }
+    };
+}
+for_all_with_write_protection_zones!(can_read_write_protection);
+
+macro_rules! can_set_and_clear_write_protection {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [
+                I2cTrans::write(DEV_ADDR, vec![0x80, 0x00, 0b1000_0001]),
+                I2cTrans::write(DEV_ADDR, vec![0x80, 0x00, 0]),
+            ];
+            let mut eeprom = $create(&trans);
+            eeprom.set_write_protection(0b1000_0001).unwrap();
+            eeprom.clear_write_protection().unwrap();
+            destroy(eeprom);
+        }
+    };
+}
+for_all_with_write_protection_zones!(can_set_and_clear_write_protection);
+
+macro_rules! can_lock_write_protection {
+    ($name:ident, $create:ident) => {
+        #[test]
+        fn $name() {
+            let trans = [
+                I2cTrans::write_read(DEV_ADDR, vec![0x80, 0x01], vec![0]),
+                I2cTrans::write(DEV_ADDR, vec![0x80, 0x01, 1]),
+                I2cTrans::write_read(DEV_ADDR, vec![0x80, 0x01], vec![1]),
+            ];
+            let mut eeprom = $create(&trans);
+            assert!(!eeprom.is_write_protection_locked().unwrap());
+            eeprom
+                .lock_write_protection(Irreversible::confirm())
+                .unwrap();
+            assert!(eeprom.is_write_protection_locked().unwrap());
+            destroy(eeprom);
+        }
+    };
+}
+for_all_with_write_protection_zones!(can_lock_write_protection);
+
+#[test]
+fn returns_bus_errors() {
+    let trans = [I2cTrans::write(DEV_ADDR, vec![0x80, 0x00, 1]).with_error(ErrorKind::Bus)];
+    let mut eeprom = new_24cwx64(&trans);
+    match eeprom.set_write_protection(1) {
+        Err(Error::I2C(ErrorKind::Bus)) => (),
+        _ => panic!("Error::I2C not returned."),
+    }
+    destroy(eeprom);
+}
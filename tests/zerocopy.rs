@@ -0,0 +1,63 @@
+#![cfg(feature = "zerocopy")]
+use eeprom24x::{addr_size, page_size, unique_serial, Eeprom24x, SlaveAddr, Storage};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+use zerocopy::{AsBytes, FromBytes, FromZeroes};
+mod common;
+use crate::common::{destroy, page_write, DEV_ADDR};
+
+#[derive(Debug, PartialEq, AsBytes, FromBytes, FromZeroes)]
+#[repr(C)]
+struct Header {
+    magic: [u8; 2],
+    version: u8,
+    flags: u8,
+}
+
+fn new_storage(
+    transactions: &[I2cTrans],
+) -> Storage<I2cMock, page_size::B8, addr_size::OneByte, unique_serial::No, NoopDelay> {
+    let eeprom = Eeprom24x::new_24x02(I2cMock::new(transactions), SlaveAddr::default());
+    Storage::new(eeprom, NoopDelay)
+}
+
+#[test]
+fn can_read_obj() {
+    let trans = [I2cTrans::write_read(
+        DEV_ADDR,
+        vec![0x10],
+        vec![b'H', b'D', 2, 0x80],
+    )];
+    let mut storage = new_storage(&trans);
+    let header = storage.read_obj::<Header>(0x10).unwrap();
+    let expected = Header {
+        magic: *b"HD",
+        version: 2,
+        flags: 0x80,
+    };
+    assert_eq!(expected, header);
+    destroy(storage.eeprom);
+}
+
+#[test]
+fn can_write_obj() {
+    let trans = page_write(DEV_ADDR, &[0x10], &[b'H', b'D', 2, 0x80]);
+    let mut storage = new_storage(&trans);
+    let header = Header {
+        magic: *b"HD",
+        version: 2,
+        flags: 0x80,
+    };
+    storage.write_obj(0x10, &header).unwrap();
+    destroy(storage.eeprom);
+}
+
+#[test]
+fn can_write_array_of_integers() {
+    let trans = page_write(DEV_ADDR, &[0x20], &1u16.to_ne_bytes());
+    let mut storage = new_storage(&trans);
+    storage.write_obj(0x20, &[1u16]).unwrap();
+    destroy(storage.eeprom);
+}